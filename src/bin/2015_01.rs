@@ -1,4 +1,18 @@
-all_aoc::solution!(1, 2015);
+use all_aoc::{cli::generator::InputGenerator, helper::rand::Rng};
+
+all_aoc::solution!(1, 2015, generator = Generator, tags = ["string"]);
+
+/// A sequence of parentheses that goes up slightly more often than down.
+struct Generator;
+impl InputGenerator for Generator {
+    const REAL_SIZE: usize = 7000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        core::iter::repeat_with(|| if rng.ratio(11, 20) { '(' } else { ')' })
+            .take(size)
+            .collect()
+    }
+}
 
 pub fn part_one(input: &str) -> Option<i32> {
     Some(
@@ -49,21 +63,9 @@ mod tests {
         assert_eq!(Some(-3), part_one(")())())"));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(280));
-    }
-
     #[test]
     fn test_part_two() {
         assert_eq!(Some(1), part_two(")"));
         assert_eq!(Some(5), part_two("()())"));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_797));
-    }
 }
@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(2, 2015);
+all_aoc::solution!(2, 2015, tags = ["math"]);
 struct Present {
     length: u32,
     width: u32,
@@ -70,21 +70,9 @@ mod tests {
         assert_eq!(Some(43), part_one("1x1x10"));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_598_415));
-    }
-
     #[test]
     fn test_part_two() {
         assert_eq!(Some(34), part_two("2x3x4"));
         assert_eq!(Some(14), part_two("1x1x10"));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_812_909));
-    }
 }
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use all_aoc::helper::position::{Direction4, Position};
 
-all_aoc::solution!(3, 2015);
+all_aoc::solution!(3, 2015, tags = ["grid", "simulation"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let mut curr = Position::<i32>::default();
@@ -49,22 +49,10 @@ mod tests {
         assert_eq!(Some(2), part_one("^v^v^v^v^v"));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_565));
-    }
-
     #[test]
     fn test_part_two() {
         assert_eq!(Some(3), part_two("^v"));
         assert_eq!(Some(3), part_two("^>v<"));
         assert_eq!(Some(11), part_two("^v^v^v^v^v"));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_639));
-    }
 }
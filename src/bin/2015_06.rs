@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::position::Position;
 
-all_aoc::solution!(6, 2015);
+all_aoc::solution!(6, 2015, tags = ["grid", "simulation"]);
 enum Mode {
     On,
     Off,
@@ -101,18 +101,3 @@ fn generate_all_combs(range: RangeInclusive<Position<i32>>) -> impl Iterator<Ite
 
     (start.y..=end.y).flat_map(move |y| (start.x..=end.x).map(move |x| Position { x, y }))
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(543_903));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(14_687_245));
-    }
-}
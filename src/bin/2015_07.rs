@@ -1,7 +1,7 @@
 use core::{hash::Hash, str::FromStr};
 use std::collections::HashMap;
 
-all_aoc::solution!(7, 2015);
+all_aoc::solution!(7, 2015, tags = ["graph", "parsing"]);
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 enum Type {
     Number(u16),
@@ -151,19 +151,3 @@ fn parse(input: &str) -> HashMap<String, Instruction> {
         })
         .collect()
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(16_076));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_797));
-    }
-}
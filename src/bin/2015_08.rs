@@ -1,4 +1,4 @@
-all_aoc::solution!(8, 2015);
+all_aoc::solution!(8, 2015, tags = ["string", "parsing"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let input = parse(input);
@@ -62,21 +62,9 @@ mod tests {
         assert_eq!(result, Some(12));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_350));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(19));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_085));
-    }
 }
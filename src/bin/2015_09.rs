@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use all_aoc::helper::permutations::IteratorPermutator as _;
 
-all_aoc::solution!(9, 2015);
+all_aoc::solution!(9, 2015, tags = ["graph", "brute-force"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let SetupResult(map, perm) = setup(input);
@@ -57,21 +57,9 @@ mod tests {
         assert_eq!(result, Some(605));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(141));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(982));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(736));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(10, 2015);
+all_aoc::solution!(10, 2015, tags = ["string", "simulation"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(run(input, 40))
@@ -32,20 +32,3 @@ fn look_and_say(s: &str) -> String {
     result.push(current);
     result
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(252_594));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_579_328));
-    }
-}
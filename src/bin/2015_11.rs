@@ -1,4 +1,4 @@
-all_aoc::solution!(11, 2015);
+all_aoc::solution!(11, 2015, tags = ["string", "brute-force"]);
 
 pub fn part_one(input: &str) -> Option<String> {
     Some(next_password(input))
@@ -71,16 +71,4 @@ mod tests {
         assert_eq!(Some("abcdffaa".to_owned()), part_one("abcdefgh"));
         assert_eq!(Some("ghjaabcc".to_owned()), part_one("ghijklmn"));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("vzbxxyzz".to_owned()));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("vzcaabcc".to_owned()));
-    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(12, 2015);
+all_aoc::solution!(12, 2015, tags = ["parsing"]);
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum JsonObject {
     Number(i32),
@@ -177,18 +177,6 @@ mod tests {
         assert_eq!(y, 13);
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(191_164));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        assert_eq!(Some(0), part_two("{\"d\":\"red\",\"e\":[1,2,3,4],\"f\":5}"));
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(87_842));
-    }
     #[test]
     fn test_part_two() {
         assert_eq!(Some(0), part_two("{\"d\":\"red\",\"e\":[1,2,3,4],\"f\":5}"));
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use all_aoc::helper::permutations::IteratorPermutator as _;
 
-all_aoc::solution!(13, 2015);
+all_aoc::solution!(13, 2015, tags = ["graph", "brute-force"]);
 
 pub fn part_one(input: &str) -> Option<i32> {
     let map = parse(input);
@@ -67,16 +67,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(330));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(733));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(725));
-    }
 }
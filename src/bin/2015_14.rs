@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(14, 2015);
+all_aoc::solution!(14, 2015, tags = ["simulation"]);
 struct Reindeer {
     speed: u32,
     fly_time: u32,
@@ -81,21 +81,9 @@ mod tests {
         assert_eq!(result, Some(1_120));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_655));
-    }
-
     #[test]
     fn test_part_two() {
         let result = solve_part_2(&all_aoc::cli::read_examples_file(DAY), 1_000);
         assert_eq!(result, Some(689));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_059));
-    }
 }
@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(15, 2015);
+all_aoc::solution!(15, 2015, tags = ["brute-force"]);
 struct Ingridient {
     capacity: i32,
     durability: i32,
@@ -99,20 +99,10 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(62_842_880));
     }
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(222_870));
-    }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(57_600_000));
     }
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(117_936));
-    }
 }
@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::HashMap;
 
-all_aoc::solution!(16, 2015);
+all_aoc::solution!(16, 2015, tags = ["parsing"]);
 #[derive(Debug, PartialEq, Eq, Hash)]
 enum Item {
     Children,
@@ -98,20 +98,3 @@ fn parse(input: &str) -> Vec<Sue> {
     }
     sues
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(213));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(323));
-    }
-}
@@ -1,6 +1,6 @@
 use all_aoc::helper::permutations::IteratorCombinator as _;
 
-all_aoc::solution!(17, 2015);
+all_aoc::solution!(17, 2015, tags = ["combinatorics", "dynamic-programming"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     solve_part_1(input, 150)
@@ -41,21 +41,9 @@ mod tests {
         assert_eq!(result, Some(4));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(654));
-    }
-
     #[test]
     fn test_part_two() {
         let result = solve_part_2(&all_aoc::cli::read_examples_file(DAY), 25);
         assert_eq!(result, Some(3));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(57));
-    }
 }
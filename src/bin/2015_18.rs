@@ -1,7 +1,12 @@
 use core::fmt::Debug;
 
-use all_aoc::helper::grid::{Grid as _, dense::DenseGrid};
-all_aoc::solution!(18, 2015);
+use all_aoc::helper::grid::{
+    Grid as _,
+    automaton::{Automaton, Neighbours},
+    dense::DenseGrid,
+    region::Connectivity,
+};
+all_aoc::solution!(18, 2015, tags = ["grid", "simulation"]);
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum State {
     On,
@@ -26,11 +31,6 @@ impl Debug for State {
         }
     }
 }
-impl From<bool> for State {
-    fn from(value: bool) -> Self {
-        if value { Self::On } else { Self::Off }
-    }
-}
 pub fn part_one(input: &str) -> Option<usize> {
     solve_part_1(input, 100)
 }
@@ -38,69 +38,34 @@ pub fn part_one(input: &str) -> Option<usize> {
 pub fn part_two(input: &str) -> Option<usize> {
     solve_part_2(input, 100)
 }
-fn solve_part_1(input: &str, iterations: u32) -> Option<usize> {
-    let mut grid = parse(input);
-    for _ in 0..iterations {
-        let grid_copy = grid.clone();
-        let size = grid_copy.len();
-        for i in 0..size {
-            let neigbors = grid_copy
-                .get_neigbors8(i)
-                .map(|(_, x)| x)
-                .filter(|s| **s == State::On)
-                .count();
-            let new_state = match grid_copy.get(i).unwrap() {
-                State::On => {
-                    matches!(neigbors, 2 | 3)
-                }
-                State::Off => neigbors == 3,
-            };
-            grid[i] = new_state.into();
-        }
-    }
-    Some(grid.into_iter().filter(|s| *s == State::On).count())
+fn solve_part_1(input: &str, iterations: usize) -> Option<usize> {
+    Some(run(parse(input), &[], iterations))
 }
-fn solve_part_2(input: &str, iterations: u32) -> Option<usize> {
+fn solve_part_2(input: &str, iterations: usize) -> Option<usize> {
     let mut grid = parse(input);
-    set_edges_to_on(&mut grid);
-    for _ in 0..iterations {
-        let grid_copy = grid.clone();
-        let size = grid_copy.len();
-        for i in 0..size {
-            let neigbors = grid_copy
-                .get_neigbors8(i)
-                .map(|(_, x)| x)
-                .filter(|s| **s == State::On)
-                .count();
-            let new_state = match grid_copy.get(i).unwrap() {
-                State::On => {
-                    if matches!(neigbors, 2 | 3) {
-                        State::On
-                    } else {
-                        State::Off
-                    }
-                }
-                State::Off => {
-                    if neigbors == 3 {
-                        State::On
-                    } else {
-                        State::Off
-                    }
-                }
-            };
-            grid[i] = new_state;
-        }
-        set_edges_to_on(&mut grid);
+    let (height, width) = (grid.height(), grid.width());
+    let corners = [
+        (0, 0),
+        (height - 1, 0),
+        (0, width - 1),
+        (height - 1, width - 1),
+    ];
+    for corner in corners {
+        grid[corner] = State::On;
     }
-    Some(grid.into_iter().filter(|s| *s == State::On).count())
+    Some(run(grid, &corners, iterations))
 }
-fn set_edges_to_on(grid: &mut DenseGrid<State>) {
-    let height = grid.height();
-    let width = grid.width();
-    grid[(0, 0)] = State::On;
-    grid[(height - 1, 0)] = State::On;
-    grid[(0, width - 1)] = State::On;
-    grid[(height - 1, width - 1)] = State::On;
+/// The number of lights that are on after the iterations, the fixed lights never change.
+fn run(grid: DenseGrid<State>, fixed: &[(usize, usize)], iterations: usize) -> usize {
+    let mut automaton = Automaton::new(grid, Connectivity::Eight).with_fixed(fixed.iter().copied());
+    automaton.run(
+        iterations,
+        |cell, neighbours: &Neighbours<'_, State>| match (cell, neighbours.count(&State::On)) {
+            (State::On, 2 | 3) | (State::Off, 3) => State::On,
+            _ => State::Off,
+        },
+    );
+    automaton.grid().iter().filter(|s| **s == State::On).count()
 }
 fn parse(input: &str) -> DenseGrid<State> {
     DenseGrid::from_string(input)
@@ -115,21 +80,9 @@ mod tests {
         assert_eq!(result, Some(4));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_061));
-    }
-
     #[test]
     fn test_part_two() {
         let result = solve_part_2(&all_aoc::cli::read_examples_file(DAY), 5);
         assert_eq!(result, Some(17));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_006));
-    }
 }
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use all_aoc::helper::rand::Shuffle as _;
 
-all_aoc::solution!(19, 2015);
+all_aoc::solution!(19, 2015, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (molecule, vec) = parse(input);
@@ -82,11 +82,6 @@ mod tests {
         assert_eq!(result, Some(4));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(576));
-    }
     /*
     #[test]
     fn test_part_two() {
@@ -94,10 +89,4 @@ mod tests {
         assert_eq!(result, Some(3));
     }
     */
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(207));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(20, 2015);
+all_aoc::solution!(20, 2015, tags = ["number-theory"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let goal = parse(input);
@@ -57,19 +57,3 @@ fn divisors(n: u32) -> Vec<u32> {
     }
     divisors
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(786_240));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(831_600));
-    }
-}
@@ -10,7 +10,7 @@ enum Win {
     BossWin(u32),
     PlayerWin(u32),
 }
-all_aoc::solution!(21, 2015);
+all_aoc::solution!(21, 2015, tags = ["simulation", "brute-force"]);
 #[rustfmt::skip]
 const WEAPONS: [Stats; 5] = [
     Stats { cost: 8, damage: 4, armor: 0 },
@@ -160,19 +160,3 @@ fn parse_boss(input: &str) -> Entitiy {
         hit_points: vec[0],
     }
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(121));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(201));
-    }
-}
@@ -1,4 +1,4 @@
-all_aoc::solution!(22, 2015);
+all_aoc::solution!(22, 2015, tags = ["simulation", "search"]);
 
 pub fn part_one(input: &str) -> Option<i32> {
     Some(solve(input, Difficulty::Easy))
@@ -115,7 +115,7 @@ impl GameState {
 
     fn cast(&self, spell: &Spell) -> Self {
         let gamelog = format!("{}Player casts {}\n", self.gamelog, spell.name);
-        assert!( self.mana >= spell.cost, "bug in move generator");
+        assert!(self.mana >= spell.cost, "bug in move generator");
 
         if spell.duration > 0 {
             let mut effects = self.effects.clone();
@@ -329,19 +329,3 @@ fn parse(input: &str) -> (i32, i32) {
     let dmg = dmg.trim_start_matches("Damage: ").parse().unwrap();
     (hit_points, dmg)
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(953));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_289));
-    }
-}
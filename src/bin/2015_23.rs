@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::HashMap;
 
-all_aoc::solution!(23, 2015);
+all_aoc::solution!(23, 2015, tags = ["vm"]);
 enum Operation {
     Half(char),
     Triple(char),
@@ -88,20 +88,3 @@ fn parse(input: &str) -> Vec<Operation> {
         .map(|l| Operation::from_str(l).unwrap())
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(170));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(247));
-    }
-}
@@ -1,6 +1,6 @@
 use all_aoc::helper::permutations::IteratorCombinator as _;
 
-all_aoc::solution!(24, 2015);
+all_aoc::solution!(24, 2015, tags = ["combinatorics"]);
 
 fn can_partition(available: u128, groups_left: usize, w: &[u64], target: u64) -> bool {
     if groups_left == 0 {
@@ -117,20 +117,10 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(99));
     }
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(10_439_961_859));
-    }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(44));
     }
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(72_050_269));
-    }
 }
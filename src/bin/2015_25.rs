@@ -1,4 +1,4 @@
-all_aoc::solution!(25, 2015);
+all_aoc::solution!(25, 2015, tags = ["number-theory"]);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let input = parse(input);
@@ -32,13 +32,3 @@ fn parse(input: &str) -> (u64, u64) {
     let n2 = vec[17].trim_end_matches('.').parse().unwrap();
     (n1, n2)
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(9_132_360));
-    }
-}
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 
 use all_aoc::helper::position::{Direction4, Position};
 
-all_aoc::solution!(1, 2016);
+all_aoc::solution!(1, 2016, tags = ["grid", "simulation"]);
 enum Turn {
     Left(u32),
     Right(u32),
@@ -80,19 +80,3 @@ fn parse(input: &str) -> Vec<Turn> {
         .map(|ins| Turn::from_str(ins).unwrap())
         .collect()
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(271));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(153));
-    }
-}
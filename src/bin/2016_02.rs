@@ -3,7 +3,7 @@ use all_aoc::helper::{
     position::Direction4,
 };
 
-all_aoc::solution!(2, 2016);
+all_aoc::solution!(2, 2016, tags = ["grid", "simulation"]);
 
 pub fn part_one(input: &str) -> Option<String> {
     let dirs = parse(input);
@@ -88,21 +88,9 @@ mod tests {
         assert_eq!(result, Some("1985".to_owned()));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("98575".to_owned()));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some("5DB3".to_owned()));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("CD8D4".to_owned()));
-    }
 }
@@ -1,6 +1,6 @@
 use all_aoc::helper::permutations::IteratorPermutator as _;
 
-all_aoc::solution!(3, 2016);
+all_aoc::solution!(3, 2016, tags = ["math"]);
 fn is_valid(a: u32, b: u32, c: u32) -> bool {
     let tri = [a, b, c];
     tri.iter().permutation().all(|w| w[0] + w[1] > *w[2])
@@ -29,19 +29,3 @@ fn parse(input: &str) -> Vec<u32> {
         .flat_map(|l| l.split_ascii_whitespace().map(|n| n.parse().unwrap()))
         .collect()
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_032));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_838));
-    }
-}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::misc::Joinable as _;
 
-all_aoc::solution!(4, 2016);
+all_aoc::solution!(4, 2016, tags = ["string"]);
 struct Room {
     encrypted_name: Vec<String>,
     id: u32,
@@ -40,10 +40,7 @@ impl FromStr for Room {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (a, b) = s.split_once('[').unwrap();
         let checksum = b[0..b.len() - 1].to_string();
-        let mut encrypted_name = a
-            .split('-')
-            .map(std::string::ToString::to_string)
-            .collect::<Vec<_>>();
+        let mut encrypted_name = a.split('-').map(str::to_owned).collect::<Vec<_>>();
         let id = encrypted_name.pop().unwrap().parse().unwrap();
         Ok(Self {
             encrypted_name,
@@ -83,16 +80,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(1_514));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(245_102));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(324));
-    }
 }
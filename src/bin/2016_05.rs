@@ -1,6 +1,6 @@
 use all_aoc::helper::md5::md5;
 
-all_aoc::solution!(5, 2016);
+all_aoc::solution!(5, 2016, tags = ["hashing", "brute-force"]);
 struct Md5iterator {
     door_id: String,
     index: u32,
@@ -69,21 +69,9 @@ mod tests {
         assert_eq!(result, Some("18f47a30".to_owned()));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("f77a0e6e".to_owned()));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some("05ace8e3".to_owned()));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("999828ec".to_owned()));
-    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(6, 2016);
+all_aoc::solution!(6, 2016, tags = ["string"]);
 #[derive(Clone, Copy)]
 enum Part {
     One,
@@ -43,21 +43,9 @@ mod tests {
         assert_eq!(result, Some("easter".to_owned()));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("xdkzukcf".to_owned()));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some("advent".to_owned()));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("cevsgyvd".to_owned()));
-    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-all_aoc::solution!(7, 2016);
+all_aoc::solution!(7, 2016, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(input.lines().filter(|l| support_tls(l)).count())
@@ -55,7 +55,8 @@ fn support_ssl(input: &str) -> bool {
         .map(|s| s.chars().collect::<Vec<_>>())
         .flat_map(|v| {
             v.windows(3)
-                .filter(|&w| w[0] == w[2] && w[0] != w[1]).map(|w| (w[0], w[1]))
+                .filter(|&w| w[0] == w[2] && w[0] != w[1])
+                .map(|w| (w[0], w[1]))
                 .collect::<Vec<_>>()
         })
         .collect::<HashSet<_>>();
@@ -84,12 +85,6 @@ mod tests {
         assert!(support_tls("ioxxoj[asdfgh]zxcvbn"));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(110));
-    }
-
     #[test]
     fn test_part_two() {
         assert!(support_ssl("aba[bab]xyz"));
@@ -97,10 +92,4 @@ mod tests {
         assert!(support_ssl("aaa[kek]eke"));
         assert!(support_ssl("zazbz[bzb]cdb"));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(242));
-    }
 }
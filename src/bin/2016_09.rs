@@ -1,4 +1,4 @@
-all_aoc::solution!(9, 2016);
+all_aoc::solution!(9, 2016, tags = ["string", "parsing"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(decompressd_length_1(input))
@@ -121,12 +121,6 @@ mod tests {
         assert_eq!(part_one("X(8x2)(3x3)ABCY"), Some(18));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(98_135));
-    }
-
     #[test]
     fn test_part_two() {
         assert_eq!(part_two("(3x3)XYZ"), Some(9));
@@ -140,10 +134,4 @@ mod tests {
             Some(445)
         );
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(10_964_557_606));
-    }
 }
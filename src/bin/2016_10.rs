@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(10, 2016);
+all_aoc::solution!(10, 2016, tags = ["simulation"]);
 #[derive(Debug, Clone, Copy)]
 enum GoesTo {
     Bot(u8),
@@ -118,19 +118,3 @@ fn parse(input: &str) -> HashMap<u8, Bot> {
     }
     map
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(116));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(23_903));
-    }
-}
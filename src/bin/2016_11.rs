@@ -6,7 +6,7 @@ use all_aoc::helper::{
     permutations::IteratorCombinator as _,
 };
 
-all_aoc::solution!(11, 2016);
+all_aoc::solution!(11, 2016, tags = ["search"]);
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 struct State<const T: usize> {
     elevator: u64,
@@ -283,16 +283,4 @@ mod tests {
         assert!(State::test().is_possible());
         assert_eq!(result, Some(11));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(33));
-    }
-    #[cfg(feature = "expensive")]
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(57));
-    }
 }
@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(12, 2016);
+all_aoc::solution!(12, 2016, tags = ["vm"]);
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum Register {
@@ -176,20 +176,3 @@ pub fn part_one(input: &str) -> Option<isize> {
 pub fn part_two(input: &str) -> Option<isize> {
     Some(executer(input, State::part2()))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(318_020));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(9_227_674));
-    }
-}
@@ -6,7 +6,7 @@ use all_aoc::helper::{
     position::Position,
 };
 
-all_aoc::solution!(13, 2016);
+all_aoc::solution!(13, 2016, tags = ["grid", "graph"]);
 #[derive(PartialEq, Clone, Copy, Debug)]
 enum Tile {
     Space,
@@ -66,16 +66,4 @@ mod tests {
         let result = shortest_path(Position { x: 7, y: 4 }, 10);
         assert_eq!(result, Some(11));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(96));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(141));
-    }
 }
@@ -2,7 +2,7 @@ use std::collections::{HashMap, hash_map::Entry};
 
 use all_aoc::helper::md5;
 
-all_aoc::solution!(14, 2016);
+all_aoc::solution!(14, 2016, tags = ["hashing"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let test = (0..)
@@ -82,19 +82,3 @@ fn five_chars_part2(salt: &str, index: usize, ch: char, map: &mut HashMap<usize,
     }
     false
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(18626));
-    }
-    #[cfg(feature = "expensive")]
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(20092));
-    }
-}
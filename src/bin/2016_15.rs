@@ -1,6 +1,6 @@
 use core::{num::ParseIntError, str::FromStr};
 
-all_aoc::solution!(15, 2016);
+all_aoc::solution!(15, 2016, tags = ["number-theory"]);
 struct Disc {
     pos_count: usize,
     starting_pos: usize,
@@ -58,16 +58,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(5));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(400_589));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_045_959));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(16, 2016);
+all_aoc::solution!(16, 2016, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<String> {
     solve(input, 272)
@@ -49,16 +49,4 @@ mod tests {
         let result = solve("10000", 20);
         assert_eq!(result, Some("01100".to_owned()));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("10011010010010010".to_owned()));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("10101011110100011".to_owned()));
-    }
 }
@@ -1,6 +1,6 @@
 use all_aoc::helper::md5;
 
-all_aoc::solution!(17, 2016);
+all_aoc::solution!(17, 2016, tags = ["grid", "search", "hashing"]);
 struct State {
     curr_room: isize,
     history: String,
@@ -131,12 +131,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("RDURRDDLRD".to_owned()));
-    }
-
     #[test]
     fn test_part_two() {
         let input = &all_aoc::cli::read_examples_file(DAY);
@@ -146,10 +140,4 @@ mod tests {
         assert_eq!(part_two(it.next().unwrap()), Some(492));
         assert_eq!(part_two(it.next().unwrap()), Some(830));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(526));
-    }
 }
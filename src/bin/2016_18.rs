@@ -1,4 +1,4 @@
-all_aoc::solution!(18, 2016);
+all_aoc::solution!(18, 2016, tags = ["grid", "simulation"]);
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Safe,
@@ -74,19 +74,3 @@ fn solve(input: &str, length: usize) -> Option<usize> {
     field.fill();
     Some(field.count())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_989));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(19_999_894));
-    }
-}
@@ -1,4 +1,4 @@
-all_aoc::solution!(19, 2016);
+all_aoc::solution!(19, 2016, tags = ["simulation"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(oesis_part1(input.parse().unwrap()))
@@ -36,19 +36,3 @@ const fn oesis_part2(n: usize) -> usize {
         x + 2 * (n % x)
     }
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_808_357));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_407_007));
-    }
-}
@@ -2,7 +2,7 @@ use core::ops::RangeInclusive;
 
 use all_aoc::helper::range::ExtRangeOps as _;
 
-all_aoc::solution!(20, 2016);
+all_aoc::solution!(20, 2016, tags = ["ranges"]);
 #[derive(Debug)]
 struct Allowed(Vec<RangeInclusive<u32>>);
 impl Allowed {
@@ -57,16 +57,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(3));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(17_348_574));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(104));
-    }
 }
@@ -2,7 +2,7 @@ use core::{ops::RangeInclusive, str::FromStr};
 
 use all_aoc::helper::permutations::IteratorPermutator as _;
 
-all_aoc::solution!(21, 2016);
+all_aoc::solution!(21, 2016, tags = ["string"]);
 enum Instruction {
     SwapPos(usize, usize),
     SwapLetter(char, char),
@@ -128,19 +128,3 @@ fn parse(input: &str) -> Vec<Instruction> {
         .map(|line| Instruction::from_str(line).unwrap())
         .collect()
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("dbfgaehc".to_owned()));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("aghfcdeb".to_owned()));
-    }
-}
@@ -6,7 +6,7 @@ use core::{
 
 use all_aoc::helper::permutations::IteratorPermutator as _;
 use std::collections::{HashMap, VecDeque};
-all_aoc::solution!(22, 2016);
+all_aoc::solution!(22, 2016, tags = ["grid", "graph"]);
 
 #[derive(Clone, Copy, Debug)]
 struct Node {
@@ -159,25 +159,8 @@ fn parse(input: &str) -> Vec<Vec<Node>> {
     let mut map: Vec<Vec<Node>> = vec![];
     for x in 0..=max_x {
         let mut col: Vec<_> = vec.iter().filter(|&&n| n.pos.0 == x).copied().collect();
-        col.sort_by(|n1, n2| n1.pos.1.cmp(&n2.pos.1));
+        col.sort_by_key(|n1| n1.pos.1);
         map.push(col);
     }
     map
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(872));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(211));
-    }
-}
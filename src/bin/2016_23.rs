@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(23, 2016);
+all_aoc::solution!(23, 2016, tags = ["vm"]);
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum Register {
@@ -217,16 +217,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(3));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(12_663));
-    }
-    #[cfg(feature = "expensive")]
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(479_009_223));
-    }
 }
@@ -6,7 +6,7 @@ use all_aoc::helper::{
     permutations::IteratorPermutator as _,
 };
 
-all_aoc::solution!(24, 2016);
+all_aoc::solution!(24, 2016, tags = ["grid", "graph"]);
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Wall,
@@ -97,21 +97,9 @@ mod tests {
         assert_eq!(result, Some(14));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(470));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(20));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(720));
-    }
 }
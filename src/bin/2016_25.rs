@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(25, 2016);
+all_aoc::solution!(25, 2016, tags = ["vm"]);
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum Register {
     A,
@@ -198,14 +198,3 @@ fn parse(input: &str) -> Vec<Instruction> {
         .map(|line| Instruction::from_str(line).unwrap())
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(196));
-    }
-}
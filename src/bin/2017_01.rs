@@ -1,4 +1,4 @@
-all_aoc::solution!(1, 2017);
+all_aoc::solution!(1, 2017, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let vec = parse(input);
@@ -38,12 +38,6 @@ mod tests {
         assert_eq!(part_one("91212129"), Some(9));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_216));
-    }
-
     #[test]
     fn test_part_two() {
         assert_eq!(part_two("1212"), Some(6));
@@ -52,10 +46,4 @@ mod tests {
         assert_eq!(part_two("123123"), Some(12));
         assert_eq!(part_two("12131415"), Some(4));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_072));
-    }
 }
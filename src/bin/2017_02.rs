@@ -1,4 +1,4 @@
-all_aoc::solution!(2, 2017);
+all_aoc::solution!(2, 2017, tags = ["math"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(
@@ -31,20 +31,3 @@ fn parse(input: &str) -> impl Iterator<Item = Vec<u32>> {
             .collect()
     })
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(42_378));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(246));
-    }
-}
@@ -4,7 +4,7 @@ use all_aoc::helper::{
     position::{Direction4, Position},
 };
 
-all_aoc::solution!(3, 2017);
+all_aoc::solution!(3, 2017, tags = ["grid", "math"]);
 fn ulam_spiral(edge_length: usize) -> DenseGrid<u64> {
     let mut grid = DenseGrid::new(edge_length, edge_length, 0);
     debug_assert!(!edge_length.is_multiple_of(2));
@@ -95,20 +95,3 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     Some(val)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(371));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(369_601));
-    }
-}
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use all_aoc::helper::{misc::count_occurrences, permutations::IteratorCombinator as _};
 
-all_aoc::solution!(4, 2017);
+all_aoc::solution!(4, 2017, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(parse(input).filter(|l| is_valid_part_one(l)).count())
@@ -38,15 +38,4 @@ mod tests {
         assert_eq!(part_two("iiii oiii ooii oooi oooo"), Some(1));
         assert_eq!(part_two("oiii ioii iioi iiio"), Some(0));
     }
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(325));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(119));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(5, 2017);
+all_aoc::solution!(5, 2017, tags = ["simulation"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut vec = input
@@ -33,20 +33,3 @@ pub fn part_two(input: &str) -> Option<u32> {
     }
     Some(ctr)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(315_613));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(22_570_529));
-    }
-}
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-all_aoc::solution!(6, 2017);
+all_aoc::solution!(6, 2017, tags = ["simulation", "cycle-detection"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut vec = input
@@ -56,20 +56,3 @@ pub fn part_two(input: &str) -> Option<usize> {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_156));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1610));
-    }
-}
@@ -5,7 +5,7 @@ use all_aoc::helper::{
     misc::count_occurrences,
 };
 
-all_aoc::solution!(7, 2017);
+all_aoc::solution!(7, 2017, tags = ["graph"]);
 
 pub fn part_one(input: &str) -> Option<String> {
     let (index_map, _, graph) = parse(input);
@@ -106,21 +106,9 @@ mod tests {
         assert_eq!(result, Some("tknk".to_owned()));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("bpvhwhh".to_owned()));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(60));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(256));
-    }
 }
@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::HashMap;
 
-all_aoc::solution!(8, 2017);
+all_aoc::solution!(8, 2017, tags = ["vm"]);
 struct Instruction {
     reg: Register,
     op: Operation,
@@ -159,21 +159,9 @@ mod tests {
         assert_eq!(result, Some(1));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_647));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(10));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(5_590));
-    }
 }
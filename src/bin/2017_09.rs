@@ -1,4 +1,4 @@
-all_aoc::solution!(9, 2017);
+all_aoc::solution!(9, 2017, tags = ["parsing"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut vec: Vec<_> = input.chars().collect();
@@ -73,20 +73,3 @@ fn filter_garabge(vec: &mut Vec<char>) {
         curr += 1;
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(12_803));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_425));
-    }
-}
@@ -1,5 +1,5 @@
 use core::fmt::Write as _;
-all_aoc::solution!(10, 2017);
+all_aoc::solution!(10, 2017, tags = ["hashing"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let mut vec = parse1(input);
@@ -65,19 +65,3 @@ fn parse2(input: &str) -> Vec<usize> {
     v.append(&mut vec![17, 31, 73, 47, 23]);
     v
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(62_238));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("2b0c9cc0449507a0db3babd57ad9e8d8".to_owned()));
-    }
-}
@@ -1,6 +1,6 @@
 use all_aoc::helper::position::Direction8;
 
-all_aoc::solution!(11, 2017);
+all_aoc::solution!(11, 2017, tags = ["geometry"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let vec = parse(input);
@@ -86,20 +86,3 @@ fn parse(input: &str) -> Vec<Direction8> {
         .map(|s| Direction8::from_short_name(s).unwrap())
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(834));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_569));
-    }
-}
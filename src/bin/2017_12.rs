@@ -1,6 +1,6 @@
 use all_aoc::helper::graph::{Graph as _, Special, WithWeights as _};
 
-all_aoc::solution!(12, 2017);
+all_aoc::solution!(12, 2017, tags = ["graph"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let graph = parse(input);
@@ -34,21 +34,9 @@ mod tests {
         assert_eq!(result, Some(6));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(141));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(2));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(171));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(13, 2017);
+all_aoc::solution!(13, 2017, tags = ["number-theory", "brute-force"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let mask = parse(input);
@@ -75,21 +75,9 @@ mod tests {
         assert_eq!(result, Some(24));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_184));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(10));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_878_062));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(15, 2017);
+all_aoc::solution!(15, 2017, tags = ["simulation"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (a, b) = parse(input);
@@ -56,21 +56,9 @@ mod tests {
         assert_eq!(result, Some(588));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(612));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(309));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(285));
-    }
 }
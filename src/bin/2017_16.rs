@@ -1,5 +1,5 @@
 use core::str::FromStr;
-all_aoc::solution!(16, 2017);
+all_aoc::solution!(16, 2017, tags = ["string", "cycle-detection"]);
 #[derive(Clone, Copy)]
 enum Instruction {
     Spin(usize),
@@ -87,20 +87,3 @@ fn parse(input: &str) -> Vec<Instruction> {
         .map(|s| Instruction::from_str(s).unwrap())
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("fnloekigdmpajchb".to_owned()));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("amkjepdhifolgncb".to_owned()));
-    }
-}
@@ -1,4 +1,4 @@
-all_aoc::solution!(17, 2017);
+all_aoc::solution!(17, 2017, tags = ["simulation"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let input: usize = input.parse().unwrap();
@@ -42,20 +42,3 @@ pub fn part_two(input: &str) -> Option<usize> {
     }
     Some(next_after_zero)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(808));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(47_465_686));
-    }
-}
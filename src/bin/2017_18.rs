@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::HashMap;
 
-all_aoc::solution!(18, 2017);
+all_aoc::solution!(18, 2017, tags = ["vm"]);
 
 enum Value {
     Number(isize),
@@ -308,21 +308,9 @@ mod tests {
         assert_eq!(result, Some(4));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(7_071));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(1));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(8_001));
-    }
 }
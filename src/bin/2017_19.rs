@@ -3,7 +3,7 @@ use all_aoc::helper::{
     position::{Direction4, Position},
 };
 
-all_aoc::solution!(19, 2017);
+all_aoc::solution!(19, 2017, tags = ["grid", "simulation"]);
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Air,
@@ -98,21 +98,9 @@ mod tests {
         assert_eq!(result, Some("ABCDEF".to_owned()));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("SXWAIBUZY".to_owned()));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(38));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(16_676));
-    }
 }
@@ -3,7 +3,7 @@ use std::collections::BTreeSet;
 
 use all_aoc::helper::{permutations::IteratorCombinator as _, position3d::Position3d};
 
-all_aoc::solution!(20, 2017);
+all_aoc::solution!(20, 2017, tags = ["simulation"]);
 #[derive(Clone, Copy)]
 struct Particle {
     position: Position3d<isize>,
@@ -207,19 +207,3 @@ fn remove_indices<T>(vec: &mut Vec<T>, indices: &BTreeSet<usize>) {
         }
     }
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(243));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(648));
-    }
-}
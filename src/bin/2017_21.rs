@@ -1,81 +1,36 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(21, 2017);
-type Grid = Vec<Vec<bool>>;
+use all_aoc::helper::grid::{Grid as _, dense::DenseGrid};
 
-fn translate_to_grid(s: &str) -> Grid {
-    s.split('/')
-        .map(|row| row.chars().map(|c| c == '#').collect())
-        .collect()
-}
-
-fn rotate(grid: &Grid) -> Grid {
-    let n = grid.len();
-    let mut res = vec![vec![false; n]; n];
-    for (i, v) in grid.iter().enumerate() {
-        for (j, x) in v.iter().enumerate() {
-            res[j][n - 1 - i] = *x;
-        }
-    }
-    res
-}
+all_aoc::solution!(21, 2017, tags = ["grid"]);
+type Grid = DenseGrid<bool>;
 
-fn flip(grid: &Grid) -> Grid {
-    let mut res = grid.clone();
-    for row in &mut res {
-        row.reverse();
-    }
-    res
-}
-
-fn grid_to_key(grid: &Grid) -> Vec<u8> {
-    grid.iter()
-        .flat_map(|row| row.iter().map(|&b| u8::from(b)))
-        .collect()
+fn translate_to_grid(s: &str) -> Grid {
+    DenseGrid::from_iter_iter(s.split('/').map(|row| row.chars().map(|c| c == '#')))
 }
 
-fn parse_rules(input: &str) -> HashMap<Vec<u8>, Grid> {
+/// Every orientation of a pattern maps to its enhancement, so that a square can be looked up
+/// as it is.
+fn parse_rules(input: &str) -> HashMap<Grid, Grid> {
     let mut mappings = HashMap::new();
     for line in input.lines() {
         let (k, v) = line.trim().split_once(" => ").unwrap();
-        let key_grid = translate_to_grid(k);
         let val_grid = translate_to_grid(v);
-
-        let variants = vec![key_grid.clone(), flip(&key_grid)];
-        for g in variants {
-            let mut r = g.clone();
-            for _ in 0..4 {
-                mappings.insert(grid_to_key(&r), val_grid.clone());
-                r = rotate(&r);
-            }
+        for key in translate_to_grid(k).orientations() {
+            mappings.insert(key, val_grid.clone());
         }
     }
     mappings
 }
 
-fn enhance(grid: &Grid, mappings: &HashMap<Vec<u8>, Grid>) -> Grid {
-    let size = grid.len();
-    let by = if size.is_multiple_of(2) { 2 } else { 3 };
-    let new_size = size * (by + 1) / by;
-    let mut solution = vec![vec![false; new_size]; new_size];
-
-    for (i, ni) in (0..size).step_by(by).zip((0..new_size).step_by(by + 1)) {
-        for (j, nj) in (0..size).step_by(by).zip((0..new_size).step_by(by + 1)) {
-            let mut square = vec![vec![false; by]; by];
-            for x in 0..by {
-                for y in 0..by {
-                    square[x][y] = grid[i + x][j + y];
-                }
-            }
-            let enhanced = mappings.get(&grid_to_key(&square)).unwrap();
-            for x in 0..=by {
-                for y in 0..=by {
-                    solution[ni + x][nj + y] = enhanced[x][y];
-                }
-            }
-        }
-    }
-    solution
+fn enhance(grid: &Grid, mappings: &HashMap<Grid, Grid>) -> Grid {
+    let by = if grid.width().is_multiple_of(2) { 2 } else { 3 };
+    let squares = grid.split_blocks(by);
+    let enhanced = DenseGrid::from_iter(
+        squares.iter().map(|square| mappings[square].clone()),
+        squares.width(),
+    );
+    DenseGrid::from_blocks(&enhanced)
 }
 
 fn solve(input: &str, iterations: usize) -> Option<u32> {
@@ -84,14 +39,7 @@ fn solve(input: &str, iterations: usize) -> Option<u32> {
     for _ in 0..iterations {
         grid = enhance(&grid, &mappings);
     }
-    Some(
-        grid.iter()
-            .flatten()
-            .filter(|&&b| b)
-            .count()
-            .try_into()
-            .unwrap(),
-    )
+    Some(grid.iter().filter(|&&b| b).count().try_into().unwrap())
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -101,20 +49,3 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     solve(input, 18)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(162));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_264_586));
-    }
-}
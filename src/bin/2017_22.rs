@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::position::{Direction4, Position};
 
-all_aoc::solution!(22, 2017);
+all_aoc::solution!(22, 2017, tags = ["grid", "simulation"]);
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 enum Tile {
     #[default]
@@ -101,21 +101,9 @@ mod tests {
         assert_eq!(result, Some(5_587));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(5_462));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(2_511_944));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_512_135));
-    }
 }
@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::HashMap;
 
-all_aoc::solution!(23, 2017);
+all_aoc::solution!(23, 2017, tags = ["vm", "number-theory"]);
 #[derive(Clone, Copy)]
 enum Instruction {
     Set(Register, Value),
@@ -148,20 +148,3 @@ pub const fn part_two(_: &str) -> Option<u32> {
 fn parse(input: &str) -> Vec<Instruction> {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_724));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(903));
-    }
-}
@@ -1,4 +1,4 @@
-all_aoc::solution!(24, 2017);
+all_aoc::solution!(24, 2017, tags = ["search"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let vec = parse(input);
@@ -74,21 +74,9 @@ mod tests {
         assert_eq!(result, Some(31));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_695));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(19));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_673));
-    }
 }
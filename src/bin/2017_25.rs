@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(25, 2017);
+all_aoc::solution!(25, 2017, tags = ["simulation"]);
 struct State {
     write_if_zero: bool,
     write_if_one: bool,
@@ -138,10 +138,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(3));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_099));
-    }
 }
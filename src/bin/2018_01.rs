@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-all_aoc::solution!(1, 2018);
+all_aoc::solution!(1, 2018, tags = ["simulation"]);
 
 pub fn part_one(input: &str) -> Option<i32> {
     Some(parse(input).sum())
@@ -20,19 +20,3 @@ pub fn part_two(input: &str) -> Option<i32> {
 fn parse(input: &str) -> impl Iterator<Item = i32> + Clone {
     input.lines().map(|n| n.parse().unwrap())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(520));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(394));
-    }
-}
@@ -1,6 +1,6 @@
 use all_aoc::helper::{misc::count_occurrences, permutations::IteratorCombinator as _};
 
-all_aoc::solution!(2, 2018);
+all_aoc::solution!(2, 2018, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let (mut x, mut y) = (0, 0);
@@ -36,21 +36,9 @@ pub fn part_two(input: &str) -> Option<String> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(9_139));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some("fgij".into()));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("uqcidadzwtnhsljvxyobmkfyr".into()));
-    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(3, 2018);
+all_aoc::solution!(3, 2018, tags = ["grid"]);
 struct Claim {
     id: usize,
     x_diff: usize,
@@ -81,21 +81,9 @@ mod tests {
         assert_eq!(result, Some(4));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(110_383));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(3));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(129));
-    }
 }
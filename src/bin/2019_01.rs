@@ -1,4 +1,4 @@
-all_aoc::solution!(1, 2019);
+all_aoc::solution!(1, 2019, tags = ["math"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(parse(input).map(sum_part_one).sum())
@@ -24,19 +24,3 @@ const fn sum_part_two(i: u32) -> u32 {
     }
     sum
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_235_550));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_850_462));
-    }
-}
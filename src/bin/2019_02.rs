@@ -1,6 +1,6 @@
 use all_aoc::helper::intcode::{IntInteger, Intcode};
 
-all_aoc::solution!(2, 2019);
+all_aoc::solution!(2, 2019, tags = ["intcode"]);
 fn execute(noun: IntInteger, verb: IntInteger, program: &[IntInteger]) -> IntInteger {
     let mut m = Intcode::new(program.to_vec());
     m[1] = noun;
@@ -23,19 +23,3 @@ pub fn part_two(input: &str) -> Option<IntInteger> {
 fn parse(input: &str) -> Vec<IntInteger> {
     input.split(',').map(|n| n.parse().unwrap()).collect()
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_627_023));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_019));
-    }
-}
@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 
 use all_aoc::helper::position::{Direction4, Position};
 
-all_aoc::solution!(3, 2019);
+all_aoc::solution!(3, 2019, tags = ["grid", "geometry"]);
 struct Instruction {
     dir: Direction4,
     len: u32,
@@ -107,12 +107,6 @@ mod tests {
         assert_eq!(result, Some(135));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(232));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(
@@ -130,10 +124,4 @@ mod tests {
         );
         assert_eq!(result, Some(410));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_084));
-    }
 }
@@ -1,6 +1,6 @@
 use core::iter;
 
-all_aoc::solution!(4, 2019);
+all_aoc::solution!(4, 2019, tags = ["brute-force"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (start, end) = parse(input);
@@ -48,17 +48,6 @@ fn parse(input: &str) -> (u32, u32) {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(921));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(603));
-    }
     #[test]
     fn test() {
         assert!(adjacent_are_same_but_not_more(112_233));
@@ -1,6 +1,6 @@
 use all_aoc::helper::intcode::{InputMode, IntInteger, Intcode};
 
-all_aoc::solution!(5, 2019);
+all_aoc::solution!(5, 2019, tags = ["intcode"]);
 
 pub fn part_one(input: &str) -> Option<IntInteger> {
     let vec = parse(input);
@@ -26,19 +26,3 @@ pub fn part_two(input: &str) -> Option<IntInteger> {
 fn parse(input: &str) -> Vec<IntInteger> {
     input.split(',').map(|n| n.parse().unwrap()).collect()
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(15_386_262));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(10_376_124));
-    }
-}
@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
-use all_aoc::helper::graph::{Graph as _, WithWeights as _, Special};
+use all_aoc::helper::graph::{Graph as _, Special, WithWeights as _};
 
-all_aoc::solution!(6, 2019);
+all_aoc::solution!(6, 2019, tags = ["graph"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (vec, _) = translate_indexes(input);
@@ -64,12 +64,6 @@ mod tests {
         assert_eq!(result, Some(42));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(621_125));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(
@@ -80,10 +74,4 @@ mod tests {
         );
         assert_eq!(result, Some(4));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(550));
-    }
 }
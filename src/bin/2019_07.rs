@@ -3,7 +3,7 @@ use all_aoc::helper::{
     permutations::IteratorPermutator as _,
 };
 
-all_aoc::solution!(7, 2019);
+all_aoc::solution!(7, 2019, tags = ["intcode"]);
 
 pub fn part_one(input: &str) -> Option<IntInteger> {
     let computer = parse(input);
@@ -81,12 +81,6 @@ mod tests {
         assert_eq!(result[2], Some(65_210));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(21_760));
-    }
-
     #[test]
     fn test_part_two() {
         let result = &all_aoc::cli::read_examples_file(DAY)
@@ -98,10 +92,4 @@ mod tests {
         assert_eq!(result[0], Some(139_629_729));
         assert_eq!(result[1], Some(18_216));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(69_816_958));
-    }
 }
@@ -1,7 +1,7 @@
 use core::fmt::Display;
 
 use all_aoc::helper::misc::Joinable as _;
-all_aoc::solution!(8, 2019);
+all_aoc::solution!(8, 2019, tags = ["grid"]);
 
 #[derive(Clone, Copy)]
 enum Color {
@@ -88,12 +88,6 @@ fn parse(input: &str) -> Vec<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_250));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two_runner(&all_aoc::cli::read_examples_file(DAY), (2, 2));
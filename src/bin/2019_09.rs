@@ -1,6 +1,6 @@
 use all_aoc::helper::intcode::{InputMode, IntInteger, Intcode};
 
-all_aoc::solution!(9, 2019);
+all_aoc::solution!(9, 2019, tags = ["intcode"]);
 fn execute(input: &str, x: isize) -> Option<isize> {
     let vec = parse(input);
     let mut m = Intcode::new(vec);
@@ -20,19 +20,3 @@ pub fn part_two(input: &str) -> Option<isize> {
 fn parse(input: &str) -> Vec<IntInteger> {
     input.split(',').map(|n| n.parse().unwrap()).collect()
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_288_078_517));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(69_256));
-    }
-}
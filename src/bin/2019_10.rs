@@ -6,7 +6,7 @@ use all_aoc::helper::{
     position::Position,
 };
 
-all_aoc::solution!(10, 2019);
+all_aoc::solution!(10, 2019, tags = ["grid", "geometry"]);
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Empty,
@@ -182,21 +182,9 @@ mod tests {
         assert_eq!(result, Some(210));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(299));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(802));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1419));
-    }
 }
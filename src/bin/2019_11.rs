@@ -4,10 +4,11 @@ use std::collections::{HashMap, HashSet};
 use all_aoc::helper::{
     intcode::{InputMode, Intcode, Return},
     misc::Joinable as _,
+    ocr::read_positions,
     position::{Direction4, Position},
 };
 
-all_aoc::solution!(11, 2019);
+all_aoc::solution!(11, 2019, tags = ["intcode", "grid"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let computer = parse(input);
@@ -17,6 +18,14 @@ pub fn part_one(input: &str) -> Option<usize> {
 pub fn part_two(input: &str) -> Option<String> {
     let computer = parse(input);
     let map = run(computer, 1).0;
+    // the robot paints with y pointing up
+    let lit = map
+        .iter()
+        .filter(|(_, color)| **color == 1)
+        .map(|(p, _)| Position { x: p.x, y: -p.y });
+    if let Ok(letters) = read_positions(lit) {
+        return Some(letters);
+    }
     let x_min = map.keys().map(|p| p.x).min().unwrap();
     let x_max = map.keys().map(|p| p.x).max().unwrap();
     let y_min = map.keys().map(|p| p.y).min().unwrap();
@@ -36,7 +45,10 @@ pub fn part_two(input: &str) -> Option<String> {
             .join("\n"),
     )
 }
-fn run(computer: Intcode, start: isize) -> (HashMap<Position<i32>, isize>, HashSet<Position<i32>>) {
+fn run(
+    computer: Intcode,
+    start: isize,
+) -> (HashMap<Position<isize>, isize>, HashSet<Position<isize>>) {
     let mut computer = computer;
     let mut pos = Position { x: 0, y: 0 };
     let mut grid = HashMap::new();
@@ -72,13 +84,3 @@ fn run(computer: Intcode, start: isize) -> (HashMap<Position<i32>, isize>, HashS
 fn parse(input: &str) -> Intcode {
     Intcode::new(input.split(',').map(|n| n.parse().unwrap()).collect())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_392));
-    }
-}
@@ -6,7 +6,7 @@ use all_aoc::helper::{
     position::Position,
 };
 
-all_aoc::solution!(13, 2019);
+all_aoc::solution!(13, 2019, tags = ["intcode"]);
 #[derive(Debug, PartialEq, Eq)]
 enum Tile {
     Empty,
@@ -109,19 +109,3 @@ pub fn part_two(input: &str) -> Option<isize> {
 fn parse(input: &str) -> Intcode {
     Intcode::new(input.split(',').map(|x| x.parse().unwrap()).collect())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(226));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(10_800));
-    }
-}
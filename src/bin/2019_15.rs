@@ -8,7 +8,7 @@ use all_aoc::helper::{
     position::{Direction4, Position},
 };
 
-all_aoc::solution!(15, 2019);
+all_aoc::solution!(15, 2019, tags = ["intcode", "grid", "graph"]);
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Air,
@@ -166,19 +166,3 @@ pub fn part_two(input: &str) -> Option<u32> {
 fn parse(input: &str) -> Intcode {
     Intcode::new(input.split(',').map(|n| n.parse().unwrap()).collect())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(204));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(340));
-    }
-}
@@ -1,4 +1,4 @@
-all_aoc::solution!(16, 2019);
+all_aoc::solution!(16, 2019, tags = ["math"]);
 pub fn part_one(input: &str) -> Option<i32> {
     solve_part_one(input, 100)
 }
@@ -73,12 +73,6 @@ mod tests {
         assert_eq!(solve_part_one(iter.next().unwrap(), 100), Some(52_432_133));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(34_694_616));
-    }
-
     #[test]
     fn test_part_two() {
         let input = &all_aoc::cli::read_examples_file(DAY);
@@ -88,10 +82,4 @@ mod tests {
         assert_eq!(part_two(iter.next().unwrap()), Some(78_725_270));
         assert_eq!(part_two(iter.next().unwrap()), Some(53_553_731));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(17_069_048));
-    }
 }
@@ -63,7 +63,7 @@ impl Debug for Tile {
         }
     }
 }
-all_aoc::solution!(17, 2019);
+all_aoc::solution!(17, 2019, tags = ["intcode", "grid"]);
 fn get_grid(mut computer: Intcode) -> DenseGrid<Tile> {
     let erg = computer.execute();
     debug_assert_eq!(erg, Return::Finished);
@@ -260,19 +260,3 @@ fn divide(
 fn parse(input: &str) -> Intcode {
     Intcode::new(input.split(',').map(|x| x.parse().unwrap()).collect())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_408));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(862_452));
-    }
-}
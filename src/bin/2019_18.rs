@@ -7,7 +7,7 @@ use all_aoc::helper::{
     position::Direction8,
 };
 
-all_aoc::solution!(18, 2019);
+all_aoc::solution!(18, 2019, tags = ["grid", "graph"]);
 #[derive(Debug, Clone, Copy)]
 enum Tile {
     Wall,
@@ -254,12 +254,6 @@ mod tests {
         assert_eq!(results, [Some(8), Some(86), Some(132), Some(136), Some(81)]);
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_118));
-    }
-
     #[test]
     fn test_part_two() {
         let results = all_aoc::cli::read_examples_file(DAY)
@@ -269,10 +263,4 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(results, [Some(8), Some(24), Some(32), Some(72)]);
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_828));
-    }
 }
@@ -1,5 +1,5 @@
 use all_aoc::helper::intcode::{InputMode, IntInteger, Intcode};
-all_aoc::solution!(19, 2019);
+all_aoc::solution!(19, 2019, tags = ["intcode", "grid"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let computer = parse(input);
@@ -56,20 +56,3 @@ fn is_attracted(computer: &Intcode, x: IntInteger, y: IntInteger) -> bool {
 fn parse(input: &str) -> Intcode {
     Intcode::new(input.split(',').map(|n| n.parse().unwrap()).collect())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(229));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_950_903));
-    }
-}
@@ -24,7 +24,7 @@ fn is_facing(grid: &DenseGrid<Tile>, pos: Position<usize>) -> Facing {
         Facing::Inner
     }
 }
-all_aoc::solution!(20, 2019);
+all_aoc::solution!(20, 2019, tags = ["grid", "graph"]);
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Air,
@@ -235,21 +235,9 @@ mod tests {
         assert_eq!(result, Some(77));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(686));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(396));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(8_384));
-    }
 }
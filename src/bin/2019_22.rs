@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(22, 2019);
+all_aoc::solution!(22, 2019, tags = ["number-theory"]);
 enum Instruction {
     DealIncrement(i128),
     Cut(i128),
@@ -101,19 +101,3 @@ const fn mod_pow(mut base: i128, mut exp: i128, m: i128) -> i128 {
 fn parse(input: &str) -> impl Iterator<Item = Instruction> {
     input.lines().map(|l| Instruction::from_str(l).unwrap())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_074));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(104_073_967_000_066));
-    }
-}
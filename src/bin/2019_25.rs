@@ -1,4 +1,4 @@
-all_aoc::solution!(25, 2019);
+all_aoc::solution!(25, 2019, tags = ["intcode"]);
 
 use core::iter;
 use std::collections::{HashMap, HashSet};
@@ -307,14 +307,3 @@ fn go_to(
 pub const fn part_two(_: &str) -> Option<u32> {
     None
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_147_502_592));
-    }
-}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::{bitmask::Bitmask as _, permutations::IteratorCombinator as _};
 
-all_aoc::solution!(14, 2020);
+all_aoc::solution!(14, 2020, tags = ["simulation"]);
 #[derive(Debug)]
 enum Instruction {
     Mask(Vec<(usize, bool)>),
@@ -131,12 +131,6 @@ mod tests {
         assert_eq!(result, Some(165));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(12_512_013_221_615));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(
@@ -147,10 +141,4 @@ mod tests {
         );
         assert_eq!(result, Some(208));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_905_642_473_893));
-    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(15, 2020);
+all_aoc::solution!(15, 2020, tags = ["simulation"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(number_spoken(parse(input), 2_020))
@@ -45,12 +45,6 @@ mod tests {
         assert_eq!(part_one("3,1,2"), Some(1_836));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_665));
-    }
-
     #[test]
     fn test_part_two_1() {
         assert_eq!(part_two("0,3,6"), Some(175_594));
@@ -79,10 +73,4 @@ mod tests {
     fn test_part_two_7() {
         assert_eq!(part_two("3,1,2"), Some(362));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(16_439));
-    }
 }
@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 
 use all_aoc::helper::misc::count_occurrences;
 
-all_aoc::solution!(16, 2020);
+all_aoc::solution!(16, 2020, tags = ["parsing"]);
 type Ticket = Vec<u64>;
 type Tickets = Vec<Ticket>;
 type Rules = Vec<Rule>;
@@ -54,11 +54,7 @@ fn check_ticket(rules: &Rules, ticket: &Ticket) -> Option<u64> {
 fn check_number(rules: &Rules, x: u64) -> Option<u64> {
     let mut ret = 0;
     for rule in rules {
-        if let Some(e) = rule.check(x) {
-            ret = e;
-        } else {
-            return None;
-        }
+        ret = rule.check(x)?;
     }
     Some(ret)
 }
@@ -184,16 +180,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(71));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(21_996));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(650_080_463_519));
-    }
 }
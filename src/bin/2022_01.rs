@@ -1,4 +1,4 @@
-all_aoc::solution!(1, 2022);
+all_aoc::solution!(1, 2022, tags = ["parsing"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(parse(input).map(core::iter::Iterator::sum).max().unwrap())
@@ -26,21 +26,9 @@ mod tests {
         assert_eq!(result, Some(24_000));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(72_017));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(45_000));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(212_520));
-    }
 }
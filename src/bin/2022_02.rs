@@ -1,4 +1,4 @@
-all_aoc::solution!(2, 2022);
+all_aoc::solution!(2, 2022, tags = ["simulation"]);
 fn score_part_1((opponent, me): (char, char)) -> u32 {
     match (opponent, me) {
         ('A', 'X') => 4,
@@ -50,21 +50,9 @@ mod tests {
         assert_eq!(result, Some(15));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(12_855));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(12));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(13_726));
-    }
 }
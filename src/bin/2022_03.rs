@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-all_aoc::solution!(3, 2022);
+all_aoc::solution!(3, 2022, tags = ["string"]);
 fn calc_rucksack(input: &str) -> u32 {
     let n = input.len() / 2;
     let set_1 = input.chars().take(n).collect::<HashSet<_>>();
@@ -51,21 +51,9 @@ mod tests {
         assert_eq!(result, Some(157));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(7_691));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(70));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_508));
-    }
 }
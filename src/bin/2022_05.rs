@@ -1,4 +1,4 @@
-all_aoc::solution!(5, 2022);
+all_aoc::solution!(5, 2022, tags = ["simulation", "parsing"]);
 
 pub fn part_one(input: &str) -> Option<String> {
     let (mut stacks, ins) = parse(input);
@@ -66,21 +66,9 @@ mod tests {
         assert_eq!(result, Some("CMZ".to_owned()));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("FJSRQCFTN".to_owned()));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some("MCD".to_owned()));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("CJVLJQPHS".to_owned()));
-    }
 }
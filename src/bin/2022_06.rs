@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-all_aoc::solution!(6, 2022);
+all_aoc::solution!(6, 2022, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     solve(input, 4)
@@ -34,12 +34,6 @@ mod tests {
         assert_eq!(v, vec![7, 5, 6, 10, 11]);
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_912));
-    }
-
     #[test]
     fn test_part_two() {
         let input = &all_aoc::cli::read_examples_file(DAY);
@@ -49,10 +43,4 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(v, vec![19, 23, 23, 29, 26]);
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_122));
-    }
 }
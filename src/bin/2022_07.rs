@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::{HashMap, HashSet};
 
-all_aoc::solution!(7, 2022);
+all_aoc::solution!(7, 2022, tags = ["parsing"]);
 enum Line {
     Command(Command),
     List(List),
@@ -199,21 +199,9 @@ mod tests {
         assert_eq!(result, Some(95_437));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_543_140));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(24_933_642));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_117_448));
-    }
 }
@@ -3,7 +3,7 @@ use all_aoc::helper::{
     position::Direction4,
 };
 
-all_aoc::solution!(8, 2022);
+all_aoc::solution!(8, 2022, tags = ["grid"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let grid = parse(input);
@@ -68,21 +68,9 @@ mod tests {
         assert_eq!(result, Some(21));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_705));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(8));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(371_200));
-    }
 }
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 
 use all_aoc::helper::position::{Direction4, Position};
 
-all_aoc::solution!(9, 2022);
+all_aoc::solution!(9, 2022, tags = ["grid", "simulation"]);
 struct Instruction {
     dir: Direction4,
     step_size: i32,
@@ -155,21 +155,9 @@ mod tests {
         assert_eq!(result, Some(13));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_081));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(1));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_487));
-    }
 }
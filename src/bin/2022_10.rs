@@ -1,8 +1,11 @@
 use core::str::FromStr;
 
-use all_aoc::helper::grid::{Grid as _, dense::DenseGrid};
+use all_aoc::helper::{
+    grid::dense::DenseGrid,
+    ocr::{read_grid, render},
+};
 
-all_aoc::solution!(10, 2022);
+all_aoc::solution!(10, 2022, tags = ["vm"]);
 enum Instruction {
     Noop,
     Addx(i32),
@@ -62,94 +65,12 @@ pub fn part_two(input: &str) -> Option<String> {
     }
 
     let grid = DenseGrid::from_iter(sprite.into_iter(), 40);
-
-    let mut chars = grid.split_width(5);
-    for g in &mut chars {
-        g.remove_col(4);
-    }
-    let chars = chars;
-    Some(chars.into_iter().map(|g| grid_to_char(&g)).collect())
-}
-fn grid_to_char(grid: &DenseGrid<bool>) -> char {
-    let vec = grid.iter().copied().collect::<Vec<_>>();
-
-    if vec == E.iter().flatten().copied().collect::<Vec<_>>() {
-        'E'
-    } else if vec == C.iter().flatten().copied().collect::<Vec<_>>() {
-        'C'
-    } else if vec == Z.iter().flatten().copied().collect::<Vec<_>>() {
-        'Z'
-    } else if vec == U.iter().flatten().copied().collect::<Vec<_>>() {
-        'U'
-    } else if vec == A.iter().flatten().copied().collect::<Vec<_>>() {
-        'A'
-    } else if vec == L.iter().flatten().copied().collect::<Vec<_>>() {
-        'L'
-    } else if vec == R.iter().flatten().copied().collect::<Vec<_>>() {
-        'R'
-    } else {
-        unimplemented!()
-    }
+    // unknown letters are left for the reader
+    Some(read_grid(&grid).unwrap_or_else(|_| render(&grid)))
 }
 fn parse(input: &str) -> impl Iterator<Item = Instruction> {
     input.lines().map(|l| Instruction::from_str(l).unwrap())
 }
-const E: [[bool; 4]; 6] = [
-    [true, true, true, true],
-    [true, false, false, false],
-    [true, true, true, false],
-    [true, false, false, false],
-    [true, false, false, false],
-    [true, true, true, true],
-];
-const C: [[bool; 4]; 6] = [
-    [false, true, true, false],
-    [true, false, false, true],
-    [true, false, false, false],
-    [true, false, false, false],
-    [true, false, false, true],
-    [false, true, true, false],
-];
-const Z: [[bool; 4]; 6] = [
-    [true, true, true, true],
-    [false, false, false, true],
-    [false, false, true, false],
-    [false, true, false, false],
-    [true, false, false, false],
-    [true, true, true, true],
-];
-const U: [[bool; 4]; 6] = [
-    [true, false, false, true],
-    [true, false, false, true],
-    [true, false, false, true],
-    [true, false, false, true],
-    [true, false, false, true],
-    [false, true, true, false],
-];
-const A: [[bool; 4]; 6] = [
-    [false, true, true, false],
-    [true, false, false, true],
-    [true, false, false, true],
-    [true, true, true, true],
-    [true, false, false, true],
-    [true, false, false, true],
-];
-const L: [[bool; 4]; 6] = [
-    [true, false, false, false],
-    [true, false, false, false],
-    [true, false, false, false],
-    [true, false, false, false],
-    [true, false, false, false],
-    [true, true, true, true],
-];
-const R: [[bool; 4]; 6] = [
-    [true, true, true, false],
-    [true, false, false, true],
-    [true, false, false, true],
-    [true, true, true, false],
-    [true, false, true, false],
-    [true, false, false, true],
-];
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,16 +80,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(13_140));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(16_020));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("ECZUZALR".to_owned()));
-    }
 }
@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::VecDeque;
 
-all_aoc::solution!(11, 2022);
+all_aoc::solution!(11, 2022, tags = ["simulation", "number-theory"]);
 #[derive(Clone, Copy)]
 enum Val {
     Old,
@@ -157,21 +157,9 @@ mod tests {
         assert_eq!(result, Some(10_605));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(58_322));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(2_713_310_158));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(13_937_702_909));
-    }
 }
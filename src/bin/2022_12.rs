@@ -1,9 +1,6 @@
-use all_aoc::helper::{
-    graph::{WithWeights as _, build_graph4_special},
-    grid::{Grid as _, dense::DenseGrid},
-};
+use all_aoc::helper::grid::{Grid as _, dense::DenseGrid, index::GridIndex as _, path::bfs};
 
-all_aoc::solution!(12, 2022);
+all_aoc::solution!(12, 2022, tags = ["grid", "graph"]);
 #[derive(Debug, Clone, Copy)]
 enum Tile {
     Number(u8),
@@ -32,47 +29,32 @@ impl TryFrom<char> for Tile {
 }
 pub fn part_one(input: &str) -> Option<u32> {
     let grid = parse(input);
-    solve(
-        &grid,
-        &[grid
-            .iter()
-            .enumerate()
-            .find(|(_, t)| matches!(t, Tile::Start))
-            .unwrap()
-            .0],
-    )
+    solve(&grid, |t| matches!(t, Tile::Start))
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
     let grid = parse(input);
-    solve(
-        &grid,
-        &grid
-            .iter()
-            .enumerate()
-            .filter(|(_, t)| t.get_elevation() == Tile::Start.get_elevation())
-            .map(|(i, _)| i)
-            .collect::<Vec<_>>(),
-    )
+    solve(&grid, |t| t.get_elevation() == Tile::Start.get_elevation())
 }
-fn solve(grid: &DenseGrid<Tile>, starts: &[usize]) -> Option<u32> {
-    let graph = build_graph4_special(grid, |curr, neigh| {
-        (neigh.get_elevation() <= curr.get_elevation() + 1).then_some(1)
-    });
-    let end = grid
+fn solve<F>(grid: &DenseGrid<Tile>, is_start: F) -> Option<u32>
+where
+    F: Fn(&Tile) -> bool,
+{
+    let position = |i: usize| i.to_coordinates(grid);
+    let starts = grid
         .iter()
         .enumerate()
-        .find(|(_, t)| matches!(t, Tile::End))
-        .unwrap()
-        .0;
-    Some(
-        starts
-            .iter()
-            .map(|start| graph.dijkstra_distances(*start, Some(end)))
-            .filter_map(|m| m.get(&end).copied())
-            .min()
-            .unwrap(),
-    )
+        .filter(|(_, t)| is_start(t))
+        .map(|(i, _)| position(i));
+    let end = grid
+        .iter()
+        .position(|t| matches!(t, Tile::End))
+        .map(position)
+        .unwrap();
+    let distances = bfs(grid, starts, |curr, neigh| {
+        neigh.get_elevation() <= curr.get_elevation() + 1
+    });
+    distances.get(end, ()).map(|d| u32::try_from(d).unwrap())
 }
 fn parse(input: &str) -> DenseGrid<Tile> {
     DenseGrid::from_string(input)
@@ -87,21 +69,9 @@ mod tests {
         assert_eq!(result, Some(31));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(468));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(29));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(459));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(13, 2022);
+all_aoc::solution!(13, 2022, tags = ["parsing"]);
 #[derive(PartialEq, Eq, Clone)]
 enum Packet {
     Int(u32),
@@ -137,21 +137,9 @@ mod tests {
         assert_eq!(result, Some(13));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(5_938));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(140));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(29_025));
-    }
 }
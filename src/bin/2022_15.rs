@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use all_aoc::helper::position::Position;
 
-all_aoc::solution!(15, 2022);
+all_aoc::solution!(15, 2022, tags = ["ranges", "geometry"]);
 #[derive(Debug, Clone, Copy)]
 struct Sensor {
     x: i64,
@@ -139,21 +139,9 @@ mod tests {
         assert_eq!(result, Some(26));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_811_413));
-    }
-
     #[test]
     fn test_part_two() {
         let result = solve_part_2(&all_aoc::cli::read_examples_file(DAY), 20);
         assert_eq!(result, Some(56_000_011));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(13_171_855_019_123));
-    }
 }
@@ -6,7 +6,7 @@ use all_aoc::helper::{
     permutations::IteratorCombinator as _,
 };
 
-all_aoc::solution!(16, 2022);
+all_aoc::solution!(16, 2022, tags = ["graph", "dynamic-programming"]);
 
 struct Node {
     curr: usize,
@@ -125,21 +125,9 @@ mod tests {
         assert_eq!(result, Some(1_651));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_673));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(1_707));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_343));
-    }
 }
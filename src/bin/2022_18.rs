@@ -2,7 +2,7 @@ use std::collections::{HashSet, VecDeque};
 
 use all_aoc::helper::position3d::Position3d;
 
-all_aoc::solution!(18, 2022);
+all_aoc::solution!(18, 2022, tags = ["graph", "geometry"]);
 fn check_neighbours(
     pos: Position3d<i32>,
     all: &[Position3d<i32>],
@@ -136,21 +136,9 @@ mod tests {
         assert_eq!(result, Some(64));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_628));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(58));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_582));
-    }
 }
@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(19, 2022);
+all_aoc::solution!(19, 2022, tags = ["search"]);
 #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 struct Blueprint {
     id: u16,
@@ -230,21 +230,10 @@ mod tests {
         assert_eq!(result, Some(33));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(790));
-    }
-    #[cfg(feature = "expensive")]
+    #[ignore = "the example takes minutes, run with --ignored"]
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(56 * 62));
     }
-    #[cfg(feature = "expensive")]
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(7_350));
-    }
 }
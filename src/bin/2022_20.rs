@@ -1,4 +1,4 @@
-all_aoc::solution!(20, 2022);
+all_aoc::solution!(20, 2022, tags = ["simulation"]);
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
 struct Unique {
     value: i64,
@@ -67,21 +67,9 @@ mod tests {
         assert_eq!(result, Some(3));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(19_559));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(1_623_178_306));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(912_226_207_972));
-    }
 }
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::rational::Rational;
 
-all_aoc::solution!(21, 2022);
+all_aoc::solution!(21, 2022, tags = ["parsing", "math"]);
 #[derive(Clone, Copy)]
 enum Op {
     Add,
@@ -145,21 +145,9 @@ mod tests {
         assert_eq!(result, Some(152));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(62_386_792_426_088));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(301));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_876_027_196_185));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(25, 2022);
+all_aoc::solution!(25, 2022, tags = ["math"]);
 fn snafu_to_decimal(input: &str) -> i64 {
     let mut sum = 0;
     for c in input.chars() {
@@ -72,21 +72,9 @@ mod tests {
         assert_eq!(result, Some("2=-1=0".to_owned()));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("2=112--220-=-00=-=20".to_owned()));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, None);
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, None);
-    }
 }
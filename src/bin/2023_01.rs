@@ -1,6 +1,6 @@
 use core::iter;
 
-all_aoc::solution!(1, 2023);
+all_aoc::solution!(1, 2023, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(
@@ -48,20 +48,3 @@ fn parse_number(input: &str) -> Option<u32> {
         _ => None,
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(54_634));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(53_855));
-    }
-}
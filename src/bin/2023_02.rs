@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(2, 2023);
+all_aoc::solution!(2, 2023, tags = ["parsing"]);
 
 #[derive(Debug)]
 struct Subset {
@@ -103,21 +103,9 @@ mod tests {
         assert_eq!(result, Some(8));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_105));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(2_286));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(72_422));
-    }
 }
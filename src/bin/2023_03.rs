@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 
 use all_aoc::helper::grid::{Grid as _, dense::DenseGrid, index::GridIndex as _};
 
-all_aoc::solution!(3, 2023);
+all_aoc::solution!(3, 2023, tags = ["grid"]);
 
 #[derive(Clone, PartialEq, Eq)]
 enum Tile {
@@ -118,21 +118,9 @@ mod tests {
         assert_eq!(result, Some(4_361));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(553_079));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(467_835));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(84_363_105));
-    }
 }
@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-all_aoc::solution!(4, 2023);
+all_aoc::solution!(4, 2023, tags = ["parsing", "dynamic-programming"]);
 
 #[derive(Debug)]
 struct ScratchCard {
@@ -80,21 +80,9 @@ mod tests {
         assert_eq!(result, Some(13));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(20_107));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(30));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(8_172_507));
-    }
 }
@@ -3,7 +3,7 @@ use std::collections::{HashMap, VecDeque};
 
 use all_aoc::helper::range::ExtRangeOps as _;
 
-all_aoc::solution!(5, 2023);
+all_aoc::solution!(5, 2023, tags = ["ranges"]);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (mut seeds, vec) = parse_part_1(input);
@@ -114,21 +114,9 @@ mod tests {
         assert_eq!(result, Some(35));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(535_088_217));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(46));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(51_399_228));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(6, 2023);
+all_aoc::solution!(6, 2023, tags = ["math"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (times, distances) = parse_part_one(input);
@@ -89,21 +89,9 @@ mod tests {
         assert_eq!(result, Some(288));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(608_902));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(71_503));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(46_173_809));
-    }
 }
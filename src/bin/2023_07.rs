@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(7, 2023);
+all_aoc::solution!(7, 2023, tags = ["string"]);
 
 #[derive(Debug)]
 struct Hand {
@@ -121,21 +121,9 @@ mod tests {
         assert_eq!(result, Some(6_440));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(248_812_215));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(5_905));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(250_057_090));
-    }
 }
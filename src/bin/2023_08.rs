@@ -1,6 +1,6 @@
 use all_aoc::helper::misc::lcm;
 
-all_aoc::solution!(8, 2023);
+all_aoc::solution!(8, 2023, tags = ["graph", "number-theory"]);
 
 #[derive(Eq, PartialEq, Hash, Clone)]
 struct Data<'a> {
@@ -164,21 +164,9 @@ mod tests {
         assert_eq!(result, Some(6));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(18_023));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(6));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(14_449_445_933_179));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(9, 2023);
+all_aoc::solution!(9, 2023, tags = ["math"]);
 
 pub fn part_one(input: &str) -> Option<i32> {
     Some(parse(input).map(|v| recurse_part_one(&v)).sum())
@@ -39,21 +39,9 @@ mod tests {
         assert_eq!(result, Some(114));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_934_898_178));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(2));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_129));
-    }
 }
@@ -6,7 +6,7 @@ use all_aoc::helper::{
     position::Direction8,
 };
 
-all_aoc::solution!(10, 2023);
+all_aoc::solution!(10, 2023, tags = ["grid", "graph", "geometry"]);
 
 #[derive(PartialEq, Clone, Copy)]
 enum Tile {
@@ -236,12 +236,6 @@ mod tests {
         assert_eq!(result, Some(8));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_831));
-    }
-
     #[test]
     fn test_part_two() {
         let input = all_aoc::cli::read_examples_file(DAY);
@@ -252,10 +246,4 @@ mod tests {
         assert_eq!(part_two(it.next().unwrap()), Some(8));
         assert_eq!(part_two(it.next().unwrap()), Some(10));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(305));
-    }
 }
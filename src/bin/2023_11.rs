@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::grid::{Grid as _, dense::DenseGrid, index::GridIndex as _};
 
-all_aoc::solution!(11, 2023);
+all_aoc::solution!(11, 2023, tags = ["grid", "geometry"]);
 
 #[derive(Clone, PartialEq, Copy)]
 enum Tile {
@@ -95,12 +95,6 @@ mod tests {
         assert_eq!(result, Some(374));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(9_769_724));
-    }
-
     #[test]
     fn test_part_two() {
         let result = solve(&all_aoc::cli::read_examples_file(DAY), 10);
@@ -108,10 +102,4 @@ mod tests {
         let result = solve(&all_aoc::cli::read_examples_file(DAY), 100);
         assert_eq!(result, Some(8_410));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(603_020_563_700));
-    }
 }
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 
 use all_aoc::helper::grid::{Grid as _, dense::DenseGrid};
 
-all_aoc::solution!(13, 2023);
+all_aoc::solution!(13, 2023, tags = ["grid"]);
 
 #[derive(Clone, Copy, PartialEq)]
 enum Tile {
@@ -148,23 +148,12 @@ mod tests {
         assert_eq!(result, Some(405));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(33_122));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(400));
     }
 
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(32_312));
-    }
     #[test]
     fn test_part_one_1() {
         let result = part_one(
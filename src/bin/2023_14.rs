@@ -1,9 +1,10 @@
 use core::fmt::Debug;
-use std::collections::HashMap;
 
-use all_aoc::helper::grid::{Grid as _, dense::DenseGrid, index::GridIndex as _};
+use all_aoc::helper::grid::{
+    Grid as _, automaton::advance, dense::DenseGrid, index::GridIndex as _,
+};
 
-all_aoc::solution!(14, 2023);
+all_aoc::solution!(14, 2023, tags = ["grid", "simulation", "cycle-detection"]);
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum Tile {
@@ -129,27 +130,7 @@ fn execute_one_cyle(grid: &mut DenseGrid<Tile>) {
 }
 pub fn part_two(input: &str) -> Option<usize> {
     const CYCLES: usize = 1_000_000_000;
-    let mut grid = parse(input);
-    let mut map = HashMap::new();
-    let mut start_cycle = None;
-    let mut end_cycle = None;
-    for i in 0..CYCLES {
-        execute_one_cyle(&mut grid);
-        if let Some(prev) = map.get(&grid) {
-            start_cycle = Some(*prev);
-            end_cycle = Some(i);
-            break;
-        }
-        map.insert(grid.clone(), i);
-    }
-    let start_cycle = start_cycle.unwrap();
-    let end_cycle = end_cycle.unwrap();
-    let diff = end_cycle - start_cycle;
-    let remaining = (CYCLES - 1 - start_cycle) % diff;
-    for _ in 0..remaining {
-        execute_one_cyle(&mut grid);
-    }
-
+    let grid = advance(parse(input), CYCLES, execute_one_cyle);
     Some(calculate_load(&grid))
 }
 fn parse(input: &str) -> DenseGrid<Tile> {
@@ -165,21 +146,9 @@ mod tests {
         assert_eq!(result, Some(136));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(109_661));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(64));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(90_176));
-    }
 }
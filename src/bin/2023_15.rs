@@ -1,4 +1,4 @@
-all_aoc::solution!(15, 2023);
+all_aoc::solution!(15, 2023, tags = ["hashing"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let vec = parse(input);
@@ -65,21 +65,9 @@ mod tests {
         assert_eq!(result, Some(1_320));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(498_538));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(145));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(286_278));
-    }
 }
@@ -5,7 +5,7 @@ use all_aoc::helper::{
     position::Direction4 as Dir4,
 };
 
-all_aoc::solution!(16, 2023);
+all_aoc::solution!(16, 2023, tags = ["grid", "simulation"]);
 #[derive(Debug, Clone, Copy)]
 enum Tile {
     Space,
@@ -108,21 +108,9 @@ mod tests {
         assert_eq!(result, Some(46));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(7_067));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(51));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(7_324));
-    }
 }
@@ -1,5 +1,5 @@
 use all_aoc::helper::position::{Direction4 as Dir, Position};
-all_aoc::solution!(18, 2023);
+all_aoc::solution!(18, 2023, tags = ["geometry"]);
 fn str_to_dir(input: &str) -> Dir {
     match input {
         "U" => Dir::North,
@@ -95,21 +95,9 @@ mod tests {
         assert_eq!(result, Some(62));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(39_194));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(952_408_144_115));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(78_242_031_808_225));
-    }
 }
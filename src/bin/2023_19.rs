@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::range::ExtRangeOps as _;
 
-all_aoc::solution!(19, 2023);
+all_aoc::solution!(19, 2023, tags = ["parsing", "ranges"]);
 #[derive(Eq, PartialEq, Clone)]
 enum Output {
     Reject,
@@ -227,21 +227,9 @@ mod tests {
         assert_eq!(result, Some(19_114));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(397_643));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(167_409_079_868_000));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(132_392_981_697_081));
-    }
 }
@@ -3,7 +3,7 @@ use std::collections::{HashMap, VecDeque};
 
 use all_aoc::helper::misc::lcm_over_slice;
 
-all_aoc::solution!(20, 2023);
+all_aoc::solution!(20, 2023, tags = ["simulation", "graph", "number-theory"]);
 
 #[derive(Clone, PartialEq, Debug)]
 enum Operation {
@@ -166,7 +166,6 @@ fn parse(input: &str) -> HashMap<String, Module> {
 
 #[cfg(test)]
 mod tests {
-
     use super::*;
 
     #[test]
@@ -176,16 +175,4 @@ mod tests {
         assert_eq!(part_one(first), Some(32_000_000));
         assert_eq!(part_one(second), Some(11_687_500));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(841_763_884));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(246_006_621_493_687));
-    }
 }
@@ -5,7 +5,7 @@ use all_aoc::helper::{
     position::Position,
 };
 
-all_aoc::solution!(21, 2023);
+all_aoc::solution!(21, 2023, tags = ["grid", "graph", "math"]);
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 enum Tile {
@@ -174,9 +174,73 @@ fn parse(input: &str) -> DenseGrid<Tile> {
 
 #[cfg(test)]
 mod tests {
+    use all_aoc::helper::{
+        grid::GridMut as _,
+        property::{Config, Shrink},
+        rand::Rng,
+    };
 
     use super::*;
 
+    /// A garden where the start is in the center and the walk can't leave the map,
+    /// so wrapping around must not change the result.
+    #[derive(Debug, Clone)]
+    struct Garden {
+        grid: DenseGrid<Tile>,
+        steps: usize,
+    }
+    impl Garden {
+        fn generate(rng: &mut Rng, size: usize) -> Self {
+            let radius = rng.range(1..size + 1);
+            let width = 2 * radius + 1;
+            let mut grid = DenseGrid::new(width, width, Tile::GardenPlot);
+            for t in grid.iter_mut() {
+                if rng.ratio(1, 4) {
+                    *t = Tile::Rock;
+                }
+            }
+            grid[(radius, radius)] = Tile::Start;
+            Self {
+                grid,
+                steps: rng.range(0..radius + 1),
+            }
+        }
+        fn to_input(&self) -> String {
+            (0..self.grid.height())
+                .map(|y| {
+                    self.grid
+                        .get_row(y)
+                        .map(|t| match t {
+                            Tile::Start => 'S',
+                            Tile::Rock => '#',
+                            Tile::GardenPlot => '.',
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+    impl Shrink for Garden {
+        fn shrink(&self) -> Vec<Self> {
+            let fewer_steps = self.steps.shrink().into_iter().map(|steps| Self {
+                grid: self.grid.clone(),
+                steps,
+            });
+            let fewer_rocks = (0..self.grid.len())
+                .filter(|i| self.grid[*i] == Tile::Rock)
+                .map(|i| {
+                    let mut grid = self.grid.clone();
+                    grid[i] = Tile::GardenPlot;
+                    Self {
+                        grid,
+                        steps: self.steps,
+                    }
+                });
+            fewer_steps.chain(fewer_rocks).collect()
+        }
+    }
+
     #[test]
     fn test_part_one() {
         let input = &all_aoc::cli::read_examples_file(DAY);
@@ -187,14 +251,11 @@ mod tests {
     }
 
     #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_658));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(608_193_767_979_991));
+    fn test_wrapping_agrees_with_bounded() {
+        Config::default().with_cases(300).agree_with(
+            Garden::generate,
+            |g| execute2(&g.to_input(), g.steps),
+            |g| execute(&g.to_input(), g.steps),
+        );
     }
 }
@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::{HashMap, HashSet};
 
-all_aoc::solution!(22, 2023);
+all_aoc::solution!(22, 2023, tags = ["simulation", "graph"]);
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 struct Brick {
@@ -165,7 +165,7 @@ fn how_many_fall(
         let diff = n.difference(curr_disintegrated);
         if diff.count() == 0 {
             let ret = how_many_fall(supports, is_supported_by, *i, curr_disintegrated);
-            curr_disintegrated.extend(ret.into_iter());
+            curr_disintegrated.extend(ret);
             curr_disintegrated.insert(*i);
         }
     }
@@ -196,21 +196,9 @@ mod tests {
         assert_eq!(result, Some(5));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(530));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(7));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(93_292));
-    }
 }
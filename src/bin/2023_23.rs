@@ -6,7 +6,7 @@ use all_aoc::helper::{
     position::{Direction4 as Dir, Direction8},
 };
 
-all_aoc::solution!(23, 2023);
+all_aoc::solution!(23, 2023, tags = ["grid", "graph"]);
 
 #[derive(Eq, PartialEq, Clone, Debug, Copy)]
 enum Tile {
@@ -221,21 +221,9 @@ mod tests {
         assert_eq!(result, Some(94));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_414));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(154));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_598));
-    }
 }
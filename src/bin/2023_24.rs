@@ -6,7 +6,7 @@ use all_aoc::helper::{
     position3d::Position3d,
 };
 
-all_aoc::solution!(24, 2023);
+all_aoc::solution!(24, 2023, tags = ["geometry", "math"]);
 
 /// This function solves a * x = b where a is a matrix and x is unknown.
 fn solve_lgs(a: &DenseGrid<i256>, b: Vec<i256>) -> Vec<i256> {
@@ -268,21 +268,9 @@ mod tests {
         assert_eq!(result, Some(2));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(11_995));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(i256::from(47)));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(i256::from(983_620_716_335_751_i64)));
-    }
 }
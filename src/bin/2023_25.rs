@@ -1,6 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
-all_aoc::solution!(25, 2023);
+all_aoc::solution!(25, 2023, tags = ["graph"]);
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 struct NetworkFlow {
@@ -161,16 +161,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(54));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(514_786));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, None);
-    }
 }
@@ -1,6 +1,32 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(1, 2024);
+use all_aoc::{cli::generator::InputGenerator, helper::rand::Rng};
+
+all_aoc::solution!(1, 2024, generator = Generator, tags = ["math"]);
+
+/// Two columns of five digit numbers, where some numbers of the left column
+/// appear in the right one.
+struct Generator;
+impl InputGenerator for Generator {
+    const REAL_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let left = core::iter::repeat_with(|| 10_000 + rng.below(90_000))
+            .take(size)
+            .collect::<Vec<_>>();
+        left.iter()
+            .map(|l| {
+                let right = if rng.ratio(1, 4) {
+                    *rng.choose(&left).unwrap()
+                } else {
+                    10_000 + rng.below(90_000)
+                };
+                format!("{l}   {right}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
 
 pub fn part_one(input: &str) -> Option<u32> {
     let (mut v1, mut v2) = parse(input);
@@ -44,21 +70,9 @@ mod tests {
         assert_eq!(result, Some(11));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_970_687));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(31));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(23_963_899));
-    }
 }
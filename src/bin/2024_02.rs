@@ -1,4 +1,4 @@
-all_aoc::solution!(2, 2024);
+all_aoc::solution!(2, 2024, tags = ["brute-force"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let input = parse(input);
@@ -58,21 +58,9 @@ mod tests {
         assert_eq!(result, Some(2));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(269));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(4));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(337));
-    }
 }
@@ -6,7 +6,7 @@ use all_aoc::helper::parser::{
     sequence::{delimited, right, separated_pair},
 };
 
-all_aoc::solution!(3, 2024);
+all_aoc::solution!(3, 2024, tags = ["parsing"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut sum = 0;
@@ -75,21 +75,9 @@ mod tests {
         assert_eq!(result, Some(161));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(159_833_790));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(48));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(89_349_241));
-    }
 }
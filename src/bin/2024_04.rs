@@ -3,7 +3,7 @@ use all_aoc::helper::{
     position::Direction8,
 };
 
-all_aoc::solution!(4, 2024);
+all_aoc::solution!(4, 2024, tags = ["grid", "string"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let grid = parse(input);
@@ -73,21 +73,9 @@ mod tests {
         assert_eq!(result, Some(18));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_567));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(9));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_029));
-    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-all_aoc::solution!(5, 2024);
+all_aoc::solution!(5, 2024, tags = ["graph"]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let (page_ordering, updates) = parse(input);
@@ -77,21 +77,9 @@ mod tests {
         assert_eq!(result, Some(143));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_949));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(123));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_145));
-    }
 }
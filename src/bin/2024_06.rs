@@ -5,7 +5,7 @@ use all_aoc::helper::{
     position::Direction4,
 };
 
-all_aoc::solution!(6, 2024);
+all_aoc::solution!(6, 2024, tags = ["grid", "simulation"]);
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Obstruction,
@@ -73,34 +73,32 @@ fn get_all_locations_visited(
     set.insert(pos);
     let mut pos = pos;
     let mut dir = dir;
-    while let Some((next_pos, tile)) = grid.get_dir8(pos, dir.into()) {
-        let (next_dir, next_pos) = match tile {
-            Tile::Obstruction => (dir.turn_right(), pos),
-            Tile::Air => (dir, next_pos.to_flat_index(grid)),
-            Tile::Guard(_) => unreachable!(),
+    loop {
+        let hit = grid.cast(pos, dir.into(), |tile| *tile == Tile::Obstruction);
+        let walked = hit.map_or(usize::MAX, |hit| hit.distance - 1);
+        set.extend(
+            grid.ray(pos, dir.into())
+                .take(walked)
+                .map(|(p, _)| p.to_flat_index(grid)),
+        );
+        let Some(hit) = hit else {
+            return set;
         };
-        set.insert(next_pos);
-        pos = next_pos;
-        dir = next_dir;
+        pos = hit.before.to_flat_index(grid);
+        dir = dir.turn_right();
     }
-    set
 }
+/// Only the turns are remembered, as the guard walks straight in between.
 fn check_if_loop(grid: &DenseGrid<Tile>, pos: usize, dir: Direction4) -> bool {
     let mut set = HashSet::new();
-    set.insert((pos, dir));
     let mut pos = pos;
     let mut dir = dir;
-    while let Some((next_pos, tile)) = grid.get_dir8(pos, dir.into()) {
-        let (next_dir, next_pos) = match tile {
-            Tile::Obstruction => (dir.turn_right(), pos),
-            Tile::Air => (dir, next_pos.to_flat_index(grid)),
-            Tile::Guard(_) => unreachable!(),
-        };
-        if !set.insert((next_pos, next_dir)) {
+    while let Some(hit) = grid.cast(pos, dir.into(), |tile| *tile == Tile::Obstruction) {
+        pos = hit.before.to_flat_index(grid);
+        dir = dir.turn_right();
+        if !set.insert((pos, dir)) {
             return true;
         }
-        pos = next_pos;
-        dir = next_dir;
     }
     false
 }
@@ -117,21 +115,9 @@ mod tests {
         assert_eq!(result, Some(41));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(5_534));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(6));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_262));
-    }
 }
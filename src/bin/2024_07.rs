@@ -2,7 +2,7 @@ use core::str::FromStr;
 
 use all_aoc::helper::misc::number_to_digit_count;
 
-all_aoc::solution!(7, 2024);
+all_aoc::solution!(7, 2024, tags = ["brute-force"]);
 #[derive(Debug)]
 struct Equation {
     result: u64,
@@ -97,21 +97,9 @@ mod tests {
         assert_eq!(result, Some(3_749));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(2_501_605_301_465));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(11_387));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(44_841_372_855_953));
-    }
 }
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use all_aoc::helper::position::Position;
 
-all_aoc::solution!(8, 2024);
+all_aoc::solution!(8, 2024, tags = ["grid", "geometry"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (map, pos_max) = parse(input);
@@ -91,21 +91,9 @@ mod tests {
         assert_eq!(result, Some(14));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(228));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(34));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(766));
-    }
 }
@@ -1,6 +1,6 @@
 use core::iter;
 
-all_aoc::solution!(9, 2024);
+all_aoc::solution!(9, 2024, tags = ["simulation"]);
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Layout {
     Occupied(u64),
@@ -174,21 +174,9 @@ mod tests {
         assert_eq!(result, Some(1_928));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_299_243_228_569));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(2_858));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_326_952_672_104));
-    }
 }
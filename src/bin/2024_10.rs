@@ -5,7 +5,7 @@ use all_aoc::helper::{
     grid::{Grid as _, dense::DenseGrid},
 };
 
-all_aoc::solution!(10, 2024);
+all_aoc::solution!(10, 2024, tags = ["grid", "graph"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let grid = parse(input);
@@ -71,21 +71,9 @@ mod tests {
         assert_eq!(result, Some(36));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(587));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(81));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_340));
-    }
 }
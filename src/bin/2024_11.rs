@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::misc::number_to_digit_count;
 
-all_aoc::solution!(11, 2024);
+all_aoc::solution!(11, 2024, tags = ["dynamic-programming"]);
 
 pub fn part_one(input: &str) -> Option<u64> {
     execute(input, 25)
@@ -59,21 +59,9 @@ mod tests {
         assert_eq!(result, Some(55_312));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(175_006));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(65_601_038_650_482));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(207_961_583_799_296));
-    }
 }
@@ -2,7 +2,7 @@ use core::str::FromStr;
 
 use all_aoc::helper::position::Position;
 
-all_aoc::solution!(13, 2024);
+all_aoc::solution!(13, 2024, tags = ["math"]);
 #[derive(Debug)]
 struct ClawMachine {
     a: Position<i64>,
@@ -87,21 +87,9 @@ mod tests {
         assert_eq!(result, Some(480));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(31_897));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(875_318_608_908));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(87_596_249_540_359));
-    }
 }
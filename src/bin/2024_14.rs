@@ -1,8 +1,13 @@
 use core::{fmt::Display, iter, str::FromStr};
 
-use all_aoc::helper::{modulo::SignedExt as _, position::Position};
+use all_aoc::helper::{
+    grid::{Grid as _, dense::DenseGrid},
+    modulo::SignedExt as _,
+    position::Position,
+    viz::{self, Cell, Color, Frame},
+};
 
-all_aoc::solution!(14, 2024);
+all_aoc::solution!(14, 2024, tags = ["grid", "simulation", "number-theory"]);
 #[derive(Debug)]
 struct Robot {
     p: Position<i32>,
@@ -58,6 +63,26 @@ impl Grid {
         }
         up_left * up_right * down_left * down_right
     }
+    fn frame(&self, step: u32) -> Frame {
+        let mut grid = DenseGrid::new(
+            usize::try_from(self.size.x).unwrap(),
+            usize::try_from(self.size.y).unwrap(),
+            false,
+        );
+        for r in &self.robots {
+            let x = usize::try_from(r.p.x).unwrap();
+            let y = usize::try_from(r.p.y).unwrap();
+            grid.set((y, x), true);
+        }
+        Frame::from_grid(&grid, |b| {
+            if *b {
+                Cell::new('#', Color::GREEN)
+            } else {
+                Cell::new('.', Color::GRAY)
+            }
+        })
+        .with_caption(format!("after {step} seconds, entropy {}", self.entropy()))
+    }
 }
 impl FromStr for Robot {
     type Err = ();
@@ -104,9 +129,14 @@ pub fn part_two(input: &str) -> Option<u32> {
         size: Position { x: 101, y: 103 },
         robots,
     };
+    let mut lowest = usize::MAX;
     for i in 1.. {
         grid.step();
         let e = grid.entropy();
+        if e < lowest {
+            lowest = e;
+            viz::record(|| grid.frame(i));
+        }
         // Empirical threshold
         if e < 60_000_000 {
             return Some(i);
@@ -132,16 +162,4 @@ mod tests {
         let result = grid.solve_part_1();
         assert_eq!(result, Some(12));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(215_987_200));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(8_050));
-    }
 }
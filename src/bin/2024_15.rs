@@ -6,7 +6,7 @@ use all_aoc::helper::{
     position::{Direction4, Direction8},
 };
 
-all_aoc::solution!(15, 2024);
+all_aoc::solution!(15, 2024, tags = ["grid", "simulation"]);
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum TilePart1 {
     Robot,
@@ -247,21 +247,9 @@ mod tests {
         assert_eq!(result, Some(10_092));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_486_930));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(9_021));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_492_011));
-    }
 }
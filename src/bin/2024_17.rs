@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(17, 2024);
+all_aoc::solution!(17, 2024, tags = ["vm", "search"]);
 #[derive(Debug)]
 enum Instruction {
     Adv(u64),
@@ -157,16 +157,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_owned()));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("5,1,3,4,3,7,2,1,7".to_owned()));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(216_584_205_979_245));
-    }
 }
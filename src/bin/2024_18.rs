@@ -4,7 +4,7 @@ use all_aoc::helper::{
     position::Position,
 };
 
-all_aoc::solution!(18, 2024);
+all_aoc::solution!(18, 2024, tags = ["grid", "graph"]);
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Empty,
@@ -67,21 +67,9 @@ mod tests {
         assert_eq!(result, Some(22));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(318));
-    }
-
     #[test]
     fn test_part_two() {
         let result = solve_part_2(&all_aoc::cli::read_examples_file(DAY), 7);
         assert_eq!(result, Some("6,1".to_owned()));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("56,29".to_owned()));
-    }
 }
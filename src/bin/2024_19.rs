@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-all_aoc::solution!(19, 2024);
+all_aoc::solution!(19, 2024, tags = ["string", "dynamic-programming"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (towels, designs) = parse(input);
@@ -74,21 +74,9 @@ mod tests {
         assert_eq!(result, Some(6));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(287));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(16));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(571_894_474_468_161));
-    }
 }
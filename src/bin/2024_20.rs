@@ -7,7 +7,7 @@ use all_aoc::helper::{
     permutations::IteratorCombinator as _,
 };
 
-all_aoc::solution!(20, 2024);
+all_aoc::solution!(20, 2024, tags = ["grid", "graph"]);
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Tile {
     Start,
@@ -106,21 +106,9 @@ mod tests {
         assert_eq!(result, Some(14));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_417));
-    }
-
     #[test]
     fn test_part_two() {
         let result = execute(&all_aoc::cli::read_examples_file(DAY), 50, 20);
         assert_eq!(result, Some(285));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_014_683));
-    }
 }
@@ -6,7 +6,7 @@ use all_aoc::helper::{
     position::{Direction4, Position},
 };
 type Pos = Position<usize>;
-all_aoc::solution!(21, 2024);
+all_aoc::solution!(21, 2024, tags = ["grid", "dynamic-programming"]);
 
 struct Keypad {
     map: SparseGrid<char>,
@@ -172,21 +172,9 @@ mod tests {
         assert_eq!(result, Some(126_384));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(188_384));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(154_115_708_116_294));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(232_389_969_568_832));
-    }
 }
@@ -1,6 +1,6 @@
 use core::iter::successors;
 
-all_aoc::solution!(22, 2024);
+all_aoc::solution!(22, 2024, tags = ["simulation"]);
 const fn next(x: u64) -> u64 {
     let mut x = x;
     x = ((64 * x) ^ x) % 0x0100_0000;
@@ -63,21 +63,9 @@ mod tests {
         assert_eq!(result, Some(37_327_623));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(14_082_561_342));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(24));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_568));
-    }
 }
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use all_aoc::helper::{graph::Graph as _, permutations::IteratorCombinator as _};
 
-all_aoc::solution!(23, 2024);
+all_aoc::solution!(23, 2024, tags = ["graph"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let vec = parse(input);
@@ -78,24 +78,9 @@ mod tests {
         assert_eq!(result, Some(7));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_098));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some("co,de,ka,ta".to_owned()));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(
-            result,
-            Some("ar,ep,ih,ju,jx,le,ol,pk,pm,pp,xf,yu,zg".to_owned())
-        );
-    }
 }
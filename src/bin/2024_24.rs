@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::graph::{Graph as _, Special, WithWeights as _};
 
-all_aoc::solution!(24, 2024);
+all_aoc::solution!(24, 2024, tags = ["graph", "simulation"]);
 #[derive(PartialEq)]
 enum Instruction {
     Or,
@@ -158,16 +158,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(2_024));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(42_410_633_905_894));
-    }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some("cqm,mps,vcv,vjv,vwp,z13,z19,z25".to_owned()));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(25, 2024);
+all_aoc::solution!(25, 2024, tags = ["grid"]);
 fn check_key_lock_pair(key: &[u32], lock: &[u32]) -> bool {
     key.iter().zip(lock).all(|(x, y)| x + y <= 5)
 }
@@ -56,10 +56,4 @@ mod tests {
         let result = part_one(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(3));
     }
-
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(3_439));
-    }
 }
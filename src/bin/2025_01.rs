@@ -1,6 +1,6 @@
 use core::iter;
 
-all_aoc::solution!(1, 2025);
+all_aoc::solution!(1, 2025, tags = ["simulation"]);
 
 fn expand(dir: i32) -> impl Iterator<Item = i32> {
     iter::repeat_n(dir.signum(), dir.unsigned_abs() as usize)
@@ -44,21 +44,9 @@ mod tests {
         assert_eq!(result, Some(3));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_078));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(6));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(6_412));
-    }
 }
@@ -4,7 +4,7 @@ use std::collections::HashSet;
 
 use all_aoc::helper::misc::{Joinable as _, number_to_digit_count};
 
-all_aoc::solution!(2, 2025);
+all_aoc::solution!(2, 2025, tags = ["ranges", "brute-force"]);
 
 pub fn part_one(input: &str) -> Option<u64> {
     Some(
@@ -115,21 +115,9 @@ mod tests {
         assert_eq!(result, Some(1_227_775_554));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(12_850_231_731));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(4_174_379_265));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(24_774_350_322));
-    }
 }
@@ -1,4 +1,4 @@
-all_aoc::solution!(3, 2025);
+all_aoc::solution!(3, 2025, tags = ["string"]);
 
 pub fn part_one(input: &str) -> Option<u64> {
     solve(input, 2)
@@ -40,21 +40,9 @@ mod tests {
         assert_eq!(result, Some(357));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(17_144));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(3_121_910_778_619));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(170_371_185_255_900));
-    }
 }
@@ -1,6 +1,6 @@
 use all_aoc::helper::grid::{Grid as _, dense::DenseGrid, index::GridIndex as _};
 
-all_aoc::solution!(4, 2025);
+all_aoc::solution!(4, 2025, tags = ["grid", "simulation"]);
 #[derive(Debug, Clone, Copy)]
 enum Tile {
     Air,
@@ -67,21 +67,9 @@ mod tests {
         assert_eq!(result, Some(13));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_493));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(43));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(9_194));
-    }
 }
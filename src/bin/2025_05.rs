@@ -2,7 +2,7 @@ use core::ops::RangeInclusive;
 
 use all_aoc::helper::range::ExtRangeOps as _;
 
-all_aoc::solution!(5, 2025);
+all_aoc::solution!(5, 2025, tags = ["ranges"]);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (ranges, ids) = parse(input);
@@ -62,21 +62,9 @@ mod tests {
         assert_eq!(result, Some(3));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(862));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(14));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(357_907_198_933_892));
-    }
 }
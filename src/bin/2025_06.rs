@@ -2,7 +2,7 @@ use core::str::FromStr;
 
 use all_aoc::helper::misc::Joinable as _;
 
-all_aoc::solution!(6, 2025);
+all_aoc::solution!(6, 2025, tags = ["parsing"]);
 enum Op {
     Add,
     Mul,
@@ -108,21 +108,9 @@ mod tests {
         assert_eq!(result, Some(4_277_556));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_648_618_073_226));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(3_263_827));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(7_329_921_182_115));
-    }
 }
@@ -5,7 +5,7 @@ use all_aoc::helper::{
     position::Direction8,
 };
 
-all_aoc::solution!(7, 2025);
+all_aoc::solution!(7, 2025, tags = ["grid", "dynamic-programming"]);
 #[derive(Debug, Clone, Copy)]
 enum Tile {
     Air,
@@ -102,21 +102,9 @@ mod tests {
         assert_eq!(result, Some(21));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_630));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(40));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(47_857_642_990_160));
-    }
 }
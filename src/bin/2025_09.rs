@@ -2,7 +2,7 @@ use core::cmp::Reverse;
 
 use all_aoc::helper::{permutations::IteratorCombinator as _, position::Position};
 
-all_aoc::solution!(9, 2025);
+all_aoc::solution!(9, 2025, tags = ["geometry"]);
 #[derive(Debug, Clone, Copy)]
 struct Candidate {
     pub corner1: Position<u64>,
@@ -92,21 +92,9 @@ mod tests {
         assert_eq!(result, Some(50));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(4_777_816_465));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, Some(24));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(1_410_501_884));
-    }
 }
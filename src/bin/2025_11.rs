@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use all_aoc::helper::graph::{Graph, Special, WithWeights as _};
 
-all_aoc::solution!(11, 2025);
+all_aoc::solution!(11, 2025, tags = ["graph", "dynamic-programming"]);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (map, graph) = build_graph(input);
@@ -82,12 +82,6 @@ mod tests {
         assert_eq!(result, Some(5));
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(788));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(
@@ -98,10 +92,4 @@ mod tests {
         );
         assert_eq!(result, Some(2));
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(316_291_887_968_000));
-    }
 }
@@ -1,6 +1,6 @@
 use all_aoc::helper::grid::{Grid as _, dense::DenseGrid};
 
-all_aoc::solution!(12, 2025);
+all_aoc::solution!(12, 2025, tags = ["grid", "search"]);
 #[derive(Debug, Clone, Copy)]
 enum Tile {
     On,
@@ -70,21 +70,9 @@ fn parse(input: &str) -> (Vec<DenseGrid<Tile>>, Vec<((usize, usize), Vec<usize>)
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, Some(463));
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, None);
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, None);
-    }
 }
@@ -0,0 +1,134 @@
+use core::fmt::Display;
+use std::{fs, io};
+
+use super::{day::Day, error::AOCError, runner::Answer};
+
+/// The known answer of one part.
+/// `answer` is `None` if the part has no answer (e.g. the second part of day 25).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub answer: Option<Answer>,
+}
+/// The known answers of one day, stored in `data/answers/YYYY/DD.txt`.
+///
+/// Every line has the form `<part>: <answer>`, with the answer escaped like in
+/// [`Answer::escaped`]:
+/// ```text
+/// 1: 33
+/// 2: #..#\n####
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub day: Day,
+    pub part_one: Option<Expected>,
+    pub part_two: Option<Expected>,
+}
+impl Answers {
+    pub const fn new(day: Day) -> Self {
+        Self {
+            day,
+            part_one: None,
+            part_two: None,
+        }
+    }
+    /// Reads the answers of the day. A missing file means that no answers are known.
+    pub fn load(day: Day) -> Result<Self, AOCError> {
+        let path = day.answers_path();
+        match fs::read_to_string(&path) {
+            Ok(s) => Self::parse(day, &s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::new(day)),
+            Err(e) => Err(AOCError::read(&path)(e)),
+        }
+    }
+    pub fn parse(day: Day, input: &str) -> Result<Self, AOCError> {
+        let mut answers = Self::new(day);
+        let invalid = |message: String| AOCError::InvalidFile {
+            path: day.answers_path(),
+            message,
+        };
+        for line in input.lines().filter(|l| !l.trim().is_empty()) {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| invalid(format!("Invalid line: {line}")))?;
+            let part = key
+                .trim()
+                .parse::<u8>()
+                .map_err(|e| invalid(format!("Invalid part: {e}")))?;
+            let value = value.trim();
+            let expected = Expected {
+                answer: (value != "None").then(|| Answer::from_escaped(value)),
+            };
+            match part {
+                1 => answers.part_one = Some(expected),
+                2 => answers.part_two = Some(expected),
+                x => return Err(invalid(format!("Invalid part: {x}"))),
+            }
+        }
+        Ok(answers)
+    }
+    pub const fn part(&self, part: u8) -> Option<&Expected> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+    }
+}
+impl Display for Answers {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (part, expected) in [(1, &self.part_one), (2, &self.part_two)] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            writeln!(
+                f,
+                "{part}: {}",
+                expected
+                    .answer
+                    .as_ref()
+                    .map_or_else(|| "None".to_owned(), Answer::escaped)
+            )?;
+        }
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let day = Day {
+            day: 11,
+            year: 2016,
+        };
+        let answers = Answers::parse(day, "1: 33\n2:57\n").unwrap();
+        assert_eq!(
+            answers.part_one,
+            Some(Expected {
+                answer: Some(Answer::Integer(33)),
+            })
+        );
+        assert_eq!(
+            answers.part(2),
+            Some(&Expected {
+                answer: Some(Answer::Integer(57)),
+            })
+        );
+        assert_eq!(answers.to_string(), "1: 33\n2: 57\n");
+
+        let answers = Answers::parse(day, "2: #..#\\n####").unwrap();
+        assert_eq!(
+            answers.part(2).unwrap().answer,
+            Some(Answer::Grid(vec!["#..#".to_owned(), "####".to_owned()]))
+        );
+        assert_eq!(answers.to_string(), "2: #..#\\n####\n");
+
+        let answers = Answers::parse(day, "2: None").unwrap();
+        assert_eq!(answers.part_one, None);
+        assert_eq!(answers.part(2).unwrap().answer, None);
+
+        Answers::parse(day, "3: 1").unwrap_err();
+        Answers::parse(day, "1 slow: 1").unwrap_err();
+    }
+}
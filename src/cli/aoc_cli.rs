@@ -1,8 +1,4 @@
-use core::fmt::Display;
-use std::{
-    path::PathBuf,
-    process::{Command, Output, Stdio},
-};
+use std::process::{Command, Output, Stdio};
 pub enum AOCCommands {
     Download,
     Submit,
@@ -16,33 +12,22 @@ impl From<AOCCommands> for String {
         .to_owned()
     }
 }
-use super::day::Day;
-#[derive(Debug)]
-pub enum AOCError {
-    CommandNotCallable,
-    BadExitStatus(Output),
-    FailedCreateDir(PathBuf),
-    FileError(String),
-    FileNotExist(PathBuf),
-}
-impl Display for AOCError {
-    #[expect(clippy::use_debug, reason = "dont bother")]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
-    }
-}
+use super::{day::Day, error::AOCError};
 pub fn call(args: &[String]) -> Result<Output, AOCError> {
     let output = Command::new("aoc")
         .args(args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .output()
-        .map_err(|_| AOCError::CommandNotCallable)?;
+        .map_err(AOCError::command("aoc"))?;
 
     if output.status.success() {
         Ok(output)
     } else {
-        Err(AOCError::BadExitStatus(output))
+        Err(AOCError::BadExitStatus {
+            command: format!("aoc {}", args.join(" ")),
+            status: output.status,
+        })
     }
 }
 pub fn build_args(command: AOCCommands, args: &[&str], day: Day) -> Vec<String> {
@@ -0,0 +1,258 @@
+use core::{
+    fmt::{Display, Write as _},
+    time::Duration,
+};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::OnceLock,
+};
+
+use super::{
+    commands::download::create_path,
+    day::Day,
+    error::AOCError,
+    index::{DayInfo, helper_sources},
+    runner::{Answer, PartDayResult},
+    timings::Profile,
+};
+use crate::helper::md5::md5;
+
+/// The files of the crate that every binary is built from, besides its helper modules.
+const CRATE_SOURCES: [&str; 4] = ["Cargo.toml", "rust-toolchain.toml", "src/lib.rs", "src/cli"];
+
+/// The last results of a day, stored in `data/cache/YYYY/DD.txt`.
+///
+/// The results are only valid for the key they were computed with, a hash of the source
+/// of the day, the helper modules it uses, the rest of the crate, the compiler, its input
+/// and the build profile. A part can have the runtime in nanoseconds it took:
+/// ```text
+/// key: 8b1a9953c4611296a827abf8c47804d7
+/// 1 52300: 33
+/// 2: None
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    pub day: Day,
+    pub key: String,
+    pub results: BTreeMap<u8, CachedPart>,
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedPart {
+    pub result: Option<Answer>,
+    pub duration: Option<Duration>,
+}
+impl Cache {
+    pub const fn new(day: Day, key: String) -> Self {
+        Self {
+            day,
+            key,
+            results: BTreeMap::new(),
+        }
+    }
+    /// The hash of everything the results depend on. `None` if the day has no input.
+    pub fn key(day: Day, profile: Profile) -> Result<Option<String>, AOCError> {
+        let input_path = day.input_path();
+        let input = match fs::read_to_string(&input_path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(AOCError::read(&input_path)(e)),
+        };
+        let info = DayInfo::load(day)?;
+        let mut sources = vec![day.bin_path()];
+        sources.extend(helper_sources(&info.helpers)?);
+        for path in CRATE_SOURCES {
+            sources.extend(rust_files(Path::new(path)));
+        }
+        let mut content = format!("{profile}\n{}\n", rustc_version());
+        for path in sources {
+            let source = fs::read_to_string(&path).map_err(AOCError::read(&path))?;
+            let _ = writeln!(content, "{}\n{source}", path.display());
+        }
+        content.push_str(&input);
+        Ok(Some(md5(&content)))
+    }
+    /// Reads the results of the day that were computed with the key.
+    /// A missing file or one with another key means that nothing is cached.
+    pub fn load(day: Day, key: &str) -> Result<Self, AOCError> {
+        let path = day.cache_path();
+        match fs::read_to_string(&path) {
+            Ok(s) => {
+                let cache = Self::parse(day, &s)?;
+                if cache.key == key {
+                    Ok(cache)
+                } else {
+                    Ok(Self::new(day, key.to_owned()))
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::new(day, key.to_owned())),
+            Err(e) => Err(AOCError::read(&path)(e)),
+        }
+    }
+    /// The stored results of the day if they are still valid for one of the profiles.
+    pub fn current(day: Day) -> Result<Option<(Profile, Self)>, AOCError> {
+        let path = day.cache_path();
+        let cache = match fs::read_to_string(&path) {
+            Ok(s) => Self::parse(day, &s)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(AOCError::read(&path)(e)),
+        };
+        for profile in [Profile::Release, Profile::Debug] {
+            if Self::key(day, profile)?.is_some_and(|key| key == cache.key) {
+                return Ok(Some((profile, cache)));
+            }
+        }
+        Ok(None)
+    }
+    pub fn parse(day: Day, input: &str) -> Result<Self, AOCError> {
+        let mut cache = Self::new(day, String::new());
+        for line in input.lines().filter(|l| !l.trim().is_empty()) {
+            let invalid = || AOCError::InvalidFile {
+                path: day.cache_path(),
+                message: format!("Invalid line: {line}"),
+            };
+            let (key, value) = line.split_once(':').ok_or_else(invalid)?;
+            let value = value.trim();
+            if key == "key" {
+                value.clone_into(&mut cache.key);
+                continue;
+            }
+            let (part, duration) = match key.split_once(' ') {
+                Some((part, nanos)) => (
+                    part,
+                    Some(Duration::from_nanos(
+                        nanos.trim().parse().map_err(|_| invalid())?,
+                    )),
+                ),
+                None => (key, None),
+            };
+            let part = part.trim().parse().map_err(|_| invalid())?;
+            let result = (value != "None").then(|| Answer::from_escaped(value));
+            cache.results.insert(part, CachedPart { result, duration });
+        }
+        Ok(cache)
+    }
+    pub fn save(&self) -> Result<(), AOCError> {
+        let path = self.day.cache_path();
+        create_path(&path).map_err(AOCError::create_dir(&path))?;
+        fs::write(&path, self.to_string()).map_err(AOCError::write(&path))
+    }
+    /// The cached result of the part with the runtime it took, if the part is cached.
+    pub fn get(&self, part: u8) -> Option<PartDayResult<Answer>> {
+        let cached = self.results.get(&part)?;
+        Some(PartDayResult {
+            day: self.day,
+            part,
+            result: cached.result.clone(),
+            durations: cached.duration.into_iter().collect(),
+        })
+    }
+    pub fn insert(&mut self, result: &PartDayResult<Answer>) {
+        self.results.insert(
+            result.part,
+            CachedPart {
+                result: result.result.clone(),
+                duration: (!result.durations.is_empty()).then(|| result.average_duration()),
+            },
+        );
+    }
+}
+impl Display for Cache {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "key: {}", self.key)?;
+        for (part, cached) in &self.results {
+            write!(f, "{part}")?;
+            if let Some(duration) = cached.duration {
+                write!(f, " {}", duration.as_nanos())?;
+            }
+            writeln!(
+                f,
+                ": {}",
+                cached
+                    .result
+                    .as_ref()
+                    .map_or_else(|| "None".to_owned(), Answer::escaped)
+            )?;
+        }
+        Ok(())
+    }
+}
+/// The file itself, or every Rust file in the directory and its subdirectories, sorted.
+fn rust_files(path: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(path) else {
+        return if path.is_file() {
+            vec![path.to_owned()]
+        } else {
+            vec![]
+        };
+    };
+    let mut files = entries
+        .filter_map(Result::ok)
+        .flat_map(|e| {
+            let path = e.path();
+            if path.is_dir() {
+                rust_files(&path)
+            } else if path.extension().is_some_and(|e| e == "rs") {
+                vec![path]
+            } else {
+                vec![]
+            }
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+/// The version of the compiler that builds the binaries, asked only once.
+fn rustc_version() -> &'static str {
+    static VERSION: OnceLock<String> = OnceLock::new();
+    VERSION.get_or_init(|| {
+        Command::new("rustc")
+            .arg("--version")
+            .output()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_owned())
+            .unwrap_or_default()
+    })
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let day = Day { day: 3, year: 2019 };
+        let input = "key: abc\n1 1500: 33\n2: None\n";
+        let cache = Cache::parse(day, input).unwrap();
+        assert_eq!(cache.key, "abc");
+        let part_1 = cache.get(1).unwrap();
+        assert_eq!(part_1.result, Some(Answer::Integer(33)));
+        assert_eq!(part_1.durations, [Duration::from_nanos(1500)]);
+        let part_2 = cache.get(2).unwrap();
+        assert_eq!(part_2.result, None);
+        assert!(part_2.durations.is_empty());
+        assert!(cache.get(3).is_none());
+        assert_eq!(cache.to_string(), input);
+        Cache::parse(day, "one: 1").unwrap_err();
+        Cache::parse(day, "1 fast: 1").unwrap_err();
+    }
+
+    #[test]
+    fn test_insert() {
+        let day = Day { day: 3, year: 2019 };
+        let mut cache = Cache::new(day, "abc".to_owned());
+        cache.insert(&PartDayResult {
+            day,
+            part: 1,
+            result: Some(Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()])),
+            durations: vec![Duration::from_nanos(10), Duration::from_nanos(20)],
+        });
+        cache.insert(&PartDayResult {
+            day,
+            part: 2,
+            result: None,
+            durations: vec![],
+        });
+        assert_eq!(cache.to_string(), "key: abc\n1 15: #.\\n.#\n2: None\n");
+    }
+}
@@ -0,0 +1,217 @@
+use core::time::Duration;
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+use crate::cli::{
+    commands::stats::format_runtime,
+    day::Day,
+    error::AOCError,
+    runner::{ANSI_BOLD, ANSI_RESET, Answer, PartDayResult},
+};
+
+/// A revision checked out into a temporary git worktree, which is removed again on drop.
+struct Worktree {
+    rev: String,
+    path: PathBuf,
+}
+impl Worktree {
+    /// The full hash of the commit a revision points to.
+    fn resolve(rev: &str) -> Result<String, AOCError> {
+        git(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{rev}^{{commit}}"),
+        ])
+        .map_err(|_| AOCError::InvalidArgument(format!("Unknown revision {rev}")))
+    }
+    fn checkout(rev: &str, commit: &str) -> Result<Self, AOCError> {
+        let path = env::temp_dir().join(format!("all_aoc-bench-{}", &commit[..12]));
+        if path.exists() {
+            // left over from an interrupted run
+            let _ = git(&["worktree", "remove", "--force", &path.display().to_string()]);
+            let _ = fs::remove_dir_all(&path);
+        }
+        git(&[
+            "worktree",
+            "add",
+            "--quiet",
+            "--detach",
+            &path.display().to_string(),
+            commit,
+        ])?;
+        Ok(Self {
+            rev: rev.to_owned(),
+            path,
+        })
+    }
+    /// Builds the binaries of the days that exist at this revision, in release.
+    /// Without a selection every day of the revision is built.
+    fn build(&self, days: Option<&[Day]>) -> Result<Vec<Day>, AOCError> {
+        let existing = Day::all_in(&self.path.join("src/bin"));
+        let days = days.map_or_else(
+            || existing.clone(),
+            |days| {
+                days.iter()
+                    .copied()
+                    .filter(|d| existing.contains(d))
+                    .collect()
+            },
+        );
+        if days.is_empty() {
+            return Ok(days);
+        }
+        println!("Building {} in release", self.rev);
+        let mut command = Command::new("cargo");
+        command
+            .args(["build", "--release", "--quiet"])
+            .current_dir(&self.path);
+        for day in &days {
+            command.args(["--bin", &day.bin_name()]);
+        }
+        let status = command
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .map_err(AOCError::command("cargo"))?;
+        if !status.success() {
+            return Err(AOCError::BadExitStatus {
+                command: format!("cargo build --release in {}", self.path.display()),
+                status,
+            });
+        }
+        Ok(days)
+    }
+    fn run(&self, day: Day) -> Result<[PartDayResult<Answer>; 2], AOCError> {
+        day.execute_built(&self.path.join("target/release"))
+            .map(Into::into)
+    }
+}
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        if let Err(e) = git(&[
+            "worktree",
+            "remove",
+            "--force",
+            &self.path.display().to_string(),
+        ]) {
+            eprintln!("Worktree {} can't be removed: {e}", self.path.display());
+        }
+    }
+}
+
+/// Builds the days at both revisions and prints the runtime of every part side by side.
+/// Without a selection only the days that exist at both revisions are compared.
+pub fn diff(rev_a: &str, rev_b: &str, days: Option<&[Day]>) -> Result<(), AOCError> {
+    let commit_a = Worktree::resolve(rev_a)?;
+    let commit_b = Worktree::resolve(rev_b)?;
+    if commit_a == commit_b {
+        return Err(AOCError::InvalidArgument(format!(
+            "{rev_a} and {rev_b} are the same commit"
+        )));
+    }
+    let a = Worktree::checkout(rev_a, &commit_a)?;
+    let b = Worktree::checkout(rev_b, &commit_b)?;
+    let days_a = a.build(days)?;
+    let days_b = b.build(days)?;
+    let days = days.map_or_else(
+        || {
+            days_a
+                .iter()
+                .copied()
+                .filter(|d| days_b.contains(d))
+                .collect()
+        },
+        <[Day]>::to_vec,
+    );
+    let width = rev_a.len().max(rev_b.len()).max(10);
+    println!(
+        "{ANSI_BOLD}{:<10}  {:>4}  {rev_a:>width$}  {rev_b:>width$}  {:>8}{ANSI_RESET}",
+        "Day", "Part", "change"
+    );
+    let mut total = [Duration::ZERO; 2];
+    for &day in &days {
+        if !days_a.contains(&day) && !days_b.contains(&day) {
+            continue;
+        }
+        // alternate between the revisions, so that both see the same load
+        let runs_a = days_a.contains(&day).then(|| a.run(day)).transpose()?;
+        let runs_b = days_b.contains(&day).then(|| b.run(day)).transpose()?;
+        for part in 0..2 {
+            let a = runs_a.as_ref().map(|r| &r[part]);
+            let b = runs_b.as_ref().map(|r| &r[part]);
+            let time_a = a.and_then(runtime);
+            let time_b = b.and_then(runtime);
+            if let (Some(time_a), Some(time_b)) = (time_a, time_b) {
+                total[0] += time_a;
+                total[1] += time_b;
+            }
+            let differs = matches!((a, b), (Some(a), Some(b)) if a.result != b.result);
+            println!(
+                "{:<10}  {:>4}  {:>width$}  {:>width$}  {:>8}{}",
+                day.to_string(),
+                part + 1,
+                format_runtime(time_a),
+                format_runtime(time_b),
+                change(time_a, time_b),
+                if differs { "  answers differ" } else { "" }
+            );
+        }
+    }
+    println!(
+        "{ANSI_BOLD}{:<10}  {:>4}  {:>width$}  {:>width$}  {:>8}{ANSI_RESET}",
+        "Total",
+        "",
+        format_runtime(Some(total[0])),
+        format_runtime(Some(total[1])),
+        change(Some(total[0]), Some(total[1]))
+    );
+    Ok(())
+}
+
+/// The runtime of a part that produced a result.
+fn runtime(result: &PartDayResult<Answer>) -> Option<Duration> {
+    result.result.as_ref().map(|_| result.average_duration())
+}
+/// The relative change from `a` to `b`, like `-12.5%`.
+fn change(a: Option<Duration>, b: Option<Duration>) -> String {
+    match (a, b) {
+        (Some(a), Some(b)) if !a.is_zero() => {
+            format!("{:+.1}%", (b.as_secs_f64() / a.as_secs_f64() - 1.0) * 100.0)
+        }
+        _ => "-".to_owned(),
+    }
+}
+/// Runs git in the current repository and returns its trimmed output.
+fn git(args: &[&str]) -> Result<String, AOCError> {
+    let output = Command::new("git")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(AOCError::command("git"))?;
+    if !output.status.success() {
+        return Err(AOCError::BadExitStatus {
+            command: format!("git {}", args.join(" ")),
+            status: output.status,
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change() {
+        let ms = Duration::from_millis;
+        assert_eq!(change(Some(ms(8)), Some(ms(6))), "-25.0%");
+        assert_eq!(change(Some(ms(4)), Some(ms(5))), "+25.0%");
+        assert_eq!(change(Some(Duration::ZERO), Some(ms(5))), "-");
+        assert_eq!(change(None, Some(ms(5))), "-");
+    }
+}
@@ -1,23 +1,24 @@
 use std::{fs, path::Path, process::Output};
 
 use crate::cli::{
-    aoc_cli::{AOCCommands, AOCError, build_args, call},
+    aoc_cli::{AOCCommands, build_args, call},
     day::Day,
+    error::AOCError,
 };
 
 pub fn download(day: Day) -> Result<Output, AOCError> {
     let input_path = day.input_path();
     let puzzle_path = day.puzzle_path();
-    create_path(&input_path).map_err(|_| AOCError::FailedCreateDir(input_path.clone()))?;
-    create_path(&puzzle_path).map_err(|_| AOCError::FailedCreateDir(puzzle_path.clone()))?;
+    create_path(&input_path).map_err(AOCError::create_dir(&input_path))?;
+    create_path(&puzzle_path).map_err(AOCError::create_dir(&puzzle_path))?;
     let args = build_args(
         AOCCommands::Download,
         &[
             "--overwrite",
             "--input-file",
-            input_path.to_str().unwrap(),
+            &input_path.to_string_lossy(),
             "--puzzle-file",
-            puzzle_path.to_str().unwrap(),
+            &puzzle_path.to_string_lossy(),
         ],
         day,
     );
@@ -25,16 +26,15 @@ pub fn download(day: Day) -> Result<Output, AOCError> {
     println!("---");
     println!(
         "🎄 Successfully wrote input to \"{}\".",
-        &input_path.to_str().unwrap()
+        input_path.display()
     );
     println!(
         "🎄 Successfully wrote puzzle to \"{}\".",
-        &puzzle_path.to_str().unwrap()
+        puzzle_path.display()
     );
     Ok(output)
 }
 
 pub fn create_path(path: &Path) -> Result<(), std::io::Error> {
-    let dir = path.parent().unwrap();
-    fs::create_dir_all(dir)
+    path.parent().map_or(Ok(()), fs::create_dir_all)
 }
@@ -0,0 +1,58 @@
+use std::{fs, path::PathBuf, process::Stdio};
+
+use crate::cli::{commands::download::create_path, day::Day, error::AOCError};
+
+/// Lets the binary of the day generate an input and stores it in the examples area.
+pub fn generate(day: Day, size: usize, seed: u64, release: bool) -> Result<PathBuf, AOCError> {
+    let args = [
+        "--generate".to_owned(),
+        "--size".to_owned(),
+        size.to_string(),
+        "--seed".to_owned(),
+        seed.to_string(),
+    ];
+    let output = day
+        .command(release, &args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(AOCError::command("cargo"))?;
+    if !output.status.success() {
+        return Err(AOCError::BadExitStatus {
+            command: format!("cargo run --bin {} -- --generate", day.bin_name()),
+            status: output.status,
+        });
+    }
+    let path = day.generated_path(size, seed);
+    create_path(&path).map_err(AOCError::create_dir(&path))?;
+    fs::write(&path, output.stdout).map_err(AOCError::write(&path))?;
+    println!(
+        "🎄 Successfully wrote generated input to \"{}\".",
+        path.display()
+    );
+    Ok(path)
+}
+/// Benchmarks both parts of the day on generated inputs of growing size.
+pub fn scaling(day: Day, size: usize, seed: u64, release: bool) -> Result<(), AOCError> {
+    let args = [
+        "--scaling".to_owned(),
+        "--size".to_owned(),
+        size.to_string(),
+        "--seed".to_owned(),
+        seed.to_string(),
+    ];
+    let status = day
+        .command(release, &args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .map_err(AOCError::command("cargo"))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(AOCError::BadExitStatus {
+            command: format!("cargo run --bin {} -- --scaling", day.bin_name()),
+            status,
+        })
+    }
+}
@@ -0,0 +1,236 @@
+use core::{cmp::Reverse, time::Duration};
+use std::{
+    env, fs,
+    io::Write as _,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use crate::cli::{
+    commands::download::create_path,
+    day::Day,
+    error::AOCError,
+    leaderboard::{Leaderboard, Member},
+    runner::{ANSI_BOLD, ANSI_RESET},
+};
+
+/// Advent of Code asks to fetch a leaderboard at most every 15 minutes.
+const REFRESH: Duration = Duration::from_mins(15);
+const USER_AGENT: &str = "all_aoc leaderboard viewer";
+
+/// Where the leaderboard JSON comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A local file, for offline use.
+    File(PathBuf),
+    /// The website, with the session cookie of aoc-cli. Cached in `data/leaderboards`.
+    Fetch { id: String },
+}
+impl Source {
+    /// The id of a private leaderboard is the number in its URL.
+    pub fn fetch(id: &str) -> Result<Self, String> {
+        if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("Leaderboard id has to be a number: {id}"));
+        }
+        Ok(Self::Fetch { id: id.to_owned() })
+    }
+}
+
+/// Prints the standings of the year and, if a day is given, the times of every member on it.
+pub fn leaderboard(year: u16, day: Option<u8>, source: &Source) -> Result<(), AOCError> {
+    let board = match source {
+        Source::File(path) => fs::read_to_string(path)
+            .map_err(AOCError::read(path))?
+            .parse::<Leaderboard>()
+            .map_err(|message| AOCError::InvalidFile {
+                path: path.clone(),
+                message,
+            })?,
+        Source::Fetch { id } => fetch(year, id)?,
+    };
+    if board.year != year {
+        return Err(AOCError::InvalidArgument(format!(
+            "The leaderboard is from {}, not from {year}",
+            board.year
+        )));
+    }
+    print_standings(&board);
+    if let Some(day) = day {
+        println!();
+        print_day(&board, day);
+    }
+    Ok(())
+}
+
+fn fetch(year: u16, id: &str) -> Result<Leaderboard, AOCError> {
+    let path = Day { day: 1, year }.leaderboard_path(id);
+    let fresh = fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < REFRESH);
+    if fresh {
+        let json = fs::read_to_string(&path).map_err(AOCError::read(&path))?;
+        return json
+            .parse()
+            .map_err(|message| AOCError::InvalidFile { path, message });
+    }
+    let session = session()?;
+    let url = format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json");
+    // the cookie goes through stdin, so that it doesn't show up in the process list
+    let mut child = Command::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--header",
+            "@-",
+            "--user-agent",
+            USER_AGENT,
+            &url,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(AOCError::command("curl"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "Cookie: session={session}").map_err(AOCError::command("curl"))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(AOCError::command("curl"))?;
+    if !output.status.success() {
+        return Err(AOCError::BadExitStatus {
+            command: format!("curl {url}"),
+            status: output.status,
+        });
+    }
+    let invalid = |message: String| AOCError::InvalidResponse {
+        url: url.clone(),
+        message: format!("{message}, maybe the session cookie expired"),
+    };
+    let json = String::from_utf8(output.stdout).map_err(|e| invalid(e.to_string()))?;
+    let board = json.parse().map_err(invalid)?;
+    create_path(&path).map_err(AOCError::create_dir(&path))?;
+    fs::write(&path, json).map_err(AOCError::write(&path))?;
+    Ok(board)
+}
+
+/// The session cookie, from the same places aoc-cli looks.
+fn session() -> Result<String, AOCError> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Ok(session.trim().to_owned());
+    }
+    let home = env::var("HOME").map_err(|_| AOCError::MissingSession)?;
+    [".adventofcode.session", ".config/adventofcode.session"]
+        .into_iter()
+        .find_map(|file| fs::read_to_string(Path::new(&home).join(file)).ok())
+        .map(|session| session.trim().to_owned())
+        .filter(|session| !session.is_empty())
+        .ok_or(AOCError::MissingSession)
+}
+
+fn print_standings(board: &Leaderboard) {
+    let scores = board.local_scores();
+    let mut order = (0..board.members.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| {
+        let last_star = board.members[i].last_star().unwrap_or(i64::MAX);
+        (Reverse(scores[i]), last_star)
+    });
+    // since 2025 there are only 12 days
+    let days = if board.year >= 2025 { 12 } else { 25 };
+    println!("{ANSI_BOLD}Private Leaderboard {}{ANSI_RESET}", board.year);
+    let tens = (1..=days)
+        .map(|d| if d < 10 { ' ' } else { tens_digit(d) })
+        .collect::<String>();
+    let ones = (1..=days).map(ones_digit).collect::<String>();
+    println!("            {tens}");
+    println!("            {ones}");
+    for (rank, &i) in order.iter().enumerate() {
+        let member = &board.members[i];
+        println!(
+            "{:>3}) {:>5}  {}  {} ({} stars)",
+            rank + 1,
+            scores[i],
+            star_strip(member, days),
+            member.name,
+            member.star_count()
+        );
+    }
+}
+fn print_day(board: &Leaderboard, day: u8) {
+    let times = board.day_times(day);
+    println!(
+        "{ANSI_BOLD}Day {}{ANSI_RESET}",
+        Day {
+            day,
+            year: board.year
+        }
+    );
+    if times.is_empty() {
+        println!("Nobody has a star yet");
+        return;
+    }
+    let width = times
+        .iter()
+        .map(|t| t.member.name.chars().count())
+        .max()
+        .unwrap_or_default();
+    println!(
+        "     {:width$}  {:>8}  {:>8}  {:>8}",
+        "", "star 1", "star 2", "delta"
+    );
+    for (rank, t) in times.iter().enumerate() {
+        println!(
+            "{:>3}) {:width$}  {:>8}  {:>8}  {:>8}",
+            rank + 1,
+            t.member.name,
+            format_time(t.first),
+            format_time(t.second),
+            format_time(t.delta())
+        );
+    }
+}
+/// `*` for both stars of a day, `+` for only the first and `.` for none.
+fn star_strip(member: &Member, days: u8) -> String {
+    (1..=days)
+        .map(|day| match (member.star(day, 0), member.star(day, 1)) {
+            (Some(_), Some(_)) => '*',
+            (Some(_), None) => '+',
+            _ => '.',
+        })
+        .collect()
+}
+/// Like on the website, `hh:mm:ss` and `>24h` for everything longer.
+fn format_time(seconds: Option<i64>) -> String {
+    match seconds {
+        None => "-".to_owned(),
+        Some(s) if s >= 24 * 3_600 => ">24h".to_owned(),
+        Some(s) => format!("{:02}:{:02}:{:02}", s / 3_600, s / 60 % 60, s % 60),
+    }
+}
+fn tens_digit(day: u8) -> char {
+    char::from(b'0' + day / 10)
+}
+fn ones_digit(day: u8) -> char {
+    char::from(b'0' + day % 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format_time(Some(3_725)), "01:02:05");
+        assert_eq!(format_time(Some(86_400)), ">24h");
+        assert_eq!(format_time(None), "-");
+        let board: Leaderboard = r#"{"event":"2016","members":{"7":{"name":"Ada","completion_day_level":{
+            "1":{"1":{"get_star_ts":1480568400,"star_index":1},"2":{"get_star_ts":1480568500,"star_index":2}},
+            "3":{"1":{"get_star_ts":1480740000,"star_index":3}}}}}}"#
+            .parse()
+            .unwrap();
+        assert_eq!(star_strip(&board.members[0], 5), "*.+..");
+    }
+}
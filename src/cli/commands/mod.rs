@@ -1,3 +1,10 @@
+pub mod bench;
 pub mod download;
+pub mod generate;
+pub mod leaderboard;
 pub mod prepare;
+pub mod read;
+pub mod report;
+pub mod search;
 pub mod solve;
+pub mod stats;
@@ -1,32 +1,39 @@
 use std::{fs, fs::OpenOptions, io::Write as _};
 
-use crate::cli::{aoc_cli::AOCError, commands::download::create_path, day::Day};
+use crate::cli::{commands::download::create_path, day::Day, error::AOCError, progress::Progress};
 
 use super::download::download;
 
 pub fn prepare(day: Day) -> Result<(), AOCError> {
     download(day)?;
+    let mut progress = Progress::load(day)?;
+    progress.start();
+    progress.save()?;
     let bin_path = day.bin_path();
     if !bin_path.exists() {
-        create_path(&bin_path).map_err(|_| AOCError::FailedCreateDir(bin_path.clone()))?;
+        create_path(&bin_path).map_err(AOCError::create_dir(&bin_path))?;
         let mut file = OpenOptions::new()
             .write(true)
             .create_new(true)
-            .open(bin_path)
-            .map_err(|e| AOCError::FileError(e.to_string()))?;
+            .open(&bin_path)
+            .map_err(AOCError::write(&bin_path))?;
         file.write_all(
             MODULE_TEMPLATE
                 .replace("DAY_NUMBER", &format!("{}, {}", day.day, day.year))
                 .as_bytes(),
         )
-        .map_err(|e| AOCError::FileError(e.to_string()))?;
+        .map_err(AOCError::write(&bin_path))?;
     }
     let examples_path = day.examples_path();
     if !examples_path.exists() {
-        create_path(&examples_path)
-            .map_err(|_| AOCError::FailedCreateDir(examples_path.clone()))?;
+        create_path(&examples_path).map_err(AOCError::create_dir(&examples_path))?;
         let _ = fs::File::create_new(examples_path);
     }
+    let answers_path = day.answers_path();
+    if !answers_path.exists() {
+        create_path(&answers_path).map_err(AOCError::create_dir(&answers_path))?;
+        let _ = fs::File::create_new(answers_path);
+    }
     Ok(())
 }
 
@@ -51,23 +58,11 @@ mod tests {
         assert_eq!(result, None);
     }
 
-    #[test]
-    fn test_part_one_actual() {
-        let result = part_one(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, None);
-    }
-
     #[test]
     fn test_part_two() {
         let result = part_two(&all_aoc::cli::read_examples_file(DAY));
         assert_eq!(result, None);
     }
-
-    #[test]
-    fn test_part_two_actual() {
-        let result = part_two(&all_aoc::cli::read_inputs_file(DAY));
-        assert_eq!(result, None);
-    }
 }
 
 ";
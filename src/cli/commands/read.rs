@@ -0,0 +1,300 @@
+use core::fmt::Write as _;
+use std::fs;
+
+use crate::cli::{
+    day::Day,
+    error::AOCError,
+    runner::{ANSI_BOLD, ANSI_RESET},
+};
+
+/// Emphasised text, which usually carries the answer of an example.
+const ANSI_EMPHASIS: &str = "\u{1b}[0;1;33m";
+const ANSI_CODE: &str = "\u{1b}[0;36m";
+/// Width used if neither `--width` nor `COLUMNS` is set.
+pub const DEFAULT_WIDTH: usize = 80;
+
+/// Prints the downloaded puzzle description of the day, optionally only one of its parts.
+pub fn read(day: Day, part: Option<u8>, width: usize) -> Result<(), AOCError> {
+    let path = day.puzzle_path();
+    if !path.exists() {
+        return Err(AOCError::MissingFile { day, path });
+    }
+    let markdown = fs::read_to_string(&path).map_err(AOCError::read(&path))?;
+    let blocks = parse(&markdown);
+    let blocks = select_part(&blocks, part).ok_or_else(|| AOCError::InvalidFile {
+        path,
+        message: "Part two is missing, download it again after solving part one".to_owned(),
+    })?;
+    print!("{}", render(blocks, width));
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Block {
+    Heading(String),
+    Paragraph(String),
+    Item(String),
+    Code(Vec<String>),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Style {
+    emphasis: bool,
+    code: bool,
+}
+impl Style {
+    const fn ansi(self) -> &'static str {
+        if self.emphasis {
+            ANSI_EMPHASIS
+        } else if self.code {
+            ANSI_CODE
+        } else {
+            ANSI_RESET
+        }
+    }
+}
+
+/// Splits the markdown written by aoc-cli into headings, paragraphs, list items and code blocks.
+fn parse(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines = markdown.lines().peekable();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || is_rule(trimmed) {
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            let code = lines
+                .by_ref()
+                .take_while(|l| !l.trim_start().starts_with("```"))
+                .map(str::to_owned)
+                .collect();
+            blocks.push(Block::Code(code));
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            blocks.push(Block::Heading(
+                heading.trim_start_matches('#').trim().to_owned(),
+            ));
+        } else if lines.peek().is_some_and(|next| is_rule(next.trim())) {
+            blocks.push(Block::Heading(trimmed.to_owned()));
+        } else {
+            let item = ["* ", "- "]
+                .into_iter()
+                .find_map(|prefix| trimmed.strip_prefix(prefix));
+            let mut text = item.unwrap_or(trimmed).to_owned();
+            while let Some(next) = lines.next_if(|l| !l.trim().is_empty() && !starts_block(l)) {
+                text.push(' ');
+                text.push_str(next.trim());
+            }
+            blocks.push(if item.is_some() {
+                Block::Item(text)
+            } else {
+                Block::Paragraph(text)
+            });
+        }
+    }
+    blocks
+}
+fn is_rule(line: &str) -> bool {
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+fn starts_block(line: &str) -> bool {
+    let line = line.trim();
+    ["```", "#", "* ", "- "]
+        .into_iter()
+        .any(|prefix| line.starts_with(prefix))
+        || is_rule(line)
+}
+
+/// Part one ends at the heading of part two. `None` if part two is requested but missing.
+fn select_part(blocks: &[Block], part: Option<u8>) -> Option<&[Block]> {
+    let two = blocks
+        .iter()
+        .position(|b| matches!(b, Block::Heading(h) if h.contains("Part Two")));
+    match (part, two) {
+        (Some(1), Some(i)) => Some(blocks.split_at(i).0),
+        (Some(2), Some(i)) => Some(blocks.split_at(i).1),
+        (Some(2), None) => None,
+        _ => Some(blocks),
+    }
+}
+
+fn render(blocks: &[Block], width: usize) -> String {
+    let mut out = String::new();
+    for block in blocks {
+        match block {
+            Block::Heading(text) => {
+                let text: String = styled(text).into_iter().map(|(c, _)| c).collect();
+                let _ = writeln!(out, "{ANSI_BOLD}{text}{ANSI_RESET}");
+            }
+            Block::Paragraph(text) => wrap(&mut out, &styled(text), width, "", ""),
+            Block::Item(text) => wrap(&mut out, &styled(text), width, "  \u{2022} ", "    "),
+            Block::Code(lines) => {
+                for line in lines {
+                    let _ = writeln!(out, "    {ANSI_CODE}{line}{ANSI_RESET}");
+                }
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Resolves escapes, links, `*emphasis*` and `` `code` `` into styled characters.
+///
+/// Inside code an asterisk only counts as emphasis if it is next to a backtick, as in
+/// `` `*142*` ``, so that code like `` `a*b` `` stays intact.
+fn styled(text: &str) -> Vec<(char, Style)> {
+    let mut styled = Vec::with_capacity(text.len());
+    let mut style = Style::default();
+    let mut after_tick = false;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let opened = after_tick;
+        after_tick = false;
+        match c {
+            '\\' => {
+                if let Some((_, c)) = chars.next() {
+                    styled.push((c, style));
+                }
+            }
+            '`' => {
+                style.code = !style.code;
+                after_tick = style.code;
+            }
+            '*' if !style.code || opened || chars.peek().is_some_and(|&(_, c)| c == '`') => {
+                while chars.next_if(|&(_, c)| c == '*').is_some() {}
+                style.emphasis = !style.emphasis;
+            }
+            '[' if text.get(i + 1..).is_some_and(|rest| {
+                rest.split_once(']')
+                    .is_some_and(|(_, after)| after.starts_with('('))
+            }) => {}
+            ']' if chars.peek().is_some_and(|&(_, c)| c == '(') => {
+                chars.by_ref().find(|&(_, c)| c == ')');
+            }
+            c => styled.push((c, style)),
+        }
+    }
+    styled
+}
+
+/// Appends the text word by word, breaking lines before they get wider than `width`.
+fn wrap(out: &mut String, text: &[(char, Style)], width: usize, first: &str, indent: &str) {
+    let mut prefix = first;
+    let mut line: Vec<(char, Style)> = Vec::new();
+    for word in text
+        .split(|(c, _)| c.is_whitespace())
+        .filter(|w| !w.is_empty())
+    {
+        if !line.is_empty() && prefix.chars().count() + line.len() + 1 + word.len() > width {
+            push_line(out, prefix, &line);
+            prefix = indent;
+            line.clear();
+        }
+        if let Some(&(_, last)) = line.last() {
+            let space = if word.first().is_some_and(|&(_, s)| s == last) {
+                last
+            } else {
+                Style::default()
+            };
+            line.push((' ', space));
+        }
+        line.extend_from_slice(word);
+    }
+    if !line.is_empty() {
+        push_line(out, prefix, &line);
+    }
+}
+fn push_line(out: &mut String, prefix: &str, line: &[(char, Style)]) {
+    out.push_str(prefix);
+    let mut current = Style::default();
+    for &(c, style) in line {
+        if style != current {
+            out.push_str(style.ansi());
+            current = style;
+        }
+        out.push(c);
+    }
+    if current != Style::default() {
+        out.push_str(ANSI_RESET);
+    }
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r"\--- Day 1: Trebuchet?! ---
+----------
+
+Something is wrong with global snow production, and you've been selected to take a look.
+See [the calendar](/2023).
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+* `*1abc2*` has `12`
+* the sum is `a*b`
+
+Adding these together produces `*142*`.
+
+Your puzzle answer was `54927`.
+
+\--- Part Two ---
+----------
+
+Now *what* is the sum?
+";
+
+    fn strip(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\u{1b}' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_parse() {
+        let blocks = parse(PUZZLE);
+        assert_eq!(blocks.len(), 9);
+        assert_eq!(
+            blocks[0],
+            Block::Heading(r"\--- Day 1: Trebuchet?! ---".to_owned())
+        );
+        assert_eq!(
+            blocks[2],
+            Block::Code(vec!["1abc2".to_owned(), "pqr3stu8vwx".to_owned()])
+        );
+        assert_eq!(blocks[3], Block::Item("`*1abc2*` has `12`".to_owned()));
+        assert_eq!(select_part(&blocks, Some(1)).map(<[_]>::len), Some(7));
+        assert_eq!(select_part(&blocks, Some(2)).map(<[_]>::len), Some(2));
+        assert_eq!(select_part(&blocks[..7], Some(2)), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let blocks = parse(PUZZLE);
+        let rendered = render(&blocks, 40);
+        assert!(rendered.contains(&format!("{ANSI_EMPHASIS}142{ANSI_RESET}.")));
+        assert!(rendered.contains(&format!("{ANSI_CODE}a*b{ANSI_RESET}")));
+        let plain = strip(&rendered);
+        assert!(plain.lines().all(|l| l.chars().count() <= 40));
+        assert!(plain.starts_with("--- Day 1: Trebuchet?! ---\n\nSomething is wrong"));
+        assert!(plain.contains("See the calendar.\n"));
+        assert!(plain.contains("    1abc2\n"));
+        assert!(plain.contains("  \u{2022} 1abc2 has 12\n"));
+        assert!(
+            strip(&render(select_part(&blocks, Some(2)).unwrap(), 80))
+                .ends_with("Now what is the sum?\n\n")
+        );
+    }
+}
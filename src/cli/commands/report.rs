@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use crate::cli::{
+    cache::Cache,
+    day::Day,
+    error::AOCError,
+    index::{DayInfo, group_by},
+    runner::{ANSI_BOLD, ANSI_RESET},
+};
+
+/// Prints the tags, helper modules and cached parts of every day, or with `by_tag`
+/// the days of every tag and of every helper module.
+pub fn report(by_tag: bool) -> Result<(), AOCError> {
+    let infos = DayInfo::all()?;
+    if !by_tag {
+        for info in &infos {
+            println!(
+                "{ANSI_BOLD}{}{ANSI_RESET} tags: {} helpers: {} cached: {}",
+                info.day,
+                list(&info.tags),
+                list(&info.helpers),
+                cached(info.day)?
+            );
+        }
+        return Ok(());
+    }
+    print_groups("Tags", &group_by(&infos, |i| &i.tags));
+    let untagged = infos.iter().filter(|i| i.tags.is_empty()).count();
+    if untagged > 0 {
+        println!("  {untagged} days without tags");
+    }
+    println!();
+    print_groups("Helper modules", &group_by(&infos, |i| &i.helpers));
+    Ok(())
+}
+
+fn print_groups(title: &str, groups: &BTreeMap<&str, Vec<Day>>) {
+    println!("{ANSI_BOLD}{title}{ANSI_RESET}");
+    for (key, days) in groups {
+        println!("  {ANSI_BOLD}{key}{ANSI_RESET} ({})", days.len());
+        for line in by_year(days) {
+            println!("    {line}");
+        }
+    }
+}
+/// The parts with valid cached results, like `1 2 (release)`.
+fn cached(day: Day) -> Result<String, AOCError> {
+    let Some((profile, cache)) = Cache::current(day)? else {
+        return Ok("-".to_owned());
+    };
+    let parts = cache
+        .results
+        .keys()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    Ok(format!("{} ({profile})", parts.join(" ")))
+}
+fn list(items: &[String]) -> String {
+    if items.is_empty() {
+        "-".to_owned()
+    } else {
+        items.join(", ")
+    }
+}
+/// The days as one line per year, like `2024: 6 14 16`.
+fn by_year(days: &[Day]) -> Vec<String> {
+    let mut years = BTreeMap::<_, Vec<_>>::new();
+    for day in days {
+        years.entry(day.year).or_default().push(day.day.to_string());
+    }
+    years
+        .into_iter()
+        .map(|(year, days)| format!("{year}: {}", days.join(" ")))
+        .collect()
+}
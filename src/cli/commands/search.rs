@@ -0,0 +1,241 @@
+use core::{cmp::Reverse, ops::Range};
+use std::{fs, path::PathBuf};
+
+use crate::cli::{
+    day::Day,
+    error::AOCError,
+    regex::Regex,
+    runner::{ANSI_BOLD, ANSI_RESET},
+};
+
+/// Number of hits printed if `--limit` is not set.
+pub const DEFAULT_LIMIT: usize = 10;
+/// Characters of context shown around the first match.
+const CONTEXT: usize = 40;
+
+/// A puzzle statement or solution with all matches of the query.
+struct Hit {
+    day: Day,
+    path: PathBuf,
+    text: String,
+    matches: Vec<Range<usize>>,
+}
+impl Hit {
+    fn lines(&self) -> usize {
+        self.text.lines().count().max(1)
+    }
+}
+
+/// Searches all stored puzzle statements and solutions and prints the densest hits first.
+///
+/// Words match case-insensitively at the start of a word and every word has to be found in the
+/// file. With `regex` the query is a single [`Regex`].
+pub fn search(query: &str, regex: bool, limit: usize) -> Result<(), AOCError> {
+    let patterns = patterns(query, regex).map_err(AOCError::InvalidArgument)?;
+    let mut hits = Vec::new();
+    for (day, path) in documents() {
+        let text = fs::read_to_string(&path).map_err(AOCError::read(&path))?;
+        if let Some(matches) = find(&patterns, &text) {
+            hits.push(Hit {
+                day,
+                path,
+                text,
+                matches,
+            });
+        }
+    }
+    rank(&mut hits);
+    if hits.is_empty() {
+        println!("No matches for \"{query}\"");
+    }
+    for hit in hits.iter().take(limit) {
+        let (line, snippet) = snippet(&hit.text, &hit.matches);
+        let title = title(hit.day).map(|t| format!(" {t}")).unwrap_or_default();
+        let count = match hit.matches.len() {
+            1 => "1 match".to_owned(),
+            n => format!("{n} matches"),
+        };
+        println!(
+            "{ANSI_BOLD}{}{ANSI_RESET}{title} ({count} in {})",
+            hit.day,
+            hit.path.display()
+        );
+        println!("    {line}: {snippet}");
+    }
+    if hits.len() > limit {
+        println!("... and {} more", hits.len() - limit);
+    }
+    Ok(())
+}
+
+fn patterns(query: &str, regex: bool) -> Result<Vec<Regex>, String> {
+    if query.trim().is_empty() {
+        return Err("Missing Query".to_owned());
+    }
+    if regex {
+        return Ok(vec![Regex::new(query)?]);
+    }
+    query
+        .split_whitespace()
+        .map(|word| {
+            let boundary = if word.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_') {
+                r"\b"
+            } else {
+                ""
+            };
+            Regex::new(&format!("(?i){boundary}{}", Regex::escape(word)))
+        })
+        .collect()
+}
+
+/// All non-empty matches sorted by position, `None` if one of the patterns doesn't match.
+fn find(patterns: &[Regex], text: &str) -> Option<Vec<Range<usize>>> {
+    let mut matches = Vec::new();
+    for pattern in patterns {
+        let len = matches.len();
+        matches.extend(pattern.find_iter(text).filter(|m| !m.is_empty()));
+        if matches.len() == len {
+            return None;
+        }
+    }
+    matches.sort_by_key(|m| m.start);
+    Some(matches)
+}
+
+/// Sorts by matches per line, then by the number of matches.
+fn rank(hits: &mut [Hit]) {
+    hits.sort_by(|a, b| {
+        (b.matches.len() * a.lines())
+            .cmp(&(a.matches.len() * b.lines()))
+            .then_with(|| b.matches.len().cmp(&a.matches.len()))
+            .then_with(|| (a.day.year, a.day.day).cmp(&(b.day.year, b.day.day)))
+    });
+}
+
+/// The solutions in `src/bin` and the puzzles in `data/puzzles`.
+fn documents() -> Vec<(Day, PathBuf)> {
+    let mut documents = Day::all()
+        .into_iter()
+        .map(|day| (day, day.bin_path()))
+        .collect::<Vec<_>>();
+    let years = fs::read_dir("data/puzzles").into_iter().flatten().flatten();
+    for year in years {
+        let Some(year_number) = year.file_name().to_str().and_then(|y| y.parse().ok()) else {
+            continue;
+        };
+        for entry in fs::read_dir(year.path()).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if let Some(day) = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.parse().ok())
+                .filter(|d| (1..=25).contains(d))
+                && path.extension().is_some_and(|e| e == "md")
+            {
+                let day = Day {
+                    day,
+                    year: year_number,
+                };
+                documents.push((day, path));
+            }
+        }
+    }
+    documents.sort_by_key(|(day, path)| (day.year, day.day, Reverse(path.clone())));
+    documents
+}
+
+/// The name of the day from the first heading of its puzzle, like `Trebuchet?!`.
+fn title(day: Day) -> Option<String> {
+    let puzzle = fs::read_to_string(day.puzzle_path()).ok()?;
+    let heading = puzzle.lines().find(|l| l.contains("--- Day"))?;
+    let (_, name) = heading.split_once(':')?;
+    Some(name.trim().trim_end_matches('-').trim().to_owned())
+}
+
+/// The line number of the first match and the text around it with all matches highlighted.
+fn snippet(text: &str, matches: &[Range<usize>]) -> (usize, String) {
+    let Some(first) = matches.first() else {
+        return (0, String::new());
+    };
+    let line_start = text[..first.start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = text[first.end..]
+        .find('\n')
+        .map_or(text.len(), |i| first.end + i);
+    let mut start = first.start.saturating_sub(CONTEXT).max(line_start);
+    while !text.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (first.end + CONTEXT).min(line_end);
+    while !text.is_char_boundary(end) {
+        end += 1;
+    }
+    let mut snippet = String::new();
+    if start > line_start {
+        snippet.push_str("...");
+    }
+    let mut pos = start + (text[start..end].len() - text[start..end].trim_start().len());
+    for m in matches {
+        if m.start < pos || m.end > end {
+            continue;
+        }
+        snippet.push_str(&text[pos..m.start]);
+        snippet.push_str(ANSI_BOLD);
+        snippet.push_str(&text[m.clone()]);
+        snippet.push_str(ANSI_RESET);
+        pos = m.end;
+    }
+    snippet.push_str(text[pos..end].trim_end());
+    if end < line_end {
+        snippet.push_str("...");
+    }
+    (text[..first.start].matches('\n').count() + 1, snippet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(day: u8, text: &str, query: &str) -> Option<Hit> {
+        let matches = find(&patterns(query, false).unwrap(), text)?;
+        Some(Hit {
+            day: Day { day, year: 2020 },
+            path: PathBuf::new(),
+            text: text.to_owned(),
+            matches,
+        })
+    }
+
+    #[test]
+    fn test_find() {
+        let text = "use chinese_remainder;\nThe Chinese Remainder Theorem\nremainders";
+        let m = find(&patterns("chinese remainder", false).unwrap(), text).unwrap();
+        assert_eq!(m.len(), 4);
+        assert!(find(&patterns("chinese modulo", false).unwrap(), text).is_none());
+        assert!(find(&patterns("hinese", false).unwrap(), text).is_none());
+        let m = find(&patterns(r"remainder\b", true).unwrap(), text).unwrap();
+        assert_eq!(m.len(), 1);
+        assert_eq!(patterns("(", true).unwrap_err(), "Unclosed group at 0");
+        assert_eq!(patterns(" ", false).unwrap_err(), "Missing Query");
+    }
+
+    #[test]
+    fn test_rank_and_snippet() {
+        let mut hits = [
+            hit(1, "a\nb\nbfs\nc", "bfs").unwrap(),
+            hit(2, "bfs bfs", "bfs").unwrap(),
+            hit(3, "bfs", "bfs").unwrap(),
+        ];
+        rank(&mut hits);
+        assert_eq!(hits.map(|h| h.day.day), [2, 3, 1]);
+        let text = format!("first\n    {}needle in the haystack\n", "x ".repeat(30));
+        let matches = find(&patterns("needle", false).unwrap(), &text).unwrap();
+        let (line, snippet) = snippet(&text, &matches);
+        assert_eq!(line, 2);
+        assert_eq!(
+            snippet,
+            format!(
+                "...x x x x x x x x x x x x x x x x x x x x {ANSI_BOLD}needle{ANSI_RESET} in the haystack"
+            )
+        );
+    }
+}
@@ -1,51 +1,136 @@
 use core::time::Duration;
 use std::io::{self, Write as _};
 
-use crate::cli::day::Day;
+use crate::cli::{
+    cache::Cache,
+    day::Day,
+    error::AOCError,
+    runner::{Answer, PartDayResult},
+    timings::{Cost, Profile, Timings},
+};
 
-pub fn single_day(day: Day, release: bool, submit: Option<u8>, time: bool) {
-    let (e1, e2) = day.execute(release, time, submit);
+/// Solves the parts of the day that fit into `max_cost`, or with `cache` takes the results
+/// from the cache if nothing changed. Timed runs and submissions always run the binary.
+pub fn single_day(
+    day: Day,
+    release: bool,
+    submit: Option<u8>,
+    time: bool,
+    max_cost: Option<Cost>,
+    cache: bool,
+) -> Result<(), AOCError> {
+    let (e1, e2, cached) = if submit.is_some() {
+        let (e1, e2) = day.execute(release, time, submit, None)?;
+        (e1, e2, false)
+    } else {
+        let part = match selected_parts(day, max_cost).as_slice() {
+            [] => {
+                println!("Every part of {day} is above the cost limit.");
+                return Ok(());
+            }
+            [part] => Some(*part),
+            _ => None,
+        };
+        execute_cached(day, release, time, part, cache && !time)?
+    };
     println!("{}\n{}", e1.pretty_print(), e2.pretty_print());
+    if cached {
+        println!("(from the cache, --no-cache solves it again)");
+    } else {
+        record_timings(day, &e1, &e2, release);
+    }
+    Ok(())
+}
+/// Runs the day with `--viz`, so that the recorded frames are played in the terminal
+/// or, if `out` is given, written to that image file.
+pub fn visualize(
+    day: Day,
+    release: bool,
+    fps: Option<f64>,
+    out: Option<&str>,
+) -> Result<(), AOCError> {
+    let mut args = vec!["--viz".to_owned()];
+    if let Some(fps) = fps {
+        args.push("--fps".to_owned());
+        args.push(fps.to_string());
+    }
+    if let Some(out) = out {
+        args.push("--viz-out".to_owned());
+        args.push(out.to_owned());
+    }
+    let status = day
+        .command(release, &args)
+        .status()
+        .map_err(AOCError::command("cargo"))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(AOCError::BadExitStatus {
+            command: format!("cargo run --bin {} -- --viz", day.bin_name()),
+            status,
+        })
+    }
 }
-pub fn year(days: Vec<Day>, release: bool, time: bool) {
+pub fn year(
+    days: Vec<Day>,
+    release: bool,
+    time: bool,
+    max_cost: Option<Cost>,
+    cache: bool,
+) -> Result<(), AOCError> {
     let mut part_1 = vec![];
     let mut part_2 = vec![];
-    let year = days[0].year;
+    let label = if days.iter().all(|d| d.year == days[0].year) {
+        format!("In the Year {}", days[0].year)
+    } else {
+        "In these Days".to_owned()
+    };
     let days = days
         .into_iter()
         .filter(|days| days.bin_path().exists())
+        .filter_map(|day| match selected_parts(day, max_cost).as_slice() {
+            [] => None,
+            [part] => Some((day, Some(*part))),
+            _ => Some((day, None)),
+        })
         .collect::<Vec<_>>();
 
-    for (i, day) in days.iter().enumerate() {
+    let mut from_cache = 0;
+    for (i, (day, part)) in days.iter().enumerate() {
         let i = i + 1;
-        let (p1, p2) = day.execute(release, time, None);
+        let (p1, p2, cached) = execute_cached(*day, release, time, *part, cache && !time)?;
+        if cached {
+            from_cache += 1;
+        } else {
+            record_timings(*day, &p1, &p2, release);
+        }
         part_1.push(p1);
         part_2.push(p2);
 
         let bar = "=".repeat(i) + &" ".repeat(days.len() - i);
         print!("\r{bar} {}/{}", i, days.len());
-        io::stdout().flush().unwrap();
+        let _ = io::stdout().flush();
     }
     println!();
     part_1.retain_mut(|p| p.result.is_some());
     part_2.retain_mut(|p| p.result.is_some());
     println!(
-        "In the Year {year} you solved {} Part Ones and {} Part Twos.",
+        "{label} you solved {} Part Ones and {} Part Twos.",
         part_1.len(),
         part_2.len()
     );
-    let min = part_1
-        .iter()
-        .chain(part_2.iter())
-        .map(|p| p.durations.iter().min().unwrap())
-        .min()
-        .unwrap();
-    let max = part_1
-        .iter()
-        .chain(part_2.iter())
-        .map(|p| p.durations.iter().max().unwrap())
-        .max()
-        .unwrap();
+    if from_cache > 0 {
+        println!("{from_cache}/{} Days are from the cache.", days.len());
+    }
+    let durations = || {
+        part_1
+            .iter()
+            .chain(part_2.iter())
+            .flat_map(|p| &p.durations)
+    };
+    let (Some(min), Some(max)) = (durations().min(), durations().max()) else {
+        return Ok(());
+    };
     let over_1_ms = part_1
         .iter()
         .chain(part_2.iter())
@@ -56,4 +141,65 @@ pub fn year(days: Vec<Day>, release: bool, time: bool) {
         part_1.len() + part_2.len()
     );
     println!("{s}");
+    Ok(())
+}
+/// Runs the day and caches the results of the parts that ran.
+/// With `use_cache`, the cached results and runtimes are returned instead if every part
+/// is cached. The last value tells if the results came from the cache.
+fn execute_cached(
+    day: Day,
+    release: bool,
+    time: bool,
+    part: Option<u8>,
+    use_cache: bool,
+) -> Result<(PartDayResult<Answer>, PartDayResult<Answer>, bool), AOCError> {
+    let Some(key) = Cache::key(day, Profile::new(release))? else {
+        let (p1, p2) = day.execute(release, time, None, part)?;
+        return Ok((p1, p2, false));
+    };
+    let mut cache = Cache::load(day, &key)?;
+    let parts = part.map_or_else(|| vec![1, 2], |p| vec![p]);
+    if use_cache && parts.iter().all(|&p| cache.get(p).is_some()) {
+        let cached = |p| {
+            cache
+                .get(p)
+                .filter(|_| parts.contains(&p))
+                .unwrap_or(PartDayResult {
+                    day,
+                    part: p,
+                    result: None,
+                    durations: vec![],
+                })
+        };
+        return Ok((cached(1), cached(2), true));
+    }
+    let (p1, p2) = day.execute(release, time, None, part)?;
+    for res in [&p1, &p2] {
+        if parts.contains(&res.part) {
+            cache.insert(res);
+        }
+    }
+    cache.save()?;
+    Ok((p1, p2, false))
+}
+/// The parts of the day that fit into the cost class.
+/// Parts without a cost are always selected, so that they get classified.
+fn selected_parts(day: Day, max_cost: Option<Cost>) -> Vec<u8> {
+    let Some(max_cost) = max_cost else {
+        return vec![1, 2];
+    };
+    let timings = Timings::load(day).unwrap_or_else(|_| Timings::new(day));
+    [1, 2]
+        .into_iter()
+        .filter(|part| timings.cost(*part).is_none_or(|c| c <= max_cost))
+        .collect()
+}
+fn record_timings(day: Day, p1: &PartDayResult<Answer>, p2: &PartDayResult<Answer>, release: bool) {
+    let result = Timings::load(day).and_then(|mut timings| {
+        timings.record_results(&[p1, p2], Profile::new(release));
+        timings.save()
+    });
+    if let Err(e) = result {
+        eprintln!("{e}");
+    }
 }
@@ -0,0 +1,159 @@
+use core::time::Duration;
+use std::collections::BTreeMap;
+
+use crate::cli::{
+    day::Day,
+    error::AOCError,
+    progress::Progress,
+    runner::{ANSI_BOLD, ANSI_RESET},
+    timings::Timings,
+};
+
+/// The recorded progress of one day together with the runtime of its solution.
+struct DayStats {
+    progress: Progress,
+    runtimes: [Option<Duration>; 2],
+}
+
+/// Prints how long every started day took to solve, how many guesses it needed
+/// and how fast the final solution runs, followed by a summary of every year.
+pub fn stats(days: Vec<Day>) -> Result<(), AOCError> {
+    let mut years = BTreeMap::<_, Vec<_>>::new();
+    for day in days {
+        let progress = Progress::load(day)?;
+        if progress.started.is_none() && progress.guesses.is_empty() {
+            continue;
+        }
+        let timings = Timings::load(day)?;
+        years.entry(day.year).or_default().push(DayStats {
+            progress,
+            runtimes: [timings.expected_duration(1), timings.expected_duration(2)],
+        });
+    }
+    if years.is_empty() {
+        println!("No progress recorded yet, it starts with `cargo prepare`");
+        return Ok(());
+    }
+    for (year, days) in &years {
+        println!("{ANSI_BOLD}{year}{ANSI_RESET}");
+        println!(
+            "        {:>9}  {:>9}  {:>7}  {:>11}  {:>11}",
+            "1st star", "2nd star", "guesses", "runtime 1", "runtime 2"
+        );
+        for stats in days {
+            let progress = &stats.progress;
+            println!(
+                "  {:>2}.   {:>9}  {:>9}  {:>3} {:>3}  {:>11}  {:>11}",
+                progress.day.day,
+                format_time(progress.time_to_first_star()),
+                format_time(progress.time_between_stars()),
+                guesses(progress, 1),
+                guesses(progress, 2),
+                format_runtime(stats.runtimes[0]),
+                format_runtime(stats.runtimes[1]),
+            );
+        }
+        print_summary(days);
+        println!();
+    }
+    Ok(())
+}
+
+fn print_summary(days: &[DayStats]) {
+    let stars = days
+        .iter()
+        .map(|d| (1..=2).filter(|&p| d.progress.star(p).is_some()).count())
+        .sum::<usize>();
+    let wrong = days
+        .iter()
+        .map(|d| (1..=2).map(|p| d.progress.guesses_needed(p)).sum::<usize>())
+        .sum::<usize>()
+        - stars;
+    let first = days
+        .iter()
+        .filter_map(|d| d.progress.time_to_first_star())
+        .collect::<Vec<_>>();
+    let between = days
+        .iter()
+        .filter_map(|d| d.progress.time_between_stars())
+        .collect::<Vec<_>>();
+    let runtime = days
+        .iter()
+        .flat_map(|d| d.runtimes)
+        .flatten()
+        .sum::<Duration>();
+    println!(
+        "  {} days, {stars} stars, {wrong} wrong answers, {} total runtime",
+        days.len(),
+        format_runtime(Some(runtime))
+    );
+    println!(
+        "  median time to the 1st star {}, between the stars {}",
+        format_time(median(first)),
+        format_time(median(between))
+    );
+}
+/// The number of guesses of a part, `-` if nothing was submitted.
+fn guesses(progress: &Progress, part: u8) -> String {
+    match progress.guesses_needed(part) {
+        0 => "-".to_owned(),
+        n if progress.star(part).is_none() => format!("{n}?"),
+        n => n.to_string(),
+    }
+}
+fn median(mut durations: Vec<Duration>) -> Option<Duration> {
+    durations.sort_unstable();
+    durations.get(durations.len() / 2).copied()
+}
+/// `hh:mm:ss`, or the number of days for everything longer.
+fn format_time(duration: Option<Duration>) -> String {
+    let Some(duration) = duration else {
+        return "-".to_owned();
+    };
+    let s = duration.as_secs();
+    if s >= 24 * 3_600 {
+        format!("{}d", s / (24 * 3_600))
+    } else {
+        format!("{:02}:{:02}:{:02}", s / 3_600, s / 60 % 60, s % 60)
+    }
+}
+pub fn format_runtime(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| "-".to_owned(), |d| format!("{d:.02?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(Some(Duration::from_secs(3_725))), "01:02:05");
+        assert_eq!(format_time(Some(Duration::from_secs(2 * 86_400 + 5))), "2d");
+        assert_eq!(format_time(None), "-");
+    }
+
+    #[test]
+    fn test_guesses() {
+        let day = Day { day: 1, year: 2023 };
+        let progress = Progress::parse(
+            day,
+            "started: 0\n1 wrong: 10 1\n1 right: 20 2\n2 wrong: 30 3\n",
+        )
+        .unwrap();
+        assert_eq!(guesses(&progress, 1), "2");
+        assert_eq!(guesses(&progress, 2), "1?");
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(
+            median(vec![
+                Duration::from_secs(3),
+                Duration::from_secs(1),
+                Duration::from_secs(2)
+            ]),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(median(vec![]), None);
+    }
+}
@@ -1,12 +1,16 @@
 use core::{fmt::Display, str::FromStr};
 use std::{
-    path::PathBuf,
+    fs,
+    path::{Path, PathBuf},
     process::{Command, Stdio},
 };
 
-use super::runner::PartDayResult;
+use super::{
+    error::AOCError,
+    runner::{Answer, PartDayResult},
+};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Day {
     pub day: u8,
     pub year: u16,
@@ -24,6 +28,45 @@ impl Day {
     pub fn examples_path(self) -> PathBuf {
         PathBuf::from(format!("data/examples/{}/{:02}.txt", self.year, self.day))
     }
+    pub fn answers_path(self) -> PathBuf {
+        PathBuf::from(format!("data/answers/{}/{:02}.txt", self.year, self.day))
+    }
+    pub fn timings_path(self) -> PathBuf {
+        PathBuf::from(format!("data/timings/{}/{:02}.txt", self.year, self.day))
+    }
+    pub fn seed_timings_path(self) -> PathBuf {
+        PathBuf::from(format!(
+            "data/seed_timings/{}/{:02}.txt",
+            self.year, self.day
+        ))
+    }
+    pub fn cache_path(self) -> PathBuf {
+        PathBuf::from(format!("data/cache/{}/{:02}.txt", self.year, self.day))
+    }
+    pub fn progress_path(self) -> PathBuf {
+        PathBuf::from(format!("data/progress/{}/{:02}.txt", self.year, self.day))
+    }
+    /// Where a generated input of this day is stored.
+    pub fn generated_path(self, size: usize, seed: u64) -> PathBuf {
+        PathBuf::from(format!(
+            "data/examples/{}/{:02}_size{size}_seed{seed}.txt",
+            self.year, self.day
+        ))
+    }
+    /// Where the private leaderboard of the year is cached.
+    pub fn leaderboard_path(self, id: &str) -> PathBuf {
+        PathBuf::from(format!("data/leaderboards/{}/{id}.json", self.year))
+    }
+    /// When the puzzle unlocks, at midnight EST, in seconds since the Unix epoch.
+    pub fn unlock_time(self) -> i64 {
+        // days_from_civil of https://howardhinnant.github.io/date_algorithms.html for December
+        let era = i64::from(self.year) / 400;
+        let year_of_era = i64::from(self.year) - era * 400;
+        let day_of_year = 275 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146_097 + day_of_era - 719_468;
+        days * 86_400 + 5 * 3_600
+    }
     pub fn bin_name(self) -> String {
         format!("{}_{:02}", self.year, self.day)
     }
@@ -38,21 +81,37 @@ impl Day {
     pub fn exists(self) -> bool {
         self.bin_path().exists()
     }
+    /// All days that have a binary in `src/bin`, sorted by year and day.
+    pub fn all() -> Vec<Self> {
+        Self::all_in(Path::new("src/bin"))
+    }
+    pub fn all_in(bin_dir: &Path) -> Vec<Self> {
+        let Ok(entries) = fs::read_dir(bin_dir) else {
+            return vec![];
+        };
+        let mut days = entries
+            .filter_map(Result::ok)
+            .filter_map(|e| Self::from_bin_name(e.path().file_stem()?.to_str()?))
+            .collect::<Vec<_>>();
+        days.sort_by_key(|d| (d.year, d.day));
+        days
+    }
+    /// Parses a binary name like `2016_11`.
+    pub fn from_bin_name(name: &str) -> Option<Self> {
+        let (year, day) = name.split_once('_')?;
+        let day = day.parse().ok()?;
+        let year = year.parse().ok()?;
+        ((1..=25).contains(&day) && year >= 2015).then_some(Self { day, year })
+    }
+    /// Runs the binary and collects the results of both parts.
     pub fn execute(
         self,
         release: bool,
         time: bool,
         submit: Option<u8>,
-    ) -> (PartDayResult<String>, PartDayResult<String>) {
-        let mut args = vec!["run".to_owned()];
-        if release {
-            args.push("--release".to_owned());
-        }
-
-        args.push("--bin".to_owned());
-        args.push(self.bin_name());
-        args.push("--quiet".to_owned());
-        args.push("--".to_owned());
+        part: Option<u8>,
+    ) -> Result<(PartDayResult<Answer>, PartDayResult<Answer>), AOCError> {
+        let mut args = vec![];
         if time {
             args.push("--time".to_owned());
         }
@@ -60,22 +119,74 @@ impl Day {
             args.push("--submit".to_owned());
             args.push(x.to_string());
         }
+        if let Some(x) = part {
+            args.push("--part".to_owned());
+            args.push(x.to_string());
+        }
         args.push("--machine-readable".to_owned());
 
-        let output = Command::new("cargo")
-            .args(args)
+        self.collect(
+            self.command(release, &args),
+            &format!("cargo run --bin {}", self.bin_name()),
+        )
+    }
+    /// Times both parts with the binary that was already built into `bin_dir`.
+    /// It runs in the current directory, so that it finds the inputs.
+    pub fn execute_built(
+        self,
+        bin_dir: &Path,
+    ) -> Result<(PartDayResult<Answer>, PartDayResult<Answer>), AOCError> {
+        let binary = bin_dir.join(self.bin_name());
+        let mut command = Command::new(&binary);
+        command.args(["--time", "--machine-readable"]);
+        self.collect(command, &binary.display().to_string())
+    }
+    fn collect(
+        self,
+        mut command: Command,
+        name: &str,
+    ) -> Result<(PartDayResult<Answer>, PartDayResult<Answer>), AOCError> {
+        let output = command
             .stdout(Stdio::piped())
             .stderr(Stdio::inherit())
             .output()
-            .unwrap();
-
-        let output = String::from_utf8_lossy(&output.stdout);
-        (
-            PartDayResult::deserialize(output.split("%%%%\n").nth(0).unwrap()).unwrap(),
-            PartDayResult::deserialize(output.split("%%%%\n").nth(1).unwrap()).unwrap(),
-        )
+            .map_err(AOCError::command(name))?;
+        if !output.status.success() {
+            return Err(AOCError::BadExitStatus {
+                command: name.to_owned(),
+                status: output.status,
+            });
+        }
+        parse_machine_readable(self, &String::from_utf8_lossy(&output.stdout))
+    }
+    /// A `cargo run` of the binary of this day with the given arguments.
+    pub fn command(self, release: bool, args: &[String]) -> Command {
+        let mut command = Command::new("cargo");
+        command.arg("run");
+        if release {
+            command.arg("--release");
+        }
+        command
+            .args(["--bin", &self.bin_name(), "--quiet", "--"])
+            .args(args);
+        command
     }
 }
+/// Parses the output of a binary that was called with `--machine-readable`.
+pub fn parse_machine_readable(
+    day: Day,
+    output: &str,
+) -> Result<(PartDayResult<Answer>, PartDayResult<Answer>), AOCError> {
+    let mut parts = output.split("%%%%\n");
+    let mut next = || {
+        parts
+            .next()
+            .ok_or_else(|| "Missing part in output".to_owned())
+            .and_then(PartDayResult::deserialize)
+            .map_err(|message| AOCError::InvalidOutput { day, message })
+    };
+    Ok((next()?, next()?))
+}
 impl Display for Day {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:02}.12.{}", self.day, self.year)
@@ -86,26 +197,53 @@ impl FromStr for Day {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut it = s.split('.');
-        let day = it
-            .next()
-            .expect("There has to be a Day")
+        let mut next = |name: &str| it.next().ok_or_else(|| format!("Missing {name} in {s}"));
+        let day = next("day")?
             .parse()
-            .expect("Is not an Integer");
+            .map_err(|_| format!("Day is not an integer: {s}"))?;
         if !(1..=25).contains(&day) {
             return Err(format!("Day is out iof range: {day}"));
         }
-        let month = it.next().expect("There has to be a month");
+        let month = next("month")?;
         if month != "12" {
             return Err(format!("Month is not 12: {month}"));
         }
-        let year = it
-            .next()
-            .expect("There has to be a Year")
+        let year = next("year")?
             .parse()
-            .expect("Is not an integer");
+            .map_err(|_| format!("Year is not an integer: {s}"))?;
         if year < 2015 {
             return Err(format!("Year has to be greater or equal to 2015: {year}"));
         }
         Ok(Self { day, year })
     }
 }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Day::from_str("07.12.2016"), Ok(Day { day: 7, year: 2016 }));
+        Day::from_str("x.12.2016").unwrap_err();
+        Day::from_str("07.12").unwrap_err();
+        Day::from_str("07.11.2016").unwrap_err();
+        let day = Day { day: 7, year: 2016 };
+        let e = parse_machine_readable(day, "07.12.2016;;1;;5;;10").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Output of Day 07.12.2016 can't be parsed: Missing part in output"
+        );
+    }
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(Day { day: 1, year: 2023 }.unlock_time(), 1_701_406_800);
+        assert_eq!(
+            Day {
+                day: 25,
+                year: 2015
+            }
+            .unlock_time(),
+            1_451_019_600
+        );
+    }
+}
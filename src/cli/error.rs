@@ -0,0 +1,200 @@
+use core::fmt::Display;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process::{self, ExitStatus},
+};
+
+use super::day::Day;
+
+/// Everything that can go wrong in the CLI.
+///
+/// Every kind of failure has its own exit code, see [`AOCError::exit_code`].
+#[derive(Debug)]
+pub enum AOCError {
+    /// The command line is malformed.
+    InvalidArgument(String),
+    /// The requested combination of options is not supported.
+    Unsupported(String),
+    /// There is no binary for the day in `src/bin`.
+    MissingDay(Day),
+    /// A file the day needs does not exist.
+    MissingFile {
+        day: Day,
+        path: PathBuf,
+    },
+    /// There is no session cookie to talk to the Advent of Code website.
+    MissingSession,
+    /// An external program like `cargo` or `aoc` can't be started.
+    CommandNotCallable {
+        command: String,
+        source: io::Error,
+    },
+    /// An external program exited unsuccessfully.
+    BadExitStatus {
+        command: String,
+        status: ExitStatus,
+    },
+    FailedCreateDir {
+        path: PathBuf,
+        source: io::Error,
+    },
+    FailedRead {
+        path: PathBuf,
+        source: io::Error,
+    },
+    FailedWrite {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// A data file like the answers or timings of a day has an invalid line.
+    InvalidFile {
+        path: PathBuf,
+        message: String,
+    },
+    /// The binary of a day printed something that is not a result.
+    InvalidOutput {
+        day: Day,
+        message: String,
+    },
+    /// The Advent of Code website answered with something unexpected.
+    InvalidResponse {
+        url: String,
+        message: String,
+    },
+}
+impl AOCError {
+    /// `2` usage, `3` missing day, file or session, `4` file system, `5` external program,
+    /// `6` invalid data. `1` is left to panics.
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::InvalidArgument(_) | Self::Unsupported(_) => 2,
+            Self::MissingDay(_) | Self::MissingFile { .. } | Self::MissingSession => 3,
+            Self::FailedCreateDir { .. } | Self::FailedRead { .. } | Self::FailedWrite { .. } => 4,
+            Self::CommandNotCallable { .. } | Self::BadExitStatus { .. } => 5,
+            Self::InvalidFile { .. }
+            | Self::InvalidOutput { .. }
+            | Self::InvalidResponse { .. } => 6,
+        }
+    }
+    /// Prints the error and ends the process with its exit code.
+    pub fn exit(&self) -> ! {
+        eprintln!("{self}");
+        process::exit(self.exit_code().into());
+    }
+    /// Attaches the path to an error of reading it.
+    pub fn read(path: &Path) -> impl FnOnce(io::Error) -> Self {
+        let path = path.to_owned();
+        |source| Self::FailedRead { path, source }
+    }
+    /// Attaches the path to an error of writing it.
+    pub fn write(path: &Path) -> impl FnOnce(io::Error) -> Self {
+        let path = path.to_owned();
+        |source| Self::FailedWrite { path, source }
+    }
+    /// Attaches the path to an error of creating its parent directory.
+    pub fn create_dir(path: &Path) -> impl FnOnce(io::Error) -> Self {
+        let path = path.to_owned();
+        |source| Self::FailedCreateDir { path, source }
+    }
+    /// Attaches the name of the program to an error of starting it.
+    pub fn command(command: &str) -> impl FnOnce(io::Error) -> Self {
+        let command = command.to_owned();
+        |source| Self::CommandNotCallable { command, source }
+    }
+}
+impl Display for AOCError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidArgument(e) | Self::Unsupported(e) => write!(f, "{e}"),
+            Self::MissingDay(day) => write!(f, "Binary for Day {day} not found"),
+            Self::MissingFile { day, path } => {
+                write!(f, "{} of Day {day} doesn't exist", path.display())
+            }
+            Self::MissingSession => write!(
+                f,
+                "No session cookie found. Set ADVENT_OF_CODE_SESSION or save it to ~/.adventofcode.session like for aoc-cli."
+            ),
+            Self::CommandNotCallable { command, source } if command == "aoc" => write!(
+                f,
+                "Command \"aoc\" can't be run: {source}. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            Self::CommandNotCallable { command, source } => {
+                write!(f, "Command \"{command}\" can't be run: {source}")
+            }
+            Self::BadExitStatus { command, status } => {
+                write!(f, "Command \"{command}\" failed with {status}")
+            }
+            Self::FailedCreateDir { path, source } => {
+                write!(
+                    f,
+                    "Directory for {} can't be created: {source}",
+                    path.display()
+                )
+            }
+            Self::FailedRead { path, source } => {
+                write!(f, "{} can't be read: {source}", path.display())
+            }
+            Self::FailedWrite { path, source } => {
+                write!(f, "{} can't be written: {source}", path.display())
+            }
+            Self::InvalidFile { path, message } => {
+                write!(f, "{} is invalid: {message}", path.display())
+            }
+            Self::InvalidOutput { day, message } => {
+                write!(f, "Output of Day {day} can't be parsed: {message}")
+            }
+            Self::InvalidResponse { url, message } => {
+                write!(f, "Response of {url} is invalid: {message}")
+            }
+        }
+    }
+}
+impl core::error::Error for AOCError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::CommandNotCallable { source, .. }
+            | Self::FailedCreateDir { source, .. }
+            | Self::FailedRead { source, .. }
+            | Self::FailedWrite { source, .. } => Some(source),
+            Self::InvalidArgument(_)
+            | Self::Unsupported(_)
+            | Self::MissingDay(_)
+            | Self::MissingFile { .. }
+            | Self::MissingSession
+            | Self::BadExitStatus { .. }
+            | Self::InvalidFile { .. }
+            | Self::InvalidOutput { .. }
+            | Self::InvalidResponse { .. } => None,
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages() {
+        let day = Day { day: 3, year: 2017 };
+        let e = AOCError::read(Path::new("data/inputs/2017/03.txt"))(io::Error::from(
+            io::ErrorKind::NotFound,
+        ));
+        assert_eq!(
+            e.to_string(),
+            "data/inputs/2017/03.txt can't be read: entity not found"
+        );
+        assert_eq!(e.exit_code(), 4);
+        assert_eq!(
+            AOCError::MissingDay(day).to_string(),
+            "Binary for Day 03.12.2017 not found"
+        );
+        assert_eq!(
+            AOCError::InvalidOutput {
+                day,
+                message: "Missing part".to_owned()
+            }
+            .exit_code(),
+            6
+        );
+    }
+}
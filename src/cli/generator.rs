@@ -0,0 +1,132 @@
+use core::{fmt::Display, str::FromStr, time::Duration};
+
+use super::{
+    day::Day,
+    error::AOCError,
+    runner::{ANSI_BOLD, ANSI_RESET, run_timed},
+};
+use crate::helper::rand::Rng;
+
+/// Produces synthetic puzzle inputs with the same shape as the real ones.
+///
+/// A day declares its generator with `all_aoc::solution!(1, 2024, generator = Generator)`.
+/// The same seed and size must always produce the same input.
+#[expect(clippy::module_name_repetitions, reason = "makes more sense")]
+pub trait InputGenerator {
+    /// `size` is the number of "elements" of the input (lines, cells, ...).
+    /// The real input roughly corresponds to [`InputGenerator::REAL_SIZE`].
+    fn generate(rng: &mut Rng, size: usize) -> String;
+    const REAL_SIZE: usize;
+}
+/// What a binary is asked to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Solve,
+    /// Print a generated input.
+    Generate {
+        size: usize,
+        seed: u64,
+    },
+    /// Run both parts on generated inputs of growing size.
+    Scaling {
+        size: usize,
+        seed: u64,
+    },
+}
+impl Mode {
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let size = flag_value(&args, "--size");
+        let seed = flag_value(&args, "--seed").unwrap_or(0);
+        if args.iter().any(|x| x == "--generate") {
+            Self::Generate {
+                size: size.unwrap_or(0),
+                seed,
+            }
+        } else if args.iter().any(|x| x == "--scaling") {
+            Self::Scaling {
+                size: size.unwrap_or(0),
+                seed,
+            }
+        } else {
+            Self::Solve
+        }
+    }
+}
+fn flag_value<T>(args: &[String], flag: &str) -> Option<T>
+where
+    T: FromStr,
+{
+    let i = args.iter().position(|x| x == flag)? + 1;
+    args.get(i)?.parse().ok()
+}
+/// Generates an input. A size of zero means the size of the real input.
+pub fn generate<G>(size: usize, seed: u64) -> String
+where
+    G: InputGenerator,
+{
+    let size = if size == 0 { G::REAL_SIZE } else { size };
+    G::generate(&mut Rng::new(seed), size)
+}
+/// Called by binaries that don't declare a generator.
+pub fn unsupported(day: Day) -> ! {
+    AOCError::Unsupported(format!("Day {day} has no input generator")).exit()
+}
+/// Times one part on generated inputs with doubling sizes up to `max_size`
+/// and estimates the exponent `k` of the growth `O(n^k)` between the sizes.
+#[expect(clippy::use_debug, reason = "Duration has no Display")]
+pub fn run_scaling<G, T, F>(func: F, day: Day, part: u8, max_size: usize, seed: u64)
+where
+    G: InputGenerator,
+    T: Display,
+    F: Fn(&str) -> Option<T>,
+{
+    let max_size = if max_size == 0 {
+        G::REAL_SIZE
+    } else {
+        max_size
+    };
+    println!("{ANSI_BOLD}Day {day} Part {part}{ANSI_RESET}");
+    let mut previous: Option<(usize, Duration)> = None;
+    for size in sizes(max_size) {
+        let input = generate::<G>(size, seed);
+        let (result, durations) = run_timed(&func, input.as_str(), true);
+        let avg = durations.iter().sum::<Duration>() / u32::try_from(durations.len()).unwrap();
+        let result = result.map_or_else(|| "\u{2716}".to_owned(), |r| r.to_string());
+        let growth = previous
+            .map(|(s, d)| format!("  n^{:.2}", exponent(s, d, size, avg)))
+            .unwrap_or_default();
+        println!("{size:>10}: {avg:>12.02?}{growth}  ({result})");
+        previous = Some((size, avg));
+    }
+}
+/// `1, 2, 4, ...` up to and including `max_size`.
+fn sizes(max_size: usize) -> Vec<usize> {
+    let mut sizes = core::iter::successors(Some(1_usize), |s| s.checked_mul(2))
+        .take_while(|s| *s < max_size)
+        .collect::<Vec<_>>();
+    sizes.push(max_size);
+    sizes
+}
+#[expect(clippy::cast_precision_loss, reason = "only an estimate")]
+fn exponent(size_a: usize, duration_a: Duration, size_b: usize, duration_b: Duration) -> f64 {
+    let ratio = duration_b.as_secs_f64() / duration_a.as_secs_f64().max(f64::MIN_POSITIVE);
+    ratio.log(size_b as f64 / size_a as f64)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(sizes(1), vec![1]);
+        assert_eq!(sizes(8), vec![1, 2, 4, 8]);
+        assert_eq!(sizes(10), vec![1, 2, 4, 8, 10]);
+    }
+
+    #[test]
+    fn test_exponent() {
+        let e = exponent(10, Duration::from_millis(1), 20, Duration::from_millis(4));
+        assert!((e - 2.0).abs() < 1e-9);
+    }
+}
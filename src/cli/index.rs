@@ -0,0 +1,347 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::{day::Day, error::AOCError};
+
+/// The tags a day declares in `solution!` and the `all_aoc::helper` modules its binary uses.
+///
+/// Both are read from the source, so no binary has to be compiled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayInfo {
+    pub day: Day,
+    pub tags: Vec<String>,
+    pub helpers: Vec<String>,
+}
+impl DayInfo {
+    pub fn load(day: Day) -> Result<Self, AOCError> {
+        let path = day.bin_path();
+        let source = fs::read_to_string(&path).map_err(AOCError::read(&path))?;
+        Ok(Self::parse(day, &source))
+    }
+    /// Every day with a binary in `src/bin`.
+    pub fn all() -> Result<Vec<Self>, AOCError> {
+        Day::all().into_iter().map(Self::load).collect()
+    }
+    pub fn parse(day: Day, source: &str) -> Self {
+        let code = strip_comments(source);
+        Self {
+            day,
+            tags: tags(&code),
+            helpers: helpers(&code),
+        }
+    }
+}
+
+/// The days with a binary that declare the tag, in the order of `days`.
+pub fn tagged(days: &[Day], tag: &str) -> Result<Vec<Day>, AOCError> {
+    let mut tagged = Vec::new();
+    for &day in days.iter().filter(|d| d.exists()) {
+        if DayInfo::load(day)?.tags.iter().any(|t| t == tag) {
+            tagged.push(day);
+        }
+    }
+    Ok(tagged)
+}
+
+/// The days for every key, like the tags or helper modules of the days.
+pub fn group_by<F>(infos: &[DayInfo], keys: F) -> BTreeMap<&str, Vec<Day>>
+where
+    F: Fn(&DayInfo) -> &[String],
+{
+    let mut groups = BTreeMap::<_, Vec<_>>::new();
+    for info in infos {
+        for key in keys(info) {
+            groups.entry(key.as_str()).or_default().push(info.day);
+        }
+    }
+    groups
+}
+
+/// The source files of the helper modules and of every helper module they use in turn,
+/// sorted and without duplicates.
+pub fn helper_sources(helpers: &[String]) -> Result<Vec<PathBuf>, AOCError> {
+    let mut modules = helpers.iter().cloned().collect::<BTreeSet<_>>();
+    let mut todo = modules.iter().cloned().collect::<Vec<_>>();
+    let mut files = BTreeSet::new();
+    while let Some(module) = todo.pop() {
+        for path in module_files(&module) {
+            let source = fs::read_to_string(&path).map_err(AOCError::read(&path))?;
+            let code = strip_comments(&source);
+            let mut paths = Vec::new();
+            for (i, _) in code.match_indices("crate::") {
+                expand(use_tree(&code[i + "crate::".len()..]), "", &mut paths);
+            }
+            // in a file directly in `src/helper`, `super` is the helper module itself
+            if path.parent() == Some(Path::new("src/helper")) {
+                for (i, _) in code.match_indices("super::") {
+                    expand(
+                        use_tree(&code[i + "super::".len()..]),
+                        "helper::",
+                        &mut paths,
+                    );
+                }
+            }
+            let used = paths
+                .iter()
+                .filter_map(|path| path.strip_prefix("helper::")?.split("::").next())
+                .filter(|m| !m.is_empty() && *m != "*");
+            for used in used {
+                if modules.insert(used.to_owned()) {
+                    todo.push(used.to_owned());
+                }
+            }
+            files.insert(path);
+        }
+    }
+    Ok(files.into_iter().collect())
+}
+/// `src/helper/<module>.rs`, or every file of the directory of the module.
+fn module_files(module: &str) -> Vec<PathBuf> {
+    let file = PathBuf::from(format!("src/helper/{module}.rs"));
+    if file.exists() {
+        return vec![file];
+    }
+    let Ok(entries) = fs::read_dir(format!("src/helper/{module}")) else {
+        return vec![];
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "rs"))
+        .collect()
+}
+
+/// The source without comments. String and char literals are kept as they are,
+/// so a `//` inside them doesn't start a comment.
+fn strip_comments(source: &str) -> String {
+    let chars = source.chars().collect::<Vec<_>>();
+    let at = |i: usize| chars.get(i).copied();
+    let is_ident = |i: Option<usize>| {
+        i.and_then(at)
+            .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    };
+    let mut code = String::with_capacity(source.len());
+    let mut i = 0;
+    while let Some(c) = at(i) {
+        let start = i;
+        match (c, at(i + 1)) {
+            ('/', Some('/')) => {
+                while at(i).is_some_and(|c| c != '\n') {
+                    i += 1;
+                }
+                continue;
+            }
+            ('/', Some('*')) => {
+                let mut depth = 0_usize;
+                while let Some(c) = at(i) {
+                    match (c, at(i + 1)) {
+                        ('/', Some('*')) => {
+                            depth += 1;
+                            i += 2;
+                        }
+                        ('*', Some('/')) => {
+                            depth -= 1;
+                            i += 2;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => i += 1,
+                    }
+                }
+                code.push(' ');
+                continue;
+            }
+            ('"', _) => {
+                i += 1;
+                while let Some(c) = at(i) {
+                    i += if c == '\\' { 2 } else { 1 };
+                    if c == '"' {
+                        break;
+                    }
+                }
+            }
+            // raw strings like `r#"..."#` or `br"..."`, but not identifiers ending in `r`
+            ('r', Some('"' | '#'))
+                if !is_ident(i.checked_sub(1))
+                    || (at(i - 1) == Some('b') && !is_ident(i.checked_sub(2))) =>
+            {
+                let hashes = chars[i + 1..].iter().take_while(|c| **c == '#').count();
+                if at(i + 1 + hashes) == Some('"') {
+                    i += hashes + 2;
+                    while let Some(c) = at(i) {
+                        i += 1;
+                        if c == '"'
+                            && chars[i..]
+                                .iter()
+                                .take(hashes)
+                                .filter(|c| **c == '#')
+                                .count()
+                                == hashes
+                        {
+                            i += hashes;
+                            break;
+                        }
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+            // char literals, a lone `'` starts a lifetime
+            ('\'', Some('\\')) => {
+                i += 2;
+                while at(i).is_some_and(|c| c != '\'') {
+                    i += 1;
+                }
+                i += 1;
+            }
+            ('\'', Some(_)) if at(i + 2) == Some('\'') => i += 3,
+            _ => i += 1,
+        }
+        code.extend(&chars[start..i.min(chars.len())]);
+    }
+    code
+}
+
+/// The string literals in `tags = [..]` of the `solution!` invocation.
+fn tags(code: &str) -> Vec<String> {
+    let Some((_, args)) = code.split_once("solution!(") else {
+        return vec![];
+    };
+    let args = args.split_once(')').map_or(args, |(args, _)| args);
+    let Some((_, tags)) = args.split_once("tags") else {
+        return vec![];
+    };
+    let Some((_, tags)) = tags.split_once('[') else {
+        return vec![];
+    };
+    let tags = tags.split_once(']').map_or(tags, |(tags, _)| tags);
+    tags.split('"')
+        .skip(1)
+        .step_by(2)
+        .map(str::to_owned)
+        .collect()
+}
+
+/// The first module after `all_aoc::helper` of every path, in `use` trees and inline.
+fn helpers(code: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    for (i, _) in code.match_indices("all_aoc::") {
+        expand(use_tree(&code[i + "all_aoc::".len()..]), "", &mut paths);
+    }
+    paths
+        .iter()
+        .filter_map(|path| path.strip_prefix("helper::")?.split("::").next())
+        .filter(|module| !module.is_empty())
+        .map(str::to_owned)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+/// The path or braced tree at the start of `rest`.
+fn use_tree(rest: &str) -> &str {
+    let mut depth = 0_usize;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    return &rest[..=i];
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' || c == ':' => {}
+            _ if depth > 0 => {}
+            _ => return &rest[..i],
+        }
+    }
+    rest
+}
+/// Flattens a tree like `helper::{grid::{Grid as _, dense::DenseGrid}, md5}` into its paths.
+fn expand(tree: &str, prefix: &str, paths: &mut Vec<String>) {
+    let tree = tree.trim();
+    let Some((head, body)) = tree.split_once('{') else {
+        let path = tree.split(" as ").next().unwrap_or(tree).trim();
+        paths.push(format!("{prefix}{path}"));
+        return;
+    };
+    let body = body.strip_suffix('}').unwrap_or(body);
+    let prefix = format!("{prefix}{head}");
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (i, c) in body.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                expand(&body[start..i], &prefix, paths);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    expand(&body[start..], &prefix, paths);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let source = r#"use all_aoc::{
+    cli::generator::InputGenerator,
+    helper::{
+        grid::{Grid as _, dense::DenseGrid},
+        position::Direction4,
+    },
+};
+// use all_aoc::helper::intcode::Intcode;
+all_aoc::solution!(14, 2024, generator = Generator, tags = ["grid", "simulation"]);
+
+fn part_one(input: &str) -> Option<u32> {
+    let _ = all_aoc::helper::md5::md5(input);
+    let _ = all_aoc::cli::read_examples_file(DAY);
+    None
+}
+"#;
+        let day = Day {
+            day: 14,
+            year: 2024,
+        };
+        let info = DayInfo::parse(day, source);
+        assert_eq!(info.tags, ["grid", "simulation"]);
+        assert_eq!(info.helpers, ["grid", "md5", "position"]);
+        let untagged = DayInfo::parse(day, "all_aoc::solution!(14, 2024);");
+        assert!(untagged.tags.is_empty());
+        assert!(untagged.helpers.is_empty());
+        let infos = [info, untagged];
+        let groups = group_by(&infos, |i| &i.helpers);
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<_>>(),
+            ["grid", "md5", "position"]
+        );
+        assert_eq!(groups["grid"], [day]);
+    }
+
+    #[test]
+    fn test_strip_comments() {
+        let source = r##"let url = "https://adventofcode.com"; // a comment
+let quote = '"'; let slash = '/'; /* block /* nested */ still */ fn f<'a>(s: &'a str) {}
+let raw = r#"a "// b"#; // "tags"
+"##;
+        assert_eq!(
+            strip_comments(source),
+            r##"let url = "https://adventofcode.com"; 
+let quote = '"'; let slash = '/';   fn f<'a>(s: &'a str) {}
+let raw = r#"a "// b"#; 
+"##
+        );
+        let day = Day { day: 1, year: 2015 };
+        let info = DayInfo::parse(day, r#"all_aoc::solution!(1, 2015, tags = ["a//b", "c"]);"#);
+        assert_eq!(info.tags, ["a//b", "c"]);
+    }
+}
@@ -0,0 +1,334 @@
+use core::str::FromStr;
+use std::collections::BTreeMap;
+
+/// Arrays and objects may be nested this deep, so that parsing can't overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// A parsed JSON value.
+///
+/// Numbers keep their text, so that integers of any size can be read without rounding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Self>),
+    Object(BTreeMap<String, Self>),
+}
+impl Json {
+    /// The value of the key, if this is an object that has it.
+    pub fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(map) => map.get(key),
+            Self::Null | Self::Bool(_) | Self::Number(_) | Self::String(_) | Self::Array(_) => None,
+        }
+    }
+    pub const fn as_bool(&self) -> Option<bool> {
+        if let Self::Bool(b) = self {
+            Some(*b)
+        } else {
+            None
+        }
+    }
+    pub fn as_i64(&self) -> Option<i64> {
+        if let Self::Number(n) = self {
+            n.parse().ok()
+        } else {
+            None
+        }
+    }
+    pub fn as_f64(&self) -> Option<f64> {
+        if let Self::Number(n) = self {
+            n.parse().ok()
+        } else {
+            None
+        }
+    }
+    pub fn as_str(&self) -> Option<&str> {
+        if let Self::String(s) = self {
+            Some(s)
+        } else {
+            None
+        }
+    }
+    pub fn as_array(&self) -> Option<&[Self]> {
+        if let Self::Array(a) = self {
+            Some(a)
+        } else {
+            None
+        }
+    }
+    pub const fn as_object(&self) -> Option<&BTreeMap<String, Self>> {
+        if let Self::Object(o) = self {
+            Some(o)
+        } else {
+            None
+        }
+    }
+}
+impl FromStr for Json {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: s.chars().collect(),
+            pos: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos < parser.chars.len() {
+            return Err(parser.error("Trailing characters"));
+        }
+        Ok(value)
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    /// The number of arrays and objects around the current value.
+    depth: usize,
+}
+impl Parser {
+    fn error(&self, message: &str) -> String {
+        format!("{message} at {}", self.pos)
+    }
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+    fn skip_whitespace(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|c| matches!(c, ' ' | '\t' | '\n' | '\r'))
+        {
+            self.pos += 1;
+        }
+    }
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{expected}'")))
+        }
+    }
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, String> {
+        for expected in word.chars() {
+            if self.peek() != Some(expected) {
+                return Err(self.error(&format!("Expected {word}")));
+            }
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('n') => self.literal("null", Json::Null),
+            Some('t') => self.literal("true", Json::Bool(true)),
+            Some('f') => self.literal("false", Json::Bool(false)),
+            Some('"') => self.string().map(Json::String),
+            Some('[') => self.nested(Self::array),
+            Some('{') => self.nested(Self::object),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(_) => Err(self.error("Unexpected character")),
+            None => Err(self.error("Unexpected end")),
+        }
+    }
+    fn nested<F>(&mut self, parse: F) -> Result<Json, String>
+    where
+        F: Fn(&mut Self) -> Result<Json, String>,
+    {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("Nested too deeply"));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+    fn number(&mut self) -> Result<Json, String> {
+        let start = self.pos;
+        let digits = |p: &mut Self| {
+            let start = p.pos;
+            while p.peek().is_some_and(|c| c.is_ascii_digit()) {
+                p.pos += 1;
+            }
+            p.pos > start
+        };
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        let mut valid = digits(self);
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            valid &= digits(self);
+        }
+        if self.peek().is_some_and(|c| c == 'e' || c == 'E') {
+            self.pos += 1;
+            if self.peek().is_some_and(|c| c == '+' || c == '-') {
+                self.pos += 1;
+            }
+            valid &= digits(self);
+        }
+        if !valid {
+            return Err(self.error("Invalid number"));
+        }
+        Ok(Json::Number(self.chars[start..self.pos].iter().collect()))
+    }
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            let c = self.peek().ok_or_else(|| self.error("Unclosed string"))?;
+            self.pos += 1;
+            match c {
+                '"' => return Ok(s),
+                '\\' => {
+                    let escaped = self.peek().ok_or_else(|| self.error("Unclosed string"))?;
+                    self.pos += 1;
+                    s.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'u' => self.unicode()?,
+                        c @ ('"' | '\\' | '/') => c,
+                        _ => return Err(self.error("Invalid escape")),
+                    });
+                }
+                c => s.push(c),
+            }
+        }
+    }
+    /// The character of `\uXXXX`, combining surrogate pairs.
+    fn unicode(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+        let code = if (0xd800..0xdc00).contains(&high) {
+            if self.peek() != Some('\\') || self.chars.get(self.pos + 1) != Some(&'u') {
+                return Err(self.error("Unpaired surrogate"));
+            }
+            self.pos += 2;
+            let low = self.hex4()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(self.error("Unpaired surrogate"));
+            }
+            0x1_0000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error("Invalid unicode escape"))
+    }
+    fn hex4(&mut self) -> Result<u32, String> {
+        let hex = self
+            .chars
+            .get(self.pos..self.pos + 4)
+            .ok_or_else(|| self.error("Invalid unicode escape"))?
+            .iter()
+            .collect::<String>();
+        let code =
+            u32::from_str_radix(&hex, 16).map_err(|_| self.error("Invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(code)
+    }
+    fn array(&mut self) -> Result<Json, String> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            if self.peek() == Some(']') {
+                self.pos += 1;
+                return Ok(Json::Array(values));
+            }
+            self.expect(',')?;
+        }
+    }
+    fn object(&mut self) -> Result<Json, String> {
+        self.expect('{')?;
+        let mut map = BTreeMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Json::Object(map));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(':')?;
+            map.insert(key, self.value()?);
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                self.pos += 1;
+                return Ok(Json::Object(map));
+            }
+            self.expect(',')?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let json: Json =
+            r#" {"a": [1, -2.5e3, true, null], "b": {"c": "x\"\u00e4\ud83c\udf84"}, "d": []} "#
+                .parse()
+                .unwrap();
+        let a = json.get("a").and_then(Json::as_array).unwrap();
+        assert_eq!(a[0].as_i64(), Some(1));
+        assert_eq!(a[1].as_f64(), Some(-2500.0));
+        assert_eq!(a[2].as_bool(), Some(true));
+        assert_eq!(a[3], Json::Null);
+        assert_eq!(
+            json.get("b")
+                .and_then(|b| b.get("c"))
+                .and_then(Json::as_str),
+            Some("x\"\u{e4}\u{1f384}")
+        );
+        assert_eq!(json.get("d"), Some(&Json::Array(vec![])));
+        assert_eq!(
+            "12345678901234567890".parse::<Json>().unwrap(),
+            Json::Number("12345678901234567890".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for input in [
+            "",
+            "[1,]",
+            "{\"a\" 1}",
+            "01x",
+            "\"abc",
+            "-",
+            "1.",
+            "[1] 2",
+            "nul",
+        ] {
+            assert!(input.parse::<Json>().is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let nested = |depth| "[".repeat(depth) + &"]".repeat(depth);
+        nested(MAX_DEPTH).parse::<Json>().unwrap();
+        assert_eq!(
+            nested(MAX_DEPTH + 1).parse::<Json>(),
+            Err(format!("Nested too deeply at {MAX_DEPTH}"))
+        );
+        "{\"a\":".repeat(100_000).parse::<Json>().unwrap_err();
+    }
+}
@@ -0,0 +1,194 @@
+use core::str::FromStr;
+use std::collections::BTreeMap;
+
+use super::day::Day;
+use super::json::Json;
+
+/// A private leaderboard as served by `https://adventofcode.com/YYYY/leaderboard/private/view/ID.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    pub members: Vec<Member>,
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: String,
+    pub name: String,
+    /// The first and second star of every day the member got a star on.
+    pub stars: BTreeMap<u8, [Option<Star>; 2]>,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Star {
+    /// Seconds since the Unix epoch.
+    pub time: i64,
+    /// Breaks ties between equal times, lower was first.
+    pub index: i64,
+}
+/// How long one member took for the two stars of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayTimes<'a> {
+    pub member: &'a Member,
+    /// Seconds from the unlock of the day.
+    pub first: Option<i64>,
+    pub second: Option<i64>,
+}
+impl DayTimes<'_> {
+    /// Seconds between the first and the second star.
+    pub fn delta(&self) -> Option<i64> {
+        Some(self.second? - self.first?)
+    }
+}
+
+impl Leaderboard {
+    /// The local score of every member, in the order of [`Leaderboard::members`].
+    ///
+    /// For every star, the first of `n` members to get it scores `n` points, the second `n - 1`
+    /// and so on.
+    pub fn local_scores(&self) -> Vec<usize> {
+        let mut scores = vec![0; self.members.len()];
+        for day in 1..=25 {
+            for part in 0..2 {
+                let mut got = self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, m)| Some((m.star(day, part)?, i)))
+                    .collect::<Vec<_>>();
+                got.sort_by_key(|(star, _)| (star.time, star.index));
+                for (rank, (_, i)) in got.into_iter().enumerate() {
+                    scores[i] += self.members.len() - rank;
+                }
+            }
+        }
+        scores
+    }
+    /// The times of every member with a star on the day, fastest second star first.
+    pub fn day_times(&self, day: u8) -> Vec<DayTimes<'_>> {
+        let unlock = Day {
+            day,
+            year: self.year,
+        }
+        .unlock_time();
+        let mut times = self
+            .members
+            .iter()
+            .map(|member| DayTimes {
+                member,
+                first: member.star(day, 0).map(|s| s.time - unlock),
+                second: member.star(day, 1).map(|s| s.time - unlock),
+            })
+            .filter(|t| t.first.is_some())
+            .collect::<Vec<_>>();
+        times.sort_by_key(|t| (t.second.is_none(), t.second, t.first));
+        times
+    }
+}
+impl Member {
+    pub fn star(&self, day: u8, part: usize) -> Option<Star> {
+        self.stars.get(&day)?[part]
+    }
+    pub fn star_count(&self) -> usize {
+        self.stars.values().flatten().flatten().count()
+    }
+    /// The time of the latest star, used to break ties in the standings.
+    pub fn last_star(&self) -> Option<i64> {
+        self.stars
+            .values()
+            .flatten()
+            .flatten()
+            .map(|s| s.time)
+            .max()
+    }
+    fn from_json(id: &str, json: &Json) -> Result<Self, String> {
+        let name = json
+            .get("name")
+            .and_then(Json::as_str)
+            .map_or_else(|| format!("(anonymous user #{id})"), str::to_owned);
+        let mut stars = BTreeMap::new();
+        let days = json
+            .get("completion_day_level")
+            .and_then(Json::as_object)
+            .ok_or_else(|| format!("Member {id} has no completion_day_level"))?;
+        for (day, parts) in days {
+            let day = day
+                .parse()
+                .map_err(|_| format!("Member {id} has an invalid day {day}"))?;
+            let mut day_stars = [None; 2];
+            for (part, star) in day_stars.iter_mut().enumerate() {
+                let Some(json) = parts.get(&(part + 1).to_string()) else {
+                    continue;
+                };
+                let time = json.get("get_star_ts").and_then(Json::as_i64);
+                let index = json.get("star_index").and_then(Json::as_i64);
+                let (Some(time), index) = (time, index) else {
+                    return Err(format!("Member {id} has an invalid star on day {day}"));
+                };
+                *star = Some(Star {
+                    time,
+                    index: index.unwrap_or_default(),
+                });
+            }
+            stars.insert(day, day_stars);
+        }
+        Ok(Self {
+            id: id.to_owned(),
+            name,
+            stars,
+        })
+    }
+}
+impl FromStr for Leaderboard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = s.parse::<Json>()?;
+        let year = json
+            .get("event")
+            .and_then(Json::as_str)
+            .and_then(|e| e.parse().ok())
+            .ok_or("Missing event")?;
+        let members = json
+            .get("members")
+            .and_then(Json::as_object)
+            .ok_or("Missing members")?
+            .iter()
+            .map(|(id, member)| Member::from_json(id, member))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { year, members })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Day 1 of 2023 unlocked at 1701406800.
+    const LEADERBOARD: &str = r#"{"event":"2023","owner_id":1,"members":{
+        "1":{"id":1,"name":"Ada","stars":3,"local_score":11,"completion_day_level":{
+            "1":{"1":{"get_star_ts":1701406900,"star_index":10},"2":{"get_star_ts":1701407200,"star_index":30}},
+            "2":{"1":{"get_star_ts":1701500000,"star_index":50}}}},
+        "2":{"id":2,"name":null,"stars":2,"local_score":5,"completion_day_level":{
+            "1":{"1":{"get_star_ts":1701406900,"star_index":5},"2":{"get_star_ts":1701410000,"star_index":40}}}},
+        "3":{"id":3,"name":"Grace","stars":0,"local_score":0,"completion_day_level":{}}}}"#;
+
+    #[test]
+    fn test_scores() {
+        let leaderboard: Leaderboard = LEADERBOARD.parse().unwrap();
+        assert_eq!(leaderboard.year, 2023);
+        assert_eq!(leaderboard.members[1].name, "(anonymous user #2)");
+        assert_eq!(leaderboard.members[0].star_count(), 3);
+        // day 1 star 1 is a tie in time, broken by the star index
+        assert_eq!(leaderboard.local_scores(), [2 + 3 + 3, 3 + 2, 0]);
+        let times = leaderboard.day_times(1);
+        assert_eq!(times.len(), 2);
+        assert_eq!(times[0].member.name, "Ada");
+        assert_eq!((times[0].first, times[0].second), (Some(100), Some(400)));
+        assert_eq!(times[0].delta(), Some(300));
+        assert_eq!(leaderboard.day_times(2)[0].delta(), None);
+        assert!(
+            "{\"event\":\"2023\"}"
+                .parse::<Leaderboard>()
+                .is_err_and(|e| e == "Missing members")
+        );
+    }
+}
@@ -1,17 +1,55 @@
+/// Declares the day and its `main`.
+///
+/// Optionally with a generator for synthetic inputs and with tags describing the puzzle:
+/// `all_aoc::solution!(14, 2024, generator = Generator, tags = ["grid", "simulation"])`.
+///
+/// The tags are only read from the source by [`crate::cli::index`], they name the kind of
+/// puzzle: `grid`, `graph`, `search`, `simulation`, `cycle-detection`, `parsing`, `string`,
+/// `vm`, `intcode`, `hashing`, `math`, `number-theory`, `geometry`, `ranges`, `combinatorics`,
+/// `dynamic-programming` and `brute-force`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr, $year:expr) => {
-        /// The current day.
+    ($day:expr, $year:expr $(, tags = [$($tag:literal),* $(,)?])?) => {
+        $crate::solution!(@consts $day, $year);
 
-        const DAY: $crate::cli::day::Day = $crate::day!($day, $year);
+        fn main() {
+            use $crate::cli::{generator::*, runner::*};
+            match Mode::from_args() {
+                Mode::Solve => {
+                    let input = $crate::cli::read_inputs_file(DAY).unwrap_or_else(|e| e.exit());
+                    run_part(part_one, &input, DAY, 1);
+                    run_part(part_two, &input, DAY, 2);
+                }
+                Mode::Generate { .. } | Mode::Scaling { .. } => unsupported(DAY),
+            }
+        }
+    };
+    ($day:expr, $year:expr, generator = $generator:ty $(, tags = [$($tag:literal),* $(,)?])?) => {
+        $crate::solution!(@consts $day, $year);
 
         fn main() {
-            use $crate::cli::runner::*;
-            let input = $crate::cli::read_inputs_file(DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            use $crate::cli::{generator::*, runner::*};
+            match Mode::from_args() {
+                Mode::Solve => {
+                    let input = $crate::cli::read_inputs_file(DAY).unwrap_or_else(|e| e.exit());
+                    run_part(part_one, &input, DAY, 1);
+                    run_part(part_two, &input, DAY, 2);
+                }
+                Mode::Generate { size, seed } => {
+                    print!("{}", generate::<$generator>(size, seed));
+                }
+                Mode::Scaling { size, seed } => {
+                    run_scaling::<$generator, _, _>(part_one, DAY, 1, size, seed);
+                    run_scaling::<$generator, _, _>(part_two, DAY, 2, size, seed);
+                }
+            }
         }
     };
+    (@consts $day:expr, $year:expr) => {
+        /// The current day.
+
+        const DAY: $crate::cli::day::Day = $crate::day!($day, $year);
+    };
 }
 #[macro_export]
 macro_rules! day {
@@ -1,23 +1,36 @@
+pub mod answers;
 #[expect(clippy::module_name_repetitions, reason = "makes more sense")]
 pub mod aoc_cli;
+pub mod cache;
 pub mod commands;
 pub mod day;
+#[expect(clippy::module_name_repetitions, reason = "makes more sense")]
+pub mod error;
+pub mod generator;
+pub mod index;
+pub mod json;
+pub mod leaderboard;
 pub mod macros;
+pub mod progress;
+pub mod regex;
 
 use core::panic;
-use std::fs;
+use std::{fs, io};
 
 use day::Day;
+use error::AOCError;
 pub mod runner;
+pub mod timings;
 
-#[must_use]
-pub fn read_inputs_file(day: Day) -> String {
+pub fn read_inputs_file(day: Day) -> Result<String, AOCError> {
     let path = day.input_path();
-    match fs::read_to_string(path) {
-        Ok(x) => x.trim_end().to_owned(),
-        Err(e) => panic!("Input File can't be read: {e}"),
+    match fs::read_to_string(&path) {
+        Ok(x) => Ok(x.trim_end().to_owned()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(AOCError::MissingFile { day, path }),
+        Err(e) => Err(AOCError::read(&path)(e)),
     }
 }
+/// Panics if the file can't be read, as it is meant for tests.
 #[must_use]
 pub fn read_examples_file(day: Day) -> String {
     let path = day.examples_path();
@@ -0,0 +1,198 @@
+use core::{fmt::Display, time::Duration};
+use std::{
+    fs, io,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::{commands::download::create_path, day::Day, error::AOCError, runner::Answer};
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+}
+impl Verdict {
+    /// Reads the verdict from the output of `aoc submit`. `None` if the answer wasn't judged,
+    /// e.g. because it was submitted too early or the part is already solved.
+    pub fn from_response(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Self::Right)
+        } else if response.contains("That's not the right answer") {
+            Some(Self::Wrong)
+        } else {
+            None
+        }
+    }
+}
+/// One submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub part: u8,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub answer: Answer,
+}
+/// When a day was started and everything that was submitted for it,
+/// stored in `data/progress/YYYY/DD.txt`.
+///
+/// ```text
+/// started: 1701406800
+/// 1 wrong: 1701407400 1234
+/// 1 right: 1701407520 1243
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    pub day: Day,
+    pub started: Option<u64>,
+    pub guesses: Vec<Guess>,
+}
+impl Progress {
+    pub const fn new(day: Day) -> Self {
+        Self {
+            day,
+            started: None,
+            guesses: vec![],
+        }
+    }
+    /// Reads the progress of the day. A missing file means that nothing was recorded.
+    pub fn load(day: Day) -> Result<Self, AOCError> {
+        let path = day.progress_path();
+        match fs::read_to_string(&path) {
+            Ok(s) => Self::parse(day, &s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::new(day)),
+            Err(e) => Err(AOCError::read(&path)(e)),
+        }
+    }
+    pub fn parse(day: Day, input: &str) -> Result<Self, AOCError> {
+        let mut progress = Self::new(day);
+        for line in input.lines().filter(|l| !l.trim().is_empty()) {
+            let invalid = || AOCError::InvalidFile {
+                path: day.progress_path(),
+                message: format!("Invalid line: {line}"),
+            };
+            let (key, value) = line.split_once(':').ok_or_else(invalid)?;
+            let value = value.trim();
+            if key == "started" {
+                progress.started = Some(value.parse().map_err(|_| invalid())?);
+                continue;
+            }
+            let (part, verdict) = key.split_once(' ').ok_or_else(invalid)?;
+            let (time, answer) = value.split_once(' ').ok_or_else(invalid)?;
+            progress.guesses.push(Guess {
+                part: part.parse().map_err(|_| invalid())?,
+                verdict: match verdict {
+                    "right" => Verdict::Right,
+                    "wrong" => Verdict::Wrong,
+                    _ => return Err(invalid()),
+                },
+                time: time.parse().map_err(|_| invalid())?,
+                answer: Answer::from_escaped(answer),
+            });
+        }
+        Ok(progress)
+    }
+    pub fn save(&self) -> Result<(), AOCError> {
+        let path = self.day.progress_path();
+        create_path(&path).map_err(AOCError::create_dir(&path))?;
+        fs::write(&path, self.to_string()).map_err(AOCError::write(&path))
+    }
+    /// Remembers now as the start, unless the day was started before.
+    pub fn start(&mut self) {
+        self.started.get_or_insert_with(now);
+    }
+    /// Remembers a judged answer, submitted now.
+    pub fn guess(&mut self, part: u8, verdict: Verdict, answer: Answer) {
+        self.guesses.push(Guess {
+            part,
+            verdict,
+            time: now(),
+            answer,
+        });
+    }
+    /// When the part was solved.
+    pub fn star(&self, part: u8) -> Option<u64> {
+        self.guesses
+            .iter()
+            .find(|g| g.part == part && g.verdict == Verdict::Right)
+            .map(|g| g.time)
+    }
+    /// How many answers were submitted for the part until it was solved.
+    pub fn guesses_needed(&self, part: u8) -> usize {
+        self.guesses
+            .iter()
+            .filter(|g| g.part == part)
+            .position(|g| g.verdict == Verdict::Right)
+            .map_or_else(
+                || self.guesses.iter().filter(|g| g.part == part).count(),
+                |i| i + 1,
+            )
+    }
+    pub fn time_to_first_star(&self) -> Option<Duration> {
+        Some(Duration::from_secs(
+            self.star(1)?.checked_sub(self.started?)?,
+        ))
+    }
+    pub fn time_between_stars(&self) -> Option<Duration> {
+        Some(Duration::from_secs(
+            self.star(2)?.checked_sub(self.star(1)?)?,
+        ))
+    }
+}
+impl Display for Progress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(started) = self.started {
+            writeln!(f, "started: {started}")?;
+        }
+        for guess in &self.guesses {
+            let verdict = match guess.verdict {
+                Verdict::Right => "right",
+                Verdict::Wrong => "wrong",
+            };
+            writeln!(
+                f,
+                "{} {verdict}: {} {}",
+                guess.part,
+                guess.time,
+                guess.answer.escaped()
+            )?;
+        }
+        Ok(())
+    }
+}
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress() {
+        let day = Day { day: 1, year: 2023 };
+        let input = "started: 1000\n1 wrong: 1600 1234\n1 right: 1720 1243\n2 wrong: 2000 a b\n";
+        let mut progress = Progress::parse(day, input).unwrap();
+        assert_eq!(progress.to_string(), input);
+        assert_eq!(progress.time_to_first_star(), Some(Duration::from_mins(12)));
+        assert_eq!(progress.guesses_needed(1), 2);
+        assert_eq!(progress.guesses_needed(2), 1);
+        assert_eq!(progress.guesses[2].answer, Answer::Text("a b".to_owned()));
+        assert_eq!(progress.time_between_stars(), None);
+        progress.start();
+        assert_eq!(progress.started, Some(1000));
+        progress.guess(2, Verdict::Right, Answer::Integer(5));
+        assert!(progress.time_between_stars().is_some());
+        Progress::parse(day, "1 maybe: 1 2").unwrap_err();
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too high."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently"),
+            None
+        );
+    }
+}
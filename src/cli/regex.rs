@@ -0,0 +1,577 @@
+use core::{iter, mem, ops::Range};
+
+/// A small regular expression engine, matching in linear time with a Pike VM.
+///
+/// Supports literals, `.`, classes like `[^a-z_]`, `\d`, `\w`, `\s` and their negations,
+/// groups `(..)` and `(?:..)`, alternation, the quantifiers `*`, `+`, `?`, `{n}`, `{n,}` and
+/// `{n,m}` with lazy variants, and the assertions `^`, `$`, `\b` and `\B`.
+/// A leading `(?i)` makes the expression ASCII case-insensitive.
+#[derive(Debug, Clone)]
+pub struct Regex {
+    program: Vec<Inst>,
+    case_insensitive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Inst {
+    Char(char),
+    Any,
+    Class(Class),
+    Assert(Assertion),
+    Split(usize, usize),
+    Jmp(usize),
+    Match,
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Class {
+    ranges: Vec<(char, char)>,
+    negated: bool,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Assertion {
+    LineStart,
+    LineEnd,
+    WordBoundary,
+    NotWordBoundary,
+}
+#[derive(Debug, Clone)]
+enum Node {
+    Empty,
+    Char(char),
+    Any,
+    Class(Class),
+    Assert(Assertion),
+    Concat(Vec<Self>),
+    Alternation(Vec<Self>),
+    Repeat {
+        node: Box<Self>,
+        min: usize,
+        max: Option<usize>,
+        greedy: bool,
+    },
+}
+
+/// Upper bound for counted repetitions, as every repetition is compiled separately.
+const MAX_REPEAT: usize = 1000;
+/// Upper bound for the number of instructions, as nested repetitions multiply.
+const MAX_PROGRAM: usize = 100_000;
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let (pattern, case_insensitive) = pattern
+            .strip_prefix("(?i)")
+            .map_or((pattern, false), |p| (p, true));
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            pos: 0,
+        };
+        let node = parser.alternation()?;
+        if let Some(c) = parser.peek() {
+            return Err(format!("Unexpected '{c}' at {}", parser.pos));
+        }
+        if node.size() > MAX_PROGRAM {
+            return Err(format!(
+                "Expression is too large, it needs more than {MAX_PROGRAM} instructions"
+            ));
+        }
+        let mut program = Vec::new();
+        compile(&node, &mut program);
+        program.push(Inst::Match);
+        Ok(Self {
+            program,
+            case_insensitive,
+        })
+    }
+    /// A regex matching the text literally.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if r"\.+*?()|[]{}^$".contains(c) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.find_at(haystack, 0).is_some()
+    }
+    /// The leftmost match starting at or after the byte offset `start`.
+    ///
+    /// # Panics
+    /// If `start` is not a char boundary of the haystack.
+    pub fn find_at(&self, haystack: &str, start: usize) -> Option<Range<usize>> {
+        assert!(
+            haystack.is_char_boundary(start),
+            "{start} is not a char boundary"
+        );
+        let mut current = Threads::new(self.program.len());
+        let mut next = Threads::new(self.program.len());
+        let mut matched = None;
+        let mut prev = haystack[..start].chars().next_back();
+        let positions = haystack[start..]
+            .char_indices()
+            .map(|(i, c)| (start + i, Some(c)))
+            .chain(iter::once((haystack.len(), None)));
+        for (i, c) in positions {
+            if matched.is_none() {
+                self.add(&mut current, 0, i, (prev, c));
+            }
+            for &(pc, thread_start) in &current.list {
+                let consumed = match (&self.program[pc], c) {
+                    (Inst::Match, _) => {
+                        matched = Some(thread_start..i);
+                        break;
+                    }
+                    (Inst::Char(expected), Some(c)) => self.eq(*expected, c),
+                    (Inst::Any, Some(c)) => c != '\n',
+                    (Inst::Class(class), Some(c)) => self.in_class(class, c),
+                    _ => false,
+                };
+                if consumed && let Some(c) = c {
+                    let after = haystack[i + c.len_utf8()..].chars().next();
+                    self.add(&mut next, pc + 1, thread_start, (Some(c), after));
+                }
+            }
+            mem::swap(&mut current, &mut next);
+            next.clear();
+            if current.list.is_empty() && matched.is_some() {
+                break;
+            }
+            prev = c;
+        }
+        matched
+    }
+    /// All non-overlapping matches from left to right.
+    pub fn find_iter<'a>(&'a self, haystack: &'a str) -> impl Iterator<Item = Range<usize>> + 'a {
+        let mut start = Some(0);
+        iter::from_fn(move || {
+            let found = self.find_at(haystack, start?)?;
+            start = if found.is_empty() {
+                haystack[found.end..]
+                    .chars()
+                    .next()
+                    .map(|c| found.end + c.len_utf8())
+            } else {
+                Some(found.end)
+            };
+            Some(found)
+        })
+    }
+    /// Follows jumps, splits and assertions from `pc`, with `around` the characters before and
+    /// after the current position.
+    fn add(
+        &self,
+        threads: &mut Threads,
+        pc: usize,
+        start: usize,
+        around: (Option<char>, Option<char>),
+    ) {
+        if threads.seen[pc] {
+            return;
+        }
+        threads.seen[pc] = true;
+        match self.program[pc] {
+            Inst::Jmp(to) => self.add(threads, to, start, around),
+            Inst::Split(first, second) => {
+                self.add(threads, first, start, around);
+                self.add(threads, second, start, around);
+            }
+            Inst::Assert(assertion) => {
+                if assertion.holds(around) {
+                    self.add(threads, pc + 1, start, around);
+                }
+            }
+            Inst::Char(_) | Inst::Any | Inst::Class(_) | Inst::Match => {
+                threads.list.push((pc, start));
+            }
+        }
+    }
+    const fn eq(&self, expected: char, c: char) -> bool {
+        expected == c || (self.case_insensitive && expected.eq_ignore_ascii_case(&c))
+    }
+    fn in_class(&self, class: &Class, c: char) -> bool {
+        let contains = |c: char| class.ranges.iter().any(|&(lo, hi)| (lo..=hi).contains(&c));
+        let found = contains(c)
+            || (self.case_insensitive
+                && (contains(c.to_ascii_lowercase()) || contains(c.to_ascii_uppercase())));
+        found != class.negated
+    }
+}
+
+struct Threads {
+    list: Vec<(usize, usize)>,
+    seen: Vec<bool>,
+}
+impl Threads {
+    fn new(len: usize) -> Self {
+        Self {
+            list: Vec::new(),
+            seen: vec![false; len],
+        }
+    }
+    fn clear(&mut self) {
+        self.list.clear();
+        self.seen.fill(false);
+    }
+}
+
+impl Assertion {
+    fn holds(self, (before, after): (Option<char>, Option<char>)) -> bool {
+        let word = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+        match self {
+            Self::LineStart => before.is_none_or(|c| c == '\n'),
+            Self::LineEnd => after.is_none_or(|c| c == '\n'),
+            Self::WordBoundary => word(before) != word(after),
+            Self::NotWordBoundary => word(before) == word(after),
+        }
+    }
+}
+
+impl Node {
+    /// The number of instructions [`compile`] emits, saturating instead of overflowing.
+    fn size(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::Char(_) | Self::Any | Self::Class(_) | Self::Assert(_) => 1,
+            Self::Concat(nodes) => nodes.iter().fold(0, |sum, n| sum.saturating_add(n.size())),
+            Self::Alternation(nodes) => nodes
+                .iter()
+                .fold(2 * nodes.len().saturating_sub(1), |sum, n| {
+                    sum.saturating_add(n.size())
+                }),
+            Self::Repeat { node, min, max, .. } => {
+                let size = node.size();
+                let optional = max.map_or_else(
+                    || size.saturating_add(2),
+                    |max| (max - min).saturating_mul(size.saturating_add(1)),
+                );
+                min.saturating_mul(size).saturating_add(optional)
+            }
+        }
+    }
+}
+
+fn compile(node: &Node, program: &mut Vec<Inst>) {
+    match node {
+        Node::Empty => {}
+        Node::Char(c) => program.push(Inst::Char(*c)),
+        Node::Any => program.push(Inst::Any),
+        Node::Class(class) => program.push(Inst::Class(class.clone())),
+        Node::Assert(assertion) => program.push(Inst::Assert(*assertion)),
+        Node::Concat(nodes) => {
+            for node in nodes {
+                compile(node, program);
+            }
+        }
+        Node::Alternation(nodes) => {
+            let mut jumps = Vec::new();
+            for (i, node) in nodes.iter().enumerate() {
+                if i + 1 == nodes.len() {
+                    compile(node, program);
+                } else {
+                    let split = program.len();
+                    program.push(Inst::Split(split + 1, 0));
+                    compile(node, program);
+                    jumps.push(program.len());
+                    program.push(Inst::Jmp(0));
+                    program[split] = Inst::Split(split + 1, program.len());
+                }
+            }
+            for jump in jumps {
+                program[jump] = Inst::Jmp(program.len());
+            }
+        }
+        Node::Repeat {
+            node,
+            min,
+            max,
+            greedy,
+        } => {
+            for _ in 0..*min {
+                compile(node, program);
+            }
+            let split = |body: usize, rest: usize| {
+                if *greedy {
+                    Inst::Split(body, rest)
+                } else {
+                    Inst::Split(rest, body)
+                }
+            };
+            if let Some(max) = max {
+                let mut splits = Vec::new();
+                for _ in *min..*max {
+                    splits.push(program.len());
+                    program.push(Inst::Match);
+                    compile(node, program);
+                }
+                for s in splits {
+                    program[s] = split(s + 1, program.len());
+                }
+            } else {
+                let start = program.len();
+                program.push(Inst::Match);
+                compile(node, program);
+                program.push(Inst::Jmp(start));
+                program[start] = split(start + 1, program.len());
+            }
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+    fn alternation(&mut self) -> Result<Node, String> {
+        let mut alternatives = vec![self.concat()?];
+        while self.eat('|') {
+            alternatives.push(self.concat()?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.remove(0)
+        } else {
+            Node::Alternation(alternatives)
+        })
+    }
+    fn concat(&mut self) -> Result<Node, String> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek()
+            && c != '|'
+            && c != ')'
+        {
+            let atom = self.atom()?;
+            nodes.push(self.quantifier(atom)?);
+        }
+        Ok(match nodes.len() {
+            0 => Node::Empty,
+            1 => nodes.remove(0),
+            _ => Node::Concat(nodes),
+        })
+    }
+    fn atom(&mut self) -> Result<Node, String> {
+        let at = self.pos;
+        Ok(match self.next() {
+            Some('(') => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err(format!("Unsupported group flag at {at}"));
+                }
+                let node = self.alternation()?;
+                if !self.eat(')') {
+                    return Err(format!("Unclosed group at {at}"));
+                }
+                node
+            }
+            Some('[') => Node::Class(self.class()?),
+            Some('.') => Node::Any,
+            Some('^') => Node::Assert(Assertion::LineStart),
+            Some('$') => Node::Assert(Assertion::LineEnd),
+            Some('\\') => match self.next() {
+                Some('b') => Node::Assert(Assertion::WordBoundary),
+                Some('B') => Node::Assert(Assertion::NotWordBoundary),
+                Some(c) => escaped_class(c).map_or_else(|| Node::Char(escaped(c)), Node::Class),
+                None => return Err("Trailing backslash".to_owned()),
+            },
+            Some(c @ ('*' | '+' | '?' | '{')) => {
+                return Err(format!("Nothing to repeat with '{c}' at {at}"));
+            }
+            Some(c) => Node::Char(c),
+            None => unreachable!("concat checks for the end"),
+        })
+    }
+    fn class(&mut self) -> Result<Class, String> {
+        let at = self.pos - 1;
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let c = match self.next() {
+                None => return Err(format!("Unclosed class at {at}")),
+                Some(']') if !first => break,
+                Some('\\') => {
+                    let c = self.next().ok_or("Trailing backslash")?;
+                    if let Some(class) = escaped_class(c) {
+                        if class.negated {
+                            return Err(format!("Negated class \\{c} inside a class at {at}"));
+                        }
+                        ranges.extend(class.ranges);
+                        first = false;
+                        continue;
+                    }
+                    escaped(c)
+                }
+                Some(c) => c,
+            };
+            first = false;
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1).is_some_and(|&c| c != ']') {
+                self.pos += 1;
+                let hi = match self.next() {
+                    Some('\\') => escaped(self.next().ok_or("Trailing backslash")?),
+                    Some(hi) => hi,
+                    None => return Err(format!("Unclosed class at {at}")),
+                };
+                if hi < c {
+                    return Err(format!("Invalid range {c}-{hi} at {at}"));
+                }
+                ranges.push((c, hi));
+            } else {
+                ranges.push((c, c));
+            }
+        }
+        Ok(Class { ranges, negated })
+    }
+    fn quantifier(&mut self, node: Node) -> Result<Node, String> {
+        let at = self.pos;
+        let (min, max) = if self.eat('*') {
+            (0, None)
+        } else if self.eat('+') {
+            (1, None)
+        } else if self.eat('?') {
+            (0, Some(1))
+        } else if self.eat('{') {
+            let min = self
+                .number()
+                .ok_or_else(|| format!("Invalid repetition at {at}"))?;
+            let max = if self.eat(',') {
+                self.number()
+            } else {
+                Some(min)
+            };
+            if !self.eat('}') || max.is_some_and(|max| max < min) {
+                return Err(format!("Invalid repetition at {at}"));
+            }
+            if max.unwrap_or(min) > MAX_REPEAT {
+                return Err(format!("Repetition at {at} is larger than {MAX_REPEAT}"));
+            }
+            (min, max)
+        } else {
+            return Ok(node);
+        };
+        let greedy = !self.eat('?');
+        Ok(Node::Repeat {
+            node: Box::new(node),
+            min,
+            max,
+            greedy,
+        })
+    }
+    fn number(&mut self) -> Option<usize> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+const fn escaped(c: char) -> char {
+    match c {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        c => c,
+    }
+}
+fn escaped_class(c: char) -> Option<Class> {
+    let ranges = match c.to_ascii_lowercase() {
+        'd' => vec![('0', '9')],
+        'w' => vec![('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')],
+        's' => vec![(' ', ' '), ('\t', '\r')],
+        _ => return None,
+    };
+    Some(Class {
+        ranges,
+        negated: c.is_ascii_uppercase(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_all(pattern: &str, haystack: &str) -> Vec<String> {
+        let regex = Regex::new(pattern).unwrap();
+        regex
+            .find_iter(haystack)
+            .map(|r| haystack[r].to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_matches() {
+        assert_eq!(find_all(r"\d+", "a12b3c"), ["12", "3"]);
+        assert_eq!(find_all("a|ab|b", "abab"), ["a", "b", "a", "b"]);
+        assert_eq!(find_all("a.*b", "axbxb\nab"), ["axbxb", "ab"]);
+        assert_eq!(find_all("a.*?b", "axbxb"), ["axb"]);
+        assert_eq!(
+            find_all(r"\bfoo\w*", "foo food afoo foo_bar"),
+            ["foo", "food", "foo_bar"]
+        );
+        assert_eq!(find_all("^[^#-]+$", "ab\n#c\nd-e\nf"), ["ab", "f"]);
+        assert_eq!(
+            find_all("(?:ab){2,3}", "ab abab abababab"),
+            ["abab", "ababab"]
+        );
+        assert_eq!(find_all("x{2}", "xxxxx"), ["xx", "xx"]);
+        assert_eq!(
+            find_all("(?i)chinese remainder", "CHINESE Remainder"),
+            ["CHINESE Remainder"]
+        );
+        assert_eq!(find_all("(?i)[a-c]+", "xAbCx"), ["AbC"]);
+        assert_eq!(find_all("a*", "baa"), ["", "aa", ""]);
+        assert_eq!(find_all("(a|b)*c", "\u{e4}abac"), ["abac"]);
+        assert!(
+            Regex::new(&Regex::escape("a.b(c)"))
+                .unwrap()
+                .is_match("xa.b(c)")
+        );
+        assert!(!Regex::new(&Regex::escape("a.b")).unwrap().is_match("axb"));
+    }
+
+    #[test]
+    fn test_errors() {
+        for pattern in ["(ab", "[ab", "*a", "a{3,2}", "[z-a]", "a)", "(?x)", "a\\"] {
+            assert!(Regex::new(pattern).is_err(), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn test_program_size() {
+        Regex::new("a{1000}").unwrap();
+        Regex::new("(a{1000}){1000}").unwrap_err();
+        Regex::new("((a{1000}){1000}){1000}").unwrap_err();
+        let regex = Regex::new("(?:ab|c){2,3}d*").unwrap();
+        assert_eq!(regex.program.len(), 20);
+    }
+
+    #[test]
+    #[should_panic = "not a char boundary"]
+    fn test_find_at_inside_char() {
+        Regex::new("a").unwrap().find_at("\u{e4}a", 1);
+    }
+
+    #[test]
+    fn test_linear() {
+        let regex = Regex::new("(a*)*b").unwrap();
+        assert!(!regex.is_match(&"a".repeat(10_000)));
+    }
+}
@@ -1,14 +1,148 @@
-use core::{cmp, fmt::Display, str::FromStr, time::Duration};
+use core::{cmp, convert::Infallible, fmt::Display, str::FromStr, time::Duration};
 use std::{
-    process::{self, Command, Stdio},
+    process::{Command, Stdio},
     time::Instant,
 };
 
-use crate::cli::day::Day;
+use crate::{
+    cli::{
+        day::Day,
+        error::AOCError,
+        progress::{Progress, Verdict},
+    },
+    helper::viz::{
+        self, export,
+        terminal::{MAX_FPS, MIN_FPS, Player},
+    },
+};
 
 pub const ANSI_BOLD: &str = "\u{1b}[1m";
 pub const ANSI_ITALIC: &str = "\u{1b}[3m";
 pub const ANSI_RESET: &str = "\u{1b}[0m";
+
+/// The result of a part, as it is compared and submitted.
+///
+/// Answers are written escaped (see [`Answer::escaped`]), so that a rendered grid
+/// fits on one line of the machine-readable output or of an answers file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Pixels that still have to be read, e.g. because OCR failed.
+    /// Lit pixels are `#`, all others `.`, and all rows have the same width.
+    Grid(Vec<String>),
+}
+impl Answer {
+    /// Classifies and normalises a result.
+    /// Numbers with leading zeros stay text, as they might be codes.
+    pub fn new(s: &str) -> Self {
+        if s.trim().contains('\n') {
+            return Self::grid(s);
+        }
+        let s = s.trim();
+        match s.parse::<i128>() {
+            Ok(n) if n.to_string() == s => Self::Integer(n),
+            _ => Self::Text(s.to_owned()),
+        }
+    }
+    fn grid(s: &str) -> Self {
+        let mut rows = s
+            .trim_matches('\n')
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .map(|c| {
+                        if matches!(c, '#' | '\u{2588}') {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+                    .trim_end_matches('.')
+                    .to_owned()
+            })
+            .collect::<Vec<_>>();
+        let width = rows.iter().map(String::len).max().unwrap_or_default();
+        for row in &mut rows {
+            row.extend(core::iter::repeat_n('.', width - row.len()));
+        }
+        Self::Grid(rows)
+    }
+    /// The answer on a single line, without the separators `;` and `%` of the machine-readable
+    /// output. Escapes are `\n` for rows, `\s` for `;`, `\p` for `%` and `\\` for `\`.
+    pub fn escaped(&self) -> String {
+        let s = match self {
+            Self::Integer(n) => return n.to_string(),
+            Self::Text(s) => s.clone(),
+            Self::Grid(rows) => rows.join("\n"),
+        };
+        let mut escaped = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                '\n' => escaped.push_str("\\n"),
+                ';' => escaped.push_str("\\s"),
+                '%' => escaped.push_str("\\p"),
+                '\\' => escaped.push_str("\\\\"),
+                c => escaped.push(c),
+            }
+        }
+        escaped
+    }
+    /// The inverse of [`Answer::escaped`].
+    pub fn from_escaped(s: &str) -> Self {
+        let mut unescaped = String::with_capacity(s.len());
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                unescaped.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('s') => unescaped.push(';'),
+                Some('p') => unescaped.push('%'),
+                Some(c) => unescaped.push(c),
+                None => unescaped.push('\\'),
+            }
+        }
+        Self::new(&unescaped)
+    }
+    /// The value that is sent to Advent of Code. Grids have to be read first.
+    pub fn submission(&self) -> Result<String, AOCError> {
+        match self {
+            Self::Integer(n) => Ok(n.to_string()),
+            Self::Text(s) => Ok(s.clone()),
+            Self::Grid(_) => Err(AOCError::Unsupported(
+                "A rendered grid can't be submitted, read the letters and submit them by hand"
+                    .to_owned(),
+            )),
+        }
+    }
+}
+/// Parses the escaped form, see [`Answer::escaped`].
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_escaped(s))
+    }
+}
+impl Display for Answer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+            Self::Grid(rows) => {
+                for row in rows {
+                    write!(f, "\n{row}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+#[derive(Debug)]
 pub struct PartDayResult<T> {
     pub day: Day,
     pub part: u8,
@@ -16,34 +150,14 @@ pub struct PartDayResult<T> {
     pub durations: Vec<Duration>,
 }
 
-impl<T> PartDayResult<T>
-where
-    T: ToString + FromStr,
-{
-    fn serialize(&self) -> String {
-        let day = self.day;
-        let part = self.part;
-        let result = self
-            .result
-            .as_ref()
-            .map_or_else(|| "None".to_owned(), std::string::ToString::to_string);
-        let durations = self
-            .durations
-            .iter()
-            .map(|d| d.as_nanos().to_string())
-            .collect::<Vec<_>>()
-            .join(";");
-
-        format!("{day};;{part};;{result};;{durations}%%%%")
-    }
-
+impl PartDayResult<Answer> {
     pub fn deserialize(input: &str) -> Result<Self, String> {
         let parts: Vec<&str> = input.split(";;").collect();
         if parts.len() != 4 {
             return Err(format!(
                 "Invalid input format. Got {} but expected 4. Parts: {:?}",
                 parts.len(),
-                &parts
+                parts
             ));
         }
         let day = Day::from_str(parts[0]).map_err(|_| "Invalid day value".to_owned())?;
@@ -53,14 +167,11 @@ where
         let result = if parts[2] == "None" {
             None
         } else {
-            Some(
-                parts[2]
-                    .parse::<T>()
-                    .map_err(|_| "Invalid result value".to_owned())?,
-            )
+            Some(Answer::from_escaped(parts[2]))
         };
         let durations = parts[3]
             .split(';')
+            .filter(|d| !d.is_empty())
             .map(|d| d.parse::<u64>().map(Duration::from_nanos))
             .collect::<Result<Vec<_>, _>>()
             .map_err(|_| "Invalid duration value".to_owned())?;
@@ -77,10 +188,26 @@ impl<T> PartDayResult<T>
 where
     T: Display,
 {
+    fn serialize(&self) -> String {
+        let day = self.day;
+        let part = self.part;
+        let result = self.result.as_ref().map_or_else(
+            || "None".to_owned(),
+            |r| Answer::new(&r.to_string()).escaped(),
+        );
+        let durations = self
+            .durations
+            .iter()
+            .map(|d| d.as_nanos().to_string())
+            .collect::<Vec<_>>()
+            .join(";");
+
+        format!("{day};;{part};;{result};;{durations}%%%%")
+    }
     pub fn pretty_print(&self) -> String {
         let avg = self.average_duration();
         self.result.as_ref().map_or_else(|| format!("Part {ANSI_BOLD}{}{ANSI_RESET}: ✖", self.part), |x| match self.durations.len() {
-                0 => unreachable!(),
+                0 => format!("Part {}: {ANSI_BOLD}{}{ANSI_RESET}", self.part, x),
                 1 => format!(
                     "Part {}: {ANSI_BOLD}{}{ANSI_RESET} ({:.02?})",
                     self.part, x, avg,
@@ -106,8 +233,13 @@ where
     }
 }
 impl<T> PartDayResult<T> {
+    /// Is zero if the part was skipped.
     pub fn average_duration(&self) -> Duration {
-        self.durations.iter().sum::<Duration>() / (self.durations.len().try_into().unwrap())
+        self.durations
+            .iter()
+            .sum::<Duration>()
+            .checked_div(self.durations.len().try_into().unwrap())
+            .unwrap_or_default()
     }
     fn standard_deviation(&self) -> Duration {
         let average = self.average_duration().as_nanos();
@@ -122,36 +254,101 @@ impl<T> PartDayResult<T> {
         Duration::from_nanos(erg.sqrt() as u64)
     }
 }
-pub fn run_part<I: Clone, T: FromStr + Display, F: Fn(I) -> Option<T>>(
+pub fn run_part<I: Clone, T: Display, F: Fn(I) -> Option<T>>(
     func: F,
     input: I,
     day: Day,
     part: u8,
 ) {
-    let (result, durations) = run_timed(func, input);
+    let machine_readable = std::env::args().any(|x| x == "--machine-readable");
+    if requested_part().is_some_and(|p| p != part) {
+        if machine_readable {
+            let skipped = PartDayResult::<T> {
+                day,
+                part,
+                result: None,
+                durations: vec![],
+            };
+            println!("{}", skipped.serialize());
+        }
+        return;
+    }
+    // with --viz, repeated runs would record the frames again
+    let time = std::env::args().any(|x| x == "--time") && !viz::enabled();
+    let (result, durations) = run_timed(func, input, time);
     let res = PartDayResult {
         day,
         part,
-        result,
+        result: result.map(|r| Answer::new(&r.to_string())),
         durations,
     };
-    if std::env::args().any(|x| x == "--machine-readable") {
+    if viz::enabled() {
+        show_frames(day, part);
+    }
+    if machine_readable {
         println!("{}", res.serialize());
     } else {
         println!("{}", res.pretty_print());
     }
 
-    if let Some(result) = res.result {
-        submit_result(result, day, part);
+    if let Some(answer) = &res.result
+        && let Err(e) = submit_result(answer, day, part)
+    {
+        e.exit();
     }
 }
 
-fn run_timed<I: Clone, T>(func: impl Fn(I) -> T, input: I) -> (T, Vec<Duration>) {
+/// Plays the frames the part recorded, with the speed given by `--fps`, which is clamped to
+/// [`MIN_FPS`]..=[`MAX_FPS`]. With `--viz-out`, the frames of all parts are written to that
+/// file after the last part.
+fn show_frames(day: Day, part: u8) {
+    let args: Vec<String> = std::env::args().collect();
+    let mut player = Player::default();
+    if let Some(fps) = args
+        .iter()
+        .position(|x| x == "--fps")
+        .and_then(|i| args.get(i + 1)?.parse::<f64>().ok())
+        .filter(|fps| !fps.is_nan())
+    {
+        player.fps = fps.clamp(MIN_FPS, MAX_FPS);
+    }
+    let Some(path) = args
+        .iter()
+        .position(|x| x == "--viz-out")
+        .and_then(|i| args.get(i + 1))
+    else {
+        player.play(&viz::take_frames(), &format!("Day {day} Part {part}"));
+        return;
+    };
+    if part != requested_part().unwrap_or(2) {
+        return;
+    }
+    let frames = viz::take_frames();
+    match export::frame_delay(player.fps)
+        .and_then(|delay| export::save(path, &frames, export::DEFAULT_SCALE, delay))
+    {
+        Ok(()) => println!("{} frames written to {path}", frames.len()),
+        Err(e) => eprintln!("Frames of {day} can't be exported: {e}"),
+    }
+}
+/// The part given with `--part`, if only one part should run.
+fn requested_part() -> Option<u8> {
+    let args: Vec<String> = std::env::args().collect();
+    let index = args.iter().position(|x| x == "--part")? + 1;
+    args.get(index)?.parse().ok()
+}
+
+/// Runs the function once, and if `time` is set, repeatedly to get a stable timing.
+pub fn run_timed<I, T, F>(func: F, input: I, time: bool) -> (T, Vec<Duration>)
+where
+    I: Clone,
+    F: Fn(I) -> T,
+{
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if time {
         bench(func, input, &base_time)
     } else {
         vec![base_time]
@@ -179,51 +376,85 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
-fn submit_result<T: Display>(result: T, day: Day, part: u8) {
+fn submit_result(answer: &Answer, day: Day, part: u8) -> Result<(), AOCError> {
     let args: Vec<String> = std::env::args().collect();
 
-    if !args.contains(&"--submit".into()) {
-        return;
-    }
-
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
-
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let Some(part_index) = args.iter().position(|x| x == "--submit") else {
+        return Ok(());
+    };
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let Some(part_submit) = args.get(part_index + 1).and_then(|p| p.parse::<u8>().ok()) else {
+        return Err(AOCError::InvalidArgument(
+            "Unexpected command-line input. Format: cargo solve 1 --submit 1".to_owned(),
+        ));
     };
 
     if part_submit != part {
-        return;
+        return Ok(());
     }
 
-    submit(day, part, &result.to_string());
-}
-fn submit(day: Day, part: u8, answer: &str) {
-    if !check() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+    let verdict = submit(day, part, &answer.submission()?)?;
+    if let Some(verdict) = verdict {
+        let mut progress = Progress::load(day)?;
+        progress.guess(part, verdict, answer.clone());
+        progress.save()?;
     }
+    Ok(())
+}
+/// Submits the answer with aoc-cli and returns the verdict of the website.
+fn submit(day: Day, part: u8, answer: &str) -> Result<Option<Verdict>, AOCError> {
+    Command::new("aoc")
+        .arg("-V")
+        .output()
+        .map_err(AOCError::command("aoc"))?;
     //println!("Submitting result via aoc-cli...");
     let mut args = day.as_args();
     args.push("submit".to_owned());
     args.push(part.to_string());
     args.push(answer.to_owned());
 
-    Command::new("aoc")
-        .args(args)
-        .stdout(Stdio::inherit())
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
         .stderr(Stdio::inherit())
         .output()
-        .unwrap();
+        .map_err(AOCError::command("aoc"))?;
+    let response = String::from_utf8_lossy(&output.stdout);
+    print!("{response}");
+    if output.status.success() {
+        Ok(Verdict::from_response(&response))
+    } else {
+        Err(AOCError::BadExitStatus {
+            command: format!("aoc {}", args.join(" ")),
+            status: output.status,
+        })
+    }
 }
-fn check() -> bool {
-    Command::new("aoc").arg("-V").output().is_ok()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::new("42"), Answer::Integer(42));
+        assert_eq!(Answer::new("007"), Answer::Text("007".to_owned()));
+        assert_eq!(
+            Answer::new("#.#\n\u{2588} \n"),
+            Answer::Grid(vec!["#.#".to_owned(), "#..".to_owned()])
+        );
+        for answer in [
+            Answer::new("a;b%c\\d;"),
+            Answer::new("##\n.#"),
+            Answer::new("-3"),
+        ] {
+            let escaped = answer.escaped();
+            assert!(!escaped.contains([';', '%', '\n']));
+            assert_eq!(Answer::from_escaped(&escaped), answer);
+        }
+        assert!(
+            Answer::new("#\n#")
+                .submission()
+                .is_err_and(|e| e.exit_code() == 2)
+        );
+    }
 }
@@ -0,0 +1,246 @@
+use core::{fmt::Display, str::FromStr, time::Duration};
+use std::{fs, io};
+
+use super::{commands::download::create_path, day::Day, error::AOCError, runner::PartDayResult};
+
+/// How many runs per part and profile are kept in the history.
+const HISTORY_LEN: usize = 10;
+/// Debug builds are roughly this much slower than release builds.
+const DEBUG_FACTOR: u32 = 10;
+
+/// How expensive it is to compute a part, classified by its timing history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Cost {
+    Fast,
+    Medium,
+    Slow,
+}
+impl Cost {
+    /// Classifies a release runtime.
+    pub fn from_duration(duration: Duration) -> Self {
+        if duration < Duration::from_millis(100) {
+            Self::Fast
+        } else if duration < Duration::from_secs(1) {
+            Self::Medium
+        } else {
+            Self::Slow
+        }
+    }
+}
+impl Display for Cost {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Fast => write!(f, "fast"),
+            Self::Medium => write!(f, "medium"),
+            Self::Slow => write!(f, "slow"),
+        }
+    }
+}
+impl FromStr for Cost {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fast" => Ok(Self::Fast),
+            "medium" => Ok(Self::Medium),
+            "slow" => Ok(Self::Slow),
+            x => Err(format!("Unknown cost class: {x}")),
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Debug,
+    Release,
+}
+impl Profile {
+    pub const fn new(release: bool) -> Self {
+        if release { Self::Release } else { Self::Debug }
+    }
+    /// The profile the current binary was compiled with.
+    pub const fn current() -> Self {
+        Self::new(!cfg!(debug_assertions))
+    }
+}
+impl Display for Profile {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Debug => write!(f, "debug"),
+            Self::Release => write!(f, "release"),
+        }
+    }
+}
+impl FromStr for Profile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "debug" => Ok(Self::Debug),
+            "release" => Ok(Self::Release),
+            x => Err(format!("Unknown profile: {x}")),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    part: u8,
+    profile: Profile,
+    duration: Duration,
+}
+/// The average runtimes of the last runs of a day, stored in `data/timings/YYYY/DD.txt`.
+///
+/// The history isn't committed. Until a day has one, it starts from the committed seed in
+/// `data/seed_timings/YYYY/DD.txt`, so that slow parts are known on a fresh checkout.
+/// The seed is part of the history after the first run and is forgotten like every run.
+///
+/// Every line has the form `<part> <profile>: <nanoseconds>`:
+/// ```text
+/// 1 release: 1534
+/// 2 debug: 3410923
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    day: Day,
+    entries: Vec<Entry>,
+}
+impl Timings {
+    pub const fn new(day: Day) -> Self {
+        Self {
+            day,
+            entries: vec![],
+        }
+    }
+    /// Reads the history of the day, or its seed if it never ran here.
+    /// Without both, nothing is known about the day.
+    pub fn load(day: Day) -> Result<Self, AOCError> {
+        for path in [day.timings_path(), day.seed_timings_path()] {
+            match fs::read_to_string(&path) {
+                Ok(s) => return Self::parse(day, &s),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(AOCError::read(&path)(e)),
+            }
+        }
+        Ok(Self::new(day))
+    }
+    pub fn parse(day: Day, input: &str) -> Result<Self, AOCError> {
+        let mut timings = Self::new(day);
+        for line in input.lines().filter(|l| !l.trim().is_empty()) {
+            let invalid = || AOCError::InvalidFile {
+                path: day.timings_path(),
+                message: format!("Invalid line: {line}"),
+            };
+            let (key, value) = line.split_once(':').ok_or_else(invalid)?;
+            let (part, profile) = key.trim().split_once(' ').ok_or_else(invalid)?;
+            timings.entries.push(Entry {
+                part: part.parse().map_err(|_| invalid())?,
+                profile: profile.parse().map_err(|_| invalid())?,
+                duration: Duration::from_nanos(value.trim().parse().map_err(|_| invalid())?),
+            });
+        }
+        Ok(timings)
+    }
+    pub fn save(&self) -> Result<(), AOCError> {
+        let path = self.day.timings_path();
+        create_path(&path).map_err(AOCError::create_dir(&path))?;
+        fs::write(&path, self.to_string()).map_err(AOCError::write(&path))
+    }
+    /// Adds the average duration of a run and forgets the oldest runs.
+    pub fn record(&mut self, part: u8, profile: Profile, duration: Duration) {
+        self.entries.push(Entry {
+            part,
+            profile,
+            duration,
+        });
+        let count = self
+            .entries
+            .iter()
+            .filter(|e| e.part == part && e.profile == profile)
+            .count();
+        let mut to_remove = count.saturating_sub(HISTORY_LEN);
+        self.entries.retain(|e| {
+            let remove = to_remove > 0 && e.part == part && e.profile == profile;
+            if remove {
+                to_remove -= 1;
+            }
+            !remove
+        });
+    }
+    /// Records every part that produced a result.
+    pub fn record_results<T>(&mut self, results: &[&PartDayResult<T>], profile: Profile) {
+        for res in results {
+            if res.result.is_some() && !res.durations.is_empty() {
+                self.record(res.part, profile, res.average_duration());
+            }
+        }
+    }
+    /// The median runtime of a part, scaled to a release build.
+    /// Release runs are preferred because debug runs are only a rough estimate.
+    pub fn expected_duration(&self, part: u8) -> Option<Duration> {
+        [Profile::Release, Profile::Debug]
+            .into_iter()
+            .find_map(|profile| {
+                let mut durations = self
+                    .entries
+                    .iter()
+                    .filter(|e| e.part == part && e.profile == profile)
+                    .map(|e| e.duration)
+                    .collect::<Vec<_>>();
+                durations.sort_unstable();
+                let median = *durations.get(durations.len() / 2)?;
+                Some(match profile {
+                    Profile::Release => median,
+                    Profile::Debug => median / DEBUG_FACTOR,
+                })
+            })
+    }
+    /// The class of a part, `None` without a history.
+    pub fn cost(&self, part: u8) -> Option<Cost> {
+        self.expected_duration(part).map(Cost::from_duration)
+    }
+}
+impl Display for Timings {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for e in &self.entries {
+            writeln!(f, "{} {}: {}", e.part, e.profile, e.duration.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification() {
+        let day = Day {
+            day: 14,
+            year: 2016,
+        };
+        let mut timings = Timings::parse(day, "1 release: 1000\n2 debug: 30000000000\n").unwrap();
+        assert_eq!(timings.cost(1), Some(Cost::Fast));
+        assert_eq!(timings.cost(2), Some(Cost::Slow));
+        timings.record(2, Profile::Release, Duration::from_millis(200));
+        assert_eq!(timings.cost(2), Some(Cost::Medium));
+        assert_eq!(
+            timings.to_string(),
+            "1 release: 1000\n2 debug: 30000000000\n2 release: 200000000\n"
+        );
+
+        for i in 0..20 {
+            timings.record(1, Profile::Release, Duration::from_secs(i));
+        }
+        assert_eq!(timings.entries.len(), HISTORY_LEN + 2);
+        assert_eq!(timings.cost(1), Some(Cost::Slow));
+        assert_eq!(Timings::new(day).cost(1), None);
+    }
+    #[test]
+    fn test_seeds() {
+        for (year, day) in [(2016, 11), (2016, 14), (2016, 23), (2022, 19)] {
+            let day = Day { day, year };
+            let seed = fs::read_to_string(day.seed_timings_path()).unwrap();
+            let timings = Timings::parse(day, &seed).unwrap();
+            assert_eq!(timings.cost(1), None, "{day}");
+            assert_eq!(timings.cost(2), Some(Cost::Slow), "{day}");
+        }
+    }
+}
@@ -614,8 +614,8 @@ impl<T> Graph for Special<T> {
     fn nodes_count(&self) -> usize {
         let set = self
             .adj_matrix
-            .iter()
-            .flat_map(|(_, v)| v.iter())
+            .values()
+            .flat_map(|v| v.iter())
             .collect::<HashSet<_>>();
         set.len()
     }
@@ -627,8 +627,8 @@ impl<T> Graph for Special<T> {
     fn nodes(&self) -> impl Iterator<Item = NodeIndex> {
         let set = self
             .adj_matrix
-            .iter()
-            .flat_map(|(_, v)| v.iter())
+            .values()
+            .flat_map(|v| v.iter())
             .chain(self.adj_matrix.keys())
             .collect::<HashSet<_>>();
         let mut vec = set.into_iter().collect::<Vec<_>>();
@@ -0,0 +1,316 @@
+use core::{fmt::Debug, hash::Hash, marker::PhantomData};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+use super::{Grid, dense::DenseGrid, region::Connectivity, sparse::SparseGrid, step};
+use crate::helper::position::{Direction8, Position};
+
+/// A grid an [`Automaton`] can run on.
+pub trait Cells<T>
+where
+    Self: Grid<T> + Sized,
+    T: Clone,
+{
+    /// The flat indices of the cells that can change in the next generation.
+    fn candidates(&self) -> Vec<usize>;
+    /// The value of the cell, which doesn't have to be stored.
+    fn value(&self, index: usize) -> Cow<'_, T>;
+    /// Prepares the grid to receive the next generation.
+    fn reset(&mut self);
+    fn store(&mut self, index: usize, val: T);
+}
+impl<T> Cells<T> for DenseGrid<T>
+where
+    T: Clone + Debug,
+{
+    fn candidates(&self) -> Vec<usize> {
+        (0..self.len()).collect()
+    }
+    fn value(&self, index: usize) -> Cow<'_, T> {
+        Cow::Borrowed(&self[index])
+    }
+    fn reset(&mut self) {}
+    fn store(&mut self, index: usize, val: T) {
+        self[index] = val;
+    }
+}
+/// Unset cells have the default value, which isn't stored. Only the set cells and their
+/// neighbours are updated.
+impl<T> Cells<T> for SparseGrid<T>
+where
+    T: Clone + Debug + Default + PartialEq,
+{
+    fn candidates(&self) -> Vec<usize> {
+        let (width, height) = (self.width(), self.height());
+        self.iter_all()
+            .flat_map(|(&i, _)| {
+                let (y, x) = (i / width, i % width);
+                (y.saturating_sub(1)..=(y + 1).min(height - 1)).flat_map(move |y| {
+                    (x.saturating_sub(1)..=(x + 1).min(width - 1)).map(move |x| y * width + x)
+                })
+            })
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect()
+    }
+    fn value(&self, index: usize) -> Cow<'_, T> {
+        self.get(index)
+            .map_or_else(|| Cow::Owned(T::default()), Cow::Borrowed)
+    }
+    fn reset(&mut self) {
+        self.clear();
+    }
+    fn store(&mut self, index: usize, val: T) {
+        if val != T::default() {
+            self.set(index, val);
+        }
+    }
+}
+
+/// The neighbours of a cell inside the grid, see [`Connectivity`]. Unset cells of a
+/// [`SparseGrid`] have the default value, so that both grids give the same neighbours.
+pub struct Neighbours<'a, T>
+where
+    T: Clone,
+{
+    cells: [Option<Cow<'a, T>>; 8],
+}
+impl<T> Neighbours<'_, T>
+where
+    T: Clone,
+{
+    pub fn count(&self, val: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.count_where(|cell| cell == val)
+    }
+    pub fn count_where<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.iter().filter(|cell| predicate(cell)).count()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter().flatten().map(|cell| &**cell)
+    }
+}
+
+/// Runs a cellular automaton: every generation, `rule(cell, neighbours)` gives the next value of
+/// every cell, computed from the previous generation only. Fixed cells never change.
+pub struct Automaton<G, T> {
+    current: G,
+    next: G,
+    fixed: HashSet<usize>,
+    connectivity: Connectivity,
+    generation: usize,
+    cell: PhantomData<T>,
+}
+impl<G, T> Automaton<G, T>
+where
+    G: Cells<T>,
+    T: Clone,
+{
+    pub fn new(grid: G, connectivity: Connectivity) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            fixed: HashSet::new(),
+            connectivity,
+            generation: 0,
+            cell: PhantomData,
+        }
+    }
+    /// Keeps the cells at their current values. Panics if a cell is outside of the grid.
+    #[must_use]
+    pub fn with_fixed<I>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let (width, height) = (self.current.width(), self.current.height());
+        self.fixed.extend(cells.into_iter().map(|(y, x)| {
+            assert!(y < height && x < width, "fixed cell must be in the grid");
+            y * width + x
+        }));
+        self
+    }
+    pub const fn grid(&self) -> &G {
+        &self.current
+    }
+    pub fn into_grid(self) -> G {
+        self.current
+    }
+    /// The number of generations so far.
+    pub const fn generation(&self) -> usize {
+        self.generation
+    }
+    pub fn step<F>(&mut self, rule: F)
+    where
+        F: Fn(&T, &Neighbours<'_, T>) -> T,
+    {
+        self.next.reset();
+        for &i in &self.fixed {
+            self.next.store(i, self.current.value(i).into_owned());
+        }
+        for i in self.current.candidates() {
+            if !self.fixed.is_empty() && self.fixed.contains(&i) {
+                continue;
+            }
+            let val = rule(&self.current.value(i), &self.neighbours(i));
+            self.next.store(i, val);
+        }
+        core::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+    fn neighbours(&self, index: usize) -> Neighbours<'_, T> {
+        let (width, height) = (self.current.width(), self.current.height());
+        let pos = Position {
+            x: (index % width).cast_signed(),
+            y: (index / width).cast_signed(),
+        };
+        let dirs = match self.connectivity {
+            Connectivity::Four => &[
+                Direction8::North,
+                Direction8::East,
+                Direction8::South,
+                Direction8::West,
+            ][..],
+            Connectivity::Eight => &Direction8::all_dirs()[..],
+        };
+        let mut cells = [const { None }; 8];
+        for (cell, &dir) in cells.iter_mut().zip(dirs) {
+            let next = step(pos, dir);
+            if let (Ok(x), Ok(y)) = (usize::try_from(next.x), usize::try_from(next.y))
+                && x < width
+                && y < height
+            {
+                *cell = Some(self.current.value(y * width + x));
+            }
+        }
+        Neighbours { cells }
+    }
+    pub fn run<F>(&mut self, generations: usize, rule: F)
+    where
+        F: Fn(&T, &Neighbours<'_, T>) -> T,
+    {
+        for _ in 0..generations {
+            self.step(&rule);
+        }
+    }
+    /// Runs until the given generation. Once a state repeats, the remaining whole cycles are
+    /// skipped, so that even a generation like 1,000,000,000 is reached quickly.
+    pub fn run_to<F>(&mut self, generation: usize, rule: F)
+    where
+        G: Hash + Eq,
+        F: Fn(&T, &Neighbours<'_, T>) -> T,
+    {
+        let mut seen = HashMap::new();
+        while self.generation < generation {
+            if let Some(previous) = seen.insert(self.current.clone(), self.generation) {
+                let remaining = (generation - self.generation) % (self.generation - previous);
+                self.run(remaining, &rule);
+                self.generation = generation;
+                return;
+            }
+            self.step(&rule);
+        }
+    }
+}
+
+/// Applies `step` to the state `generations` times, skipping the remaining whole cycles once a
+/// state repeats. See [`Automaton::run_to`] for rules that only depend on the neighbours.
+pub fn advance<S, F>(state: S, generations: usize, mut step: F) -> S
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&mut S),
+{
+    let mut state = state;
+    let mut seen = HashMap::new();
+    let mut generation = 0;
+    while generation < generations {
+        if let Some(previous) = seen.insert(state.clone(), generation) {
+            for _ in 0..(generations - generation) % (generation - previous) {
+                step(&mut state);
+            }
+            return state;
+        }
+        step(&mut state);
+        generation += 1;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIFE: fn(&bool, &Neighbours<'_, bool>) -> bool =
+        |cell, neighbours| matches!((cell, neighbours.count(&true)), (true, 2 | 3) | (false, 3));
+    fn parse(s: &str) -> DenseGrid<bool> {
+        DenseGrid::from_iter(s.chars().filter(|c| *c != '\n').map(|c| c == '#'), 5)
+    }
+    const BLINKER: &str = ".....\n..#..\n..#..\n..#..\n.....";
+    const BLINKER_TURNED: &str = ".....\n.....\n.###.\n.....\n.....";
+    fn sparse(grid: &DenseGrid<bool>) -> SparseGrid<bool> {
+        let mut sparse = SparseGrid::new(grid.width(), grid.height());
+        for (i, _) in grid.iter().enumerate().filter(|(_, c)| **c) {
+            sparse.set(i, true);
+        }
+        sparse
+    }
+    #[test]
+    fn test_step() {
+        let mut automaton = Automaton::new(parse(BLINKER), Connectivity::Eight);
+        automaton.step(LIFE);
+        assert_eq!(automaton.generation(), 1);
+        assert_eq!(automaton.grid(), &parse(BLINKER_TURNED));
+    }
+    #[test]
+    fn test_run_to() {
+        let mut automaton = Automaton::new(parse(BLINKER), Connectivity::Eight);
+        automaton.run_to(1_000_000_001, LIFE);
+        assert_eq!(automaton.generation(), 1_000_000_001);
+        assert_eq!(automaton.grid(), &parse(BLINKER_TURNED));
+    }
+    #[test]
+    fn test_fixed() {
+        let mut fixed = Automaton::new(parse(BLINKER), Connectivity::Eight).with_fixed([(1, 2)]);
+        fixed.run(2, LIFE);
+        assert_eq!(fixed.grid(), &parse(".....\n.###.\n.###.\n..#..\n....."));
+    }
+    #[test]
+    #[should_panic = "fixed cell must be in the grid"]
+    fn test_fixed_outside() {
+        let _ = Automaton::new(parse(BLINKER), Connectivity::Eight).with_fixed([(0, 5)]);
+    }
+    #[test]
+    fn test_sparse() {
+        let mut automaton = Automaton::new(sparse(&parse(BLINKER)), Connectivity::Eight);
+        automaton.step(LIFE);
+        assert_eq!(automaton.grid().len(), 3);
+        assert_eq!(automaton.grid().get((2, 1)), Some(&true));
+        assert_eq!(automaton.grid().get((1, 2)), None);
+    }
+    #[test]
+    fn test_unset_neighbours() {
+        // only cells away from the border have 6 neighbours that are off
+        let rule = |_: &bool, neighbours: &Neighbours<'_, bool>| neighbours.count(&false) >= 6;
+        let grid = parse(BLINKER);
+        let mut dense = Automaton::new(grid.clone(), Connectivity::Eight);
+        let mut sparse = Automaton::new(sparse(&grid), Connectivity::Eight);
+        dense.step(rule);
+        sparse.step(rule);
+        assert_eq!(&self::sparse(dense.grid()), sparse.grid());
+        assert_eq!(sparse.grid().get((1, 1)), Some(&true));
+        assert_eq!(sparse.grid().get((0, 0)), None);
+    }
+    #[test]
+    fn test_advance() {
+        assert_eq!(
+            advance(0, 1_000_000_000, |n| *n = (*n + 1) % 7),
+            1_000_000_000 % 7
+        );
+    }
+}
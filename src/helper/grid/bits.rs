@@ -0,0 +1,447 @@
+use core::{
+    fmt::Debug,
+    ops::{BitAnd, BitOr, BitXor, Index, Not},
+};
+use std::borrow::Cow;
+
+use super::{Grid, automaton::Cells, dense::DenseGrid, index::GridIndex};
+use crate::helper::position::{Direction8, Position};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans with one bit per cell.
+///
+/// Every row starts with a new word, so that whole rows can be shifted and combined a word at a
+/// time. The bits behind the last column are always unset.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    /// The number of words per row.
+    stride: usize,
+}
+impl Grid<bool> for BitGrid {
+    fn width(&self) -> usize {
+        self.width
+    }
+    fn height(&self) -> usize {
+        self.height
+    }
+    fn len(&self) -> usize {
+        self.width * self.height
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn get<I>(&self, index: I) -> Option<&bool>
+    where
+        I: GridIndex<bool>,
+    {
+        let i = index.to_flat_index(self);
+        (i < self.len()).then(|| if self.bit(i) { &true } else { &false })
+    }
+    fn set<I>(&mut self, index: I, val: bool) -> bool
+    where
+        I: GridIndex<bool>,
+    {
+        let i = index.to_flat_index(self);
+        if i >= self.len() {
+            return false;
+        }
+        let (word, bit) = self.locate(i);
+        if val {
+            self.words[word] |= 1 << bit;
+        } else {
+            self.words[word] &= !(1 << bit);
+        }
+        true
+    }
+    fn all_indices(&self) -> impl Iterator<Item = impl GridIndex<bool>> {
+        0..self.len()
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a bool>
+    where
+        bool: 'a,
+    {
+        (0..self.len()).map(|i| if self.bit(i) { &true } else { &false })
+    }
+}
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(BITS);
+        Self {
+            words: vec![0; stride * height],
+            width,
+            height,
+            stride,
+        }
+    }
+    pub fn from_dense(grid: &DenseGrid<bool>) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for (i, _) in grid.iter().enumerate().filter(|(_, lit)| **lit) {
+            bits.set(i, true);
+        }
+        bits
+    }
+    pub fn to_dense(&self) -> DenseGrid<bool> {
+        DenseGrid::from_iter(self.iter().copied(), self.width)
+    }
+    /// The number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+    pub fn count_row(&self, y: usize) -> usize {
+        self.row(y).iter().map(|w| w.count_ones() as usize).sum()
+    }
+    /// Moves every cell of the row `by` columns to the East, wrapping around.
+    pub fn rotate_row(&mut self, y: usize, by: usize) {
+        // a row without cells stays as it is
+        let Some(by) = by.checked_rem(self.width) else {
+            return;
+        };
+        if by == 0 {
+            return;
+        }
+        let row = self.row(y);
+        let rotated = shift_up(row, by)
+            .into_iter()
+            .zip(shift_down(row, self.width - by))
+            .map(|(a, b)| a | b)
+            .collect::<Vec<_>>();
+        self.row_mut(y).copy_from_slice(&rotated);
+        self.clear_padding();
+    }
+    /// Moves every cell of the column `by` rows to the South, wrapping around.
+    pub fn rotate_col(&mut self, x: usize, by: usize) {
+        let Some(by) = by.checked_rem(self.height) else {
+            return;
+        };
+        let mut col = (0..self.height)
+            .map(|y| self.bit(y * self.width + x))
+            .collect::<Vec<_>>();
+        col.rotate_right(by);
+        for (y, val) in col.into_iter().enumerate() {
+            self.set((y, x), val);
+        }
+    }
+    /// Every cell moved one step in the direction, cells leaving the grid are dropped.
+    #[must_use]
+    pub fn shifted(&self, dir: Direction8) -> Self {
+        let (dx, dy) = match dir {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        };
+        let mut shifted = Self::new(self.width, self.height);
+        for y in 0..self.height {
+            let Some(source) = y.checked_add_signed(-dy).filter(|s| *s < self.height) else {
+                continue;
+            };
+            let row = self.row(source);
+            let row = match dx {
+                1 => shift_up(row, 1),
+                -1 => shift_down(row, 1),
+                _ => row.to_vec(),
+            };
+            shifted.row_mut(y).copy_from_slice(&row);
+        }
+        shifted.clear_padding();
+        shifted
+    }
+    /// The next generation of a life-like automaton: a cell is born with a number of set
+    /// neighbours in `birth` and survives with one in `survive`.
+    /// All cells are computed at once, 64 at a time.
+    #[must_use]
+    pub fn step_life(&self, birth: &[usize], survive: &[usize]) -> Self {
+        // the neighbour counts in binary, one grid per bit
+        let mut counts: [Vec<u64>; 4] = core::array::from_fn(|_| vec![0; self.words.len()]);
+        for dir in Direction8::all_dirs() {
+            let neighbours = self.shifted(dir);
+            for (i, word) in neighbours.words.iter().enumerate() {
+                let mut carry = *word;
+                for count in &mut counts {
+                    let sum = count[i] ^ carry;
+                    carry &= count[i];
+                    count[i] = sum;
+                }
+            }
+        }
+        let equals = |i: usize, n: usize| {
+            counts
+                .iter()
+                .enumerate()
+                .fold(u64::MAX, |mask, (bit, count)| {
+                    mask & if (n >> bit) & 1 == 1 {
+                        count[i]
+                    } else {
+                        !count[i]
+                    }
+                })
+        };
+        let mut next = Self::new(self.width, self.height);
+        for (i, word) in self.words.iter().enumerate() {
+            let born = birth.iter().fold(0, |acc, n| acc | equals(i, *n));
+            let stays = survive.iter().fold(0, |acc, n| acc | equals(i, *n));
+            next.words[i] = (born & !word) | (stays & word);
+        }
+        next.clear_padding();
+        next
+    }
+    const fn locate(&self, i: usize) -> (usize, usize) {
+        let (y, x) = (i / self.width, i % self.width);
+        (y * self.stride + x / BITS, x % BITS)
+    }
+    fn bit(&self, i: usize) -> bool {
+        let (word, bit) = self.locate(i);
+        (self.words[word] >> bit) & 1 == 1
+    }
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.stride..(y + 1) * self.stride]
+    }
+    fn row_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.words[y * self.stride..(y + 1) * self.stride]
+    }
+    fn clear_padding(&mut self) {
+        let used = self.width % BITS;
+        if used == 0 {
+            return;
+        }
+        let mask = (1 << used) - 1;
+        for y in 0..self.height {
+            self.words[(y + 1) * self.stride - 1] &= mask;
+        }
+    }
+}
+/// The bits moved `by` towards the higher indices.
+fn shift_up(words: &[u64], by: usize) -> Vec<u64> {
+    let (skip, bits) = (by / BITS, by % BITS);
+    (0..words.len())
+        .map(|i| {
+            let Some(source) = i.checked_sub(skip) else {
+                return 0;
+            };
+            let carry = match source.checked_sub(1) {
+                Some(lower) if bits != 0 => words[lower] >> (BITS - bits),
+                _ => 0,
+            };
+            (words[source] << bits) | carry
+        })
+        .collect()
+}
+/// The bits moved `by` towards the lower indices.
+fn shift_down(words: &[u64], by: usize) -> Vec<u64> {
+    let (skip, bits) = (by / BITS, by % BITS);
+    (0..words.len())
+        .map(|i| {
+            let Some(&word) = words.get(i + skip) else {
+                return 0;
+            };
+            let carry = match words.get(i + skip + 1) {
+                Some(higher) if bits != 0 => higher << (BITS - bits),
+                _ => 0,
+            };
+            (word >> bits) | carry
+        })
+        .collect()
+}
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        combine(self, rhs, |a, b| a & b)
+    }
+}
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        combine(self, rhs, |a, b| a | b)
+    }
+}
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        combine(self, rhs, |a, b| a ^ b)
+    }
+}
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> Self::Output {
+        let mut grid = BitGrid {
+            words: self.words.iter().map(|w| !w).collect(),
+            ..self.clone()
+        };
+        grid.clear_padding();
+        grid
+    }
+}
+fn combine<F>(a: &BitGrid, b: &BitGrid, op: F) -> BitGrid
+where
+    F: Fn(u64, u64) -> u64,
+{
+    assert_eq!(
+        (a.width, a.height),
+        (b.width, b.height),
+        "grids must have the same size"
+    );
+    BitGrid {
+        words: a
+            .words
+            .iter()
+            .zip(&b.words)
+            .map(|(a, b)| op(*a, *b))
+            .collect(),
+        ..a.clone()
+    }
+}
+impl Cells<bool> for BitGrid {
+    fn candidates(&self) -> Vec<usize> {
+        (0..self.len()).collect()
+    }
+    fn value(&self, index: usize) -> Cow<'_, bool> {
+        Cow::Owned(self.bit(index))
+    }
+    fn reset(&mut self) {}
+    fn store(&mut self, index: usize, val: bool) {
+        self.set(index, val);
+    }
+}
+impl Index<usize> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+impl Index<(usize, usize)> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+impl Index<Position<usize>> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, index: Position<usize>) -> &Self::Output {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+impl IntoIterator for BitGrid {
+    type Item = bool;
+    type IntoIter = std::vec::IntoIter<bool>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter().copied().collect::<Vec<_>>().into_iter()
+    }
+}
+impl Debug for BitGrid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for y in 0..self.height {
+            let row = (0..self.width)
+                .map(|x| {
+                    if self.bit(y * self.width + x) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> BitGrid {
+        BitGrid::from_dense(&DenseGrid::from_iter_iter(
+            s.lines().map(|l| l.chars().map(|c| c == '#')),
+        ))
+    }
+
+    /// Wider than a word, so that bits cross word borders.
+    fn wide() -> BitGrid {
+        let mut grid = BitGrid::new(70, 3);
+        grid.set((0, 63), true);
+        grid.set((1, 69), true);
+        grid.set((2, 0), true);
+        grid
+    }
+    #[test]
+    fn test_set() {
+        let mut grid = wide();
+        assert_eq!(grid.count_ones(), 3);
+        assert_eq!(grid.count_row(1), 1);
+        assert!(grid[(0, 63)]);
+        assert!(!grid[(0, 64)]);
+        assert!(!grid.set(210, true));
+        grid.set((0, 63), false);
+        assert_eq!(grid.count_ones(), 2);
+    }
+    #[test]
+    fn test_rotate() {
+        let mut grid = wide();
+        grid.rotate_row(0, 2);
+        assert!(grid[(0, 65)]);
+        grid.rotate_row(1, 1);
+        assert!(grid[(1, 0)]);
+        assert_eq!(grid.count_row(1), 1);
+        grid.rotate_col(0, 2);
+        assert!(grid[(1, 0)]);
+        assert!(grid[(0, 0)]);
+        assert_eq!(grid.count_ones(), 3);
+    }
+    #[test]
+    fn test_rotate_empty() {
+        let mut no_columns = BitGrid::new(0, 3);
+        no_columns.rotate_row(0, 1);
+        assert_eq!(no_columns.count_ones(), 0);
+        let mut no_rows = BitGrid::new(3, 0);
+        no_rows.rotate_col(0, 1);
+        assert_eq!(no_rows.count_ones(), 0);
+    }
+    #[test]
+    fn test_shifted() {
+        let grid = wide();
+        let moved = grid.shifted(Direction8::SouthEast);
+        // the cells on the right and bottom border are shifted out
+        assert_eq!(moved.count_ones(), 1);
+        assert!(moved[(1, 64)]);
+        let moved = grid.shifted(Direction8::NorthWest);
+        assert_eq!(moved.count_ones(), 1);
+        assert!(moved[(0, 68)]);
+    }
+    #[test]
+    fn test_operators() {
+        let grid = wide();
+        let moved = grid.shifted(Direction8::East);
+        assert_eq!((&moved & &grid).count_ones(), 0);
+        assert_eq!((&moved | &grid).count_ones(), 5);
+        assert_eq!((!&grid).count_ones(), 210 - 3);
+    }
+    #[test]
+    fn test_to_dense() {
+        let dense = wide().to_dense();
+        assert_eq!(dense.iter().filter(|b| **b).count(), 3);
+        assert!(dense[(1, 69)]);
+    }
+    #[test]
+    fn test_step_life() {
+        let blinker = parse(".....\n..#..\n..#..\n..#..\n.....");
+        let next = blinker.step_life(&[3], &[2, 3]);
+        assert_eq!(next, parse(".....\n.....\n.###.\n.....\n....."));
+        assert_eq!(next.step_life(&[3], &[2, 3]), blinker);
+    }
+}
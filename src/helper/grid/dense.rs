@@ -1,9 +1,14 @@
 use crate::helper::position::Position;
 
-use super::{Grid, index::GridIndex};
+use super::{
+    Grid, GridMut,
+    index::GridIndex,
+    view::{View, ViewMut},
+};
 use core::fmt::Write as _;
 use core::{
     fmt::Debug,
+    iter,
     ops::{Index, IndexMut},
 };
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -56,23 +61,29 @@ where
     {
         self.data.iter()
     }
+    fn all_indices(&self) -> impl Iterator<Item = impl GridIndex<T>> {
+        0..self.len()
+    }
+}
+impl<T> GridMut<T> for DenseGrid<T>
+where
+    T: Clone + Debug,
+{
     fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
     where
         T: 'a,
     {
         self.data.iter_mut()
     }
-    fn all_indices(&self) -> impl Iterator<Item = impl GridIndex<T>> {
-        0..self.len()
-    }
 }
 impl<T> DenseGrid<T> {
+    /// A width of 0 gives an empty grid.
     pub fn from_iter<I>(it: I, width: usize) -> Self
     where
         I: Iterator<Item = T>,
     {
         let data: Vec<_> = it.collect();
-        let height = data.len() / width;
+        let height = data.len().checked_div(width).unwrap_or_default();
         debug_assert_eq!(height * width, data.len());
         Self {
             data,
@@ -213,6 +224,152 @@ impl<T> DenseGrid<T> {
 
         *self = out;
     }
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_cw(&mut self)
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        *self = self.remap(height, width, |y, x| (height - 1 - x) * width + y);
+    }
+    /// Rotates the grid by 90 degrees counterclockwise.
+    pub fn rotate_ccw(&mut self)
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        *self = self.remap(height, width, |y, x| x * width + width - 1 - y);
+    }
+    /// Mirrors the grid at its vertical axis, so that every row is reversed.
+    pub fn flip_horizontal(&mut self) {
+        if self.width == 0 {
+            return;
+        }
+        for row in self.data.chunks_mut(self.width) {
+            row.reverse();
+        }
+    }
+    /// Mirrors the grid at its horizontal axis, so that the order of the rows is reversed.
+    pub fn flip_vertical(&mut self) {
+        for y in 0..self.height / 2 {
+            let (top, bottom) = self.data.split_at_mut((self.height - 1 - y) * self.width);
+            top[y * self.width..(y + 1) * self.width].swap_with_slice(&mut bottom[..self.width]);
+        }
+    }
+    /// The eight rotations and reflections of the grid, starting with the grid itself.
+    /// The first four are the clockwise rotations, the others their mirror images.
+    pub fn orientations(&self) -> impl Iterator<Item = Self>
+    where
+        T: Clone,
+    {
+        let mut grid = self.clone();
+        (0..8).map(move |i| {
+            if i == 4 {
+                grid.flip_horizontal();
+            } else if i > 0 {
+                grid.rotate_cw();
+            }
+            grid.clone()
+        })
+    }
+    /// The smallest of the [`DenseGrid::orientations`], which is the same for all of them.
+    /// Hashing it identifies a grid regardless of its orientation.
+    #[must_use]
+    pub fn canonical(&self) -> Self
+    where
+        T: Clone + Ord,
+    {
+        self.orientations()
+            .min_by(|a, b| (a.height, a.width, &a.data).cmp(&(b.height, b.width, &b.data)))
+            .expect("there are always eight orientations")
+    }
+    /// A grid of the given size, where the cell `(y, x)` is the cell `index(y, x)` of this grid.
+    fn remap<F>(&self, width: usize, height: usize, index: F) -> Self
+    where
+        T: Clone,
+        F: Fn(usize, usize) -> usize,
+    {
+        Self {
+            data: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (y, x)))
+                .map(|(y, x)| self.data[index(y, x)].clone())
+                .collect(),
+            height,
+            width,
+        }
+    }
+}
+impl<T> DenseGrid<T>
+where
+    T: Clone + Debug,
+{
+    /// The rectangle of the given size with its top left corner at `offset`.
+    pub fn view(&self, offset: (usize, usize), height: usize, width: usize) -> View<'_, T> {
+        View::new(self, offset, height, width)
+    }
+    pub fn view_mut(
+        &mut self,
+        offset: (usize, usize),
+        height: usize,
+        width: usize,
+    ) -> ViewMut<'_, T> {
+        ViewMut::new(self, offset, height, width)
+    }
+    /// Every rectangle of the given size that fits into the grid, row by row.
+    pub fn windows(&self, height: usize, width: usize) -> impl Iterator<Item = View<'_, T>> {
+        let ys = 0..=self.height.saturating_sub(height);
+        let xs = 0..=self.width.saturating_sub(width);
+        let fits = height <= self.height && width <= self.width;
+        ys.filter(move |_| fits)
+            .flat_map(move |y| xs.clone().map(move |x| self.view((y, x), height, width)))
+    }
+    /// Cuts the grid into blocks of `size` x `size`, which keep their arrangement.
+    pub fn split_blocks(&self, size: usize) -> DenseGrid<Self> {
+        assert!(
+            self.width.is_multiple_of(size) && self.height.is_multiple_of(size),
+            "size must divide evenly into width and height"
+        );
+        DenseGrid::from_iter(
+            (0..self.height / size).flat_map(|y| {
+                (0..self.width / size)
+                    .map(move |x| self.view((y * size, x * size), size, size).to_grid())
+            }),
+            self.width / size,
+        )
+    }
+    /// Joins blocks of equal size, the inverse of [`DenseGrid::split_blocks`].
+    /// Without blocks or with empty blocks the grid is empty.
+    pub fn from_blocks(blocks: &DenseGrid<Self>) -> Self {
+        let Some(first) = blocks.data.first().and_then(|b| b.data.first()) else {
+            return Self::from_iter(iter::empty(), 0);
+        };
+        let (block_height, block_width) = (blocks.data[0].height, blocks.data[0].width);
+        let mut grid = Self::new(
+            blocks.width * block_width,
+            blocks.height * block_height,
+            first.clone(),
+        );
+        for (i, block) in blocks.data.iter().enumerate() {
+            debug_assert_eq!((block.height, block.width), (block_height, block_width));
+            let (y, x) = (i / blocks.width, i % blocks.width);
+            grid.paste(block, (y * block_height, x * block_width));
+        }
+        grid
+    }
+    /// Copies the other grid into this one, with its top left corner at `offset`.
+    pub fn paste(&mut self, other: &Self, offset: (usize, usize)) {
+        assert!(
+            offset.0 + other.height <= self.height && offset.1 + other.width <= self.width,
+            "pasted grid must fit into the grid"
+        );
+        if other.width == 0 || other.height == 0 {
+            return;
+        }
+        for (y, row) in other.data.chunks(other.width).enumerate() {
+            let start = (offset.0 + y) * self.width + offset.1;
+            self.data[start..start + other.width].clone_from_slice(row);
+        }
+    }
 }
 impl<T> Index<usize> for DenseGrid<T> {
     type Output = T;
@@ -295,3 +452,130 @@ where
         f.write_fmt(format_args!("\n{s}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(grid: &DenseGrid<char>) -> Vec<String> {
+        (0..grid.height)
+            .map(|y| grid.get_row(y).collect())
+            .collect()
+    }
+    #[test]
+    fn test_rotate() {
+        let grid = DenseGrid::<char>::from_string("abc\ndef");
+        let mut cw = grid.clone();
+        cw.rotate_cw();
+        assert_eq!(rows(&cw), ["da", "eb", "fc"]);
+        let mut ccw = grid.clone();
+        ccw.rotate_ccw();
+        assert_eq!(rows(&ccw), ["cf", "be", "ad"]);
+        ccw.rotate_cw();
+        assert_eq!(ccw, grid);
+    }
+    #[test]
+    fn test_flip() {
+        let mut grid = DenseGrid::<char>::from_string("abc\ndef");
+        grid.flip_horizontal();
+        assert_eq!(rows(&grid), ["cba", "fed"]);
+        grid.flip_vertical();
+        assert_eq!(rows(&grid), ["fed", "cba"]);
+    }
+    #[test]
+    fn test_orientations() {
+        let grid = DenseGrid::<char>::from_string("abc\ndef");
+        let orientations = grid.orientations().collect::<Vec<_>>();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert_eq!(rows(&orientations[1]), ["da", "eb", "fc"]);
+        assert_eq!(rows(&orientations[5]), ["def", "abc"]);
+        assert_eq!(rows(&orientations[6]), ["ad", "be", "cf"]);
+    }
+    #[test]
+    fn test_canonical() {
+        let grid = DenseGrid::<char>::from_string("abc\ndef");
+        assert_eq!(rows(&grid.canonical()), ["abc", "def"]);
+        assert!(
+            grid.orientations()
+                .all(|o| o.canonical() == grid.canonical())
+        );
+    }
+    #[test]
+    fn test_empty_orientations() {
+        let empty = DenseGrid::<char>::from_iter(iter::empty(), 0);
+        assert!(empty.orientations().all(|o| o.data.is_empty()));
+        assert_eq!(empty.orientations().count(), 8);
+        assert_eq!(empty.canonical().data, []);
+    }
+
+    fn letters() -> DenseGrid<char> {
+        DenseGrid::from_string("abcd\nefgh\nijkl\nmnop")
+    }
+    #[test]
+    fn test_view() {
+        let grid = letters();
+        let view = grid.view((1, 1), 2, 3);
+        assert_eq!(view.iter().collect::<String>(), "fghjkl");
+        assert_eq!(view.get_row(1).collect::<String>(), "jkl");
+        assert_eq!(view[(1, 0)], 'j');
+        assert_eq!(view.get((2, 0)), None);
+        assert_eq!(rows(&view.to_grid()), ["fgh", "jkl"]);
+    }
+    #[test]
+    #[should_panic = "row must be in the view"]
+    fn test_view_row_outside() {
+        let grid = letters();
+        let _ = grid.view((1, 1), 2, 3).get_row(2);
+    }
+    #[test]
+    fn test_empty_view() {
+        let grid = letters();
+        let empty = grid.view((1, 1), 2, 0).to_grid();
+        assert_eq!((empty.width, empty.height), (0, 0));
+        assert_eq!(grid.view((1, 1), 0, 2).to_grid().data, []);
+    }
+    #[test]
+    fn test_windows() {
+        let grid = letters();
+        let windows = grid.windows(3, 3).map(|w| w[(0, 0)]).collect::<String>();
+        assert_eq!(windows, "abef");
+        assert_eq!(grid.windows(5, 1).count(), 0);
+    }
+    #[test]
+    fn test_blocks() {
+        let grid = letters();
+        let blocks = grid.split_blocks(2);
+        assert_eq!((blocks.width, blocks.height), (2, 2));
+        assert_eq!(blocks[(1, 0)].data, ['i', 'j', 'm', 'n']);
+        assert_eq!(DenseGrid::from_blocks(&blocks), grid);
+    }
+    #[test]
+    fn test_empty_blocks() {
+        let none = DenseGrid::<DenseGrid<char>>::from_iter(iter::empty(), 0);
+        assert_eq!(DenseGrid::from_blocks(&none).data, []);
+        let empty = DenseGrid::new(2, 2, DenseGrid::<char>::from_iter(iter::empty(), 0));
+        assert_eq!(DenseGrid::from_blocks(&empty).data, []);
+    }
+    #[test]
+    fn test_view_mut() {
+        let mut grid = letters();
+        let mut view = grid.view_mut((2, 2), 2, 2);
+        view.fill(&'.');
+        view[(0, 1)] = '#';
+        assert!(!view.set((2, 0), '#'));
+        assert_eq!(rows(&grid), ["abcd", "efgh", "ij.#", "mn.."]);
+    }
+    #[test]
+    fn test_paste() {
+        let mut grid = letters();
+        grid.paste(&DenseGrid::from_string("xy"), (0, 1));
+        assert_eq!(rows(&grid), ["axyd", "efgh", "ijkl", "mnop"]);
+    }
+    #[test]
+    fn test_paste_empty() {
+        let mut grid = letters();
+        grid.paste(&DenseGrid::from_iter(iter::empty(), 0), (1, 1));
+        assert_eq!(grid, letters());
+    }
+}
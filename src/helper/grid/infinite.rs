@@ -0,0 +1,284 @@
+use core::{
+    fmt::Debug,
+    ops::{Index, IndexMut},
+};
+use std::collections::{
+    HashMap,
+    hash_map::{IntoIter, Iter},
+};
+
+use super::{dense::DenseGrid, step};
+use crate::helper::position::{Direction8, Position};
+
+/// A grid without borders, that grows in every direction.
+///
+/// Positions are signed, `y` grows to the South like in the other grids.
+/// The bounding box covers every position that was set, it doesn't shrink on [`InfiniteGrid::remove`]
+/// unless the removed position was on its border.
+#[derive(Clone, PartialEq, Eq)]
+#[expect(clippy::module_name_repetitions, reason = "makes more sense")]
+pub struct InfiniteGrid<T> {
+    data: HashMap<Position<isize>, T>,
+    bounds: Option<(Position<isize>, Position<isize>)>,
+}
+impl<T> Default for InfiniteGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> InfiniteGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            data: HashMap::new(),
+            bounds: None,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    pub fn get(&self, pos: Position<isize>) -> Option<&T> {
+        self.data.get(&pos)
+    }
+    pub fn get_mut(&mut self, pos: Position<isize>) -> Option<&mut T> {
+        self.data.get_mut(&pos)
+    }
+    pub fn contains(&self, pos: Position<isize>) -> bool {
+        self.data.contains_key(&pos)
+    }
+    /// Sets the value and returns the previous one.
+    pub fn set(&mut self, pos: Position<isize>, val: T) -> Option<T> {
+        self.bounds = Some(grow(self.bounds, pos));
+        self.data.insert(pos, val)
+    }
+    pub fn remove(&mut self, pos: Position<isize>) -> Option<T> {
+        let val = self.data.remove(&pos)?;
+        let on_border = self.bounds.is_some_and(|(min, max)| {
+            [min.x, max.x].contains(&pos.x) || [min.y, max.y].contains(&pos.y)
+        });
+        if on_border {
+            self.bounds = self
+                .data
+                .keys()
+                .fold(None, |bounds, &p| Some(grow(bounds, p)));
+        }
+        Some(val)
+    }
+    /// The smallest and the largest corner of the set positions, both inclusive.
+    pub const fn bounds(&self) -> Option<(Position<isize>, Position<isize>)> {
+        self.bounds
+    }
+    /// The width of the bounding box.
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| max.x.abs_diff(min.x) + 1)
+    }
+    /// The height of the bounding box.
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| max.y.abs_diff(min.y) + 1)
+    }
+    pub fn iter_all(&self) -> Iter<'_, Position<isize>, T> {
+        self.data.iter()
+    }
+    pub fn positions(&self) -> impl Iterator<Item = Position<isize>> {
+        self.data.keys().copied()
+    }
+    /// The neighbour in the direction, if it is set.
+    pub fn get_dir8(&self, pos: Position<isize>, dir: Direction8) -> Option<(Position<isize>, &T)> {
+        let next = step(pos, dir);
+        self.get(next).map(|v| (next, v))
+    }
+    /// returns the neigbors (if they are set) clockwise starting from the North.
+    pub fn get_neigbors4(
+        &self,
+        pos: Position<isize>,
+    ) -> impl Iterator<Item = (Position<isize>, &T)> {
+        [
+            Direction8::North,
+            Direction8::East,
+            Direction8::South,
+            Direction8::West,
+        ]
+        .into_iter()
+        .filter_map(move |dir| self.get_dir8(pos, dir))
+    }
+    /// returns the neigbors (if they are set) clockwise starting from the North.
+    pub fn get_neigbors8(
+        &self,
+        pos: Position<isize>,
+    ) -> impl Iterator<Item = (Position<isize>, &T)> {
+        Direction8::all_dirs()
+            .into_iter()
+            .filter_map(move |dir| self.get_dir8(pos, dir))
+    }
+    /// One line per row of the bounding box, with `background` where nothing is set.
+    pub fn render<F>(&self, background: char, cell: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| self.get(Position { x, y }).map_or(background, &cell))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+    /// The bounding box as a dense grid, filled with `background` where nothing is set.
+    /// The index `(0, 0)` is the smallest corner of [`InfiniteGrid::bounds`].
+    pub fn to_dense(&self, background: T) -> DenseGrid<T>
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return DenseGrid::from_iter(core::iter::empty(), 0);
+        };
+        let background = &background;
+        DenseGrid::from_iter(
+            (min.y..=max.y).flat_map(|y| {
+                (min.x..=max.x).map(move |x| {
+                    self.get(Position { x, y })
+                        .cloned()
+                        .unwrap_or_else(|| background.clone())
+                })
+            }),
+            self.width(),
+        )
+    }
+}
+/// The bounding box extended to contain the position.
+fn grow(
+    bounds: Option<(Position<isize>, Position<isize>)>,
+    pos: Position<isize>,
+) -> (Position<isize>, Position<isize>) {
+    bounds.map_or((pos, pos), |(min, max)| {
+        (
+            Position {
+                x: min.x.min(pos.x),
+                y: min.y.min(pos.y),
+            },
+            Position {
+                x: max.x.max(pos.x),
+                y: max.y.max(pos.y),
+            },
+        )
+    })
+}
+impl<T> FromIterator<(Position<isize>, T)> for InfiniteGrid<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Position<isize>, T)>,
+    {
+        let mut grid = Self::new();
+        for (pos, val) in iter {
+            grid.set(pos, val);
+        }
+        grid
+    }
+}
+impl<T> Index<Position<isize>> for InfiniteGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: Position<isize>) -> &Self::Output {
+        self.data.get(&index).expect("Position is not set")
+    }
+}
+impl<T> IndexMut<Position<isize>> for InfiniteGrid<T> {
+    fn index_mut(&mut self, index: Position<isize>) -> &mut Self::Output {
+        self.data.get_mut(&index).expect("Position is not set")
+    }
+}
+impl<T> IntoIterator for InfiniteGrid<T> {
+    type Item = (Position<isize>, T);
+    type IntoIter = IntoIter<Position<isize>, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+impl<T> Debug for InfiniteGrid<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return f.write_str("\n");
+        };
+        let mut s = format!("\n{min:?}..={max:?}\n");
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Position { x, y }) {
+                    Some(v) => s.extend(format!("{v:?}").chars()),
+                    None => s.push(' '),
+                }
+            }
+            s.push('\n');
+        }
+        f.write_str(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::grid::Grid as _;
+
+    /// `...b`, `..#.` and `a...` with the `#` at the origin.
+    fn sample() -> InfiniteGrid<char> {
+        let mut grid = InfiniteGrid::new();
+        grid.set(Position { x: 0, y: 0 }, '#');
+        grid.set(Position { x: -2, y: 1 }, 'a');
+        grid.set(Position { x: 1, y: -1 }, 'b');
+        grid
+    }
+    #[test]
+    fn test_bounds() {
+        assert_eq!(InfiniteGrid::<char>::new().bounds(), None);
+        let grid = sample();
+        assert_eq!(
+            grid.bounds(),
+            Some((Position { x: -2, y: -1 }, Position { x: 1, y: 1 }))
+        );
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+    }
+    #[test]
+    fn test_render() {
+        assert_eq!(sample().render('.', |c| *c), "...b\n..#.\na...");
+    }
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let neighbours = grid
+            .get_neigbors8(Position { x: 0, y: 0 })
+            .collect::<Vec<_>>();
+        assert_eq!(neighbours, [(Position { x: 1, y: -1 }, &'b')]);
+        assert_eq!(grid.get_neigbors4(Position { x: -1, y: 1 }).count(), 1);
+    }
+    #[test]
+    fn test_to_dense() {
+        let empty = InfiniteGrid::<char>::new().to_dense('.');
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        let dense = sample().to_dense('.');
+        assert_eq!((dense.width(), dense.height()), (4, 3));
+        assert_eq!(dense[(2, 0)], 'a');
+    }
+    #[test]
+    fn test_remove() {
+        let mut grid = sample();
+        assert_eq!(grid.remove(Position { x: -2, y: 1 }), Some('a'));
+        assert_eq!(grid.remove(Position { x: -2, y: 1 }), None);
+        assert_eq!(
+            grid.bounds(),
+            Some((Position { x: 0, y: -1 }, Position { x: 1, y: 0 }))
+        );
+        grid[Position { x: 0, y: 0 }] = '@';
+        assert_eq!(grid.render(' ', |c| *c), " b\n@ ");
+    }
+}
@@ -0,0 +1,150 @@
+use crate::helper::position::Position;
+
+/// Where a ray stopped, see [`super::Grid::cast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hit<'a, T> {
+    /// The cell that matched, as `(y, x)`.
+    pub index: (usize, usize),
+    pub cell: &'a T,
+    /// The number of steps from the start, so 1 for a neighbour.
+    pub distance: usize,
+    /// The cell in front of the hit, which is the start if the neighbour matched.
+    pub before: (usize, usize),
+}
+
+/// The positions from one end of a line to the other, both included.
+/// Horizontal, vertical and diagonal lines contain every cell, other lines are rasterized with
+/// Bresenham's algorithm.
+#[derive(Debug, Clone)]
+pub struct Line {
+    pos: Position<isize>,
+    to: Position<isize>,
+    delta: Position<isize>,
+    step: Position<isize>,
+    error: isize,
+    done: bool,
+}
+impl Line {
+    pub const fn new(from: Position<isize>, to: Position<isize>) -> Self {
+        let delta = Position {
+            x: (to.x - from.x).abs(),
+            y: -(to.y - from.y).abs(),
+        };
+        Self {
+            pos: from,
+            to,
+            delta,
+            step: Position {
+                x: (to.x - from.x).signum(),
+                y: (to.y - from.y).signum(),
+            },
+            error: delta.x + delta.y,
+            done: false,
+        }
+    }
+}
+impl Iterator for Line {
+    type Item = Position<isize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let pos = self.pos;
+        if pos == self.to {
+            self.done = true;
+            return Some(pos);
+        }
+        let error = 2 * self.error;
+        if error >= self.delta.y {
+            self.error += self.delta.y;
+            self.pos.x += self.step.x;
+        }
+        if error <= self.delta.x {
+            self.error += self.delta.x;
+            self.pos.y += self.step.y;
+        }
+        Some(pos)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let len = (self.to.x - self.pos.x)
+            .unsigned_abs()
+            .max((self.to.y - self.pos.y).unsigned_abs())
+            + 1;
+        (len, Some(len))
+    }
+}
+impl ExactSizeIterator for Line {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::{
+        grid::{Grid as _, dense::DenseGrid},
+        position::Direction8,
+    };
+
+    fn pos(x: isize, y: isize) -> Position<isize> {
+        Position { x, y }
+    }
+    fn sample() -> DenseGrid<char> {
+        DenseGrid::from_string("#...\n....\n.#..\n..L.")
+    }
+    #[test]
+    fn test_straight_line() {
+        let line = Line::new(pos(0, 0), pos(3, 0));
+        assert_eq!(line.len(), 4);
+        assert_eq!(
+            line.collect::<Vec<_>>(),
+            [pos(0, 0), pos(1, 0), pos(2, 0), pos(3, 0)]
+        );
+        assert_eq!(
+            Line::new(pos(2, 2), pos(0, 0)).collect::<Vec<_>>(),
+            [pos(2, 2), pos(1, 1), pos(0, 0)]
+        );
+        assert_eq!(Line::new(pos(5, 5), pos(5, 5)).count(), 1);
+    }
+    #[test]
+    fn test_bresenham() {
+        let line = Line::new(pos(0, 0), pos(4, -2));
+        assert_eq!(line.len(), 5);
+        assert_eq!(
+            line.collect::<Vec<_>>(),
+            [pos(0, 0), pos(1, -1), pos(2, -1), pos(3, -2), pos(4, -2)]
+        );
+    }
+    #[test]
+    fn test_ray() {
+        let grid = sample();
+        let ray = grid.ray((3, 2), Direction8::North).collect::<Vec<_>>();
+        assert_eq!(ray, [((2, 2), &'.'), ((1, 2), &'.'), ((0, 2), &'.')]);
+    }
+    #[test]
+    fn test_cast() {
+        let grid = sample();
+        let hit = grid
+            .cast((3, 2), Direction8::NorthWest, |c| *c == '#')
+            .unwrap();
+        assert_eq!(hit.index, (2, 1));
+        assert_eq!(hit.distance, 1);
+        assert_eq!(hit.before, (3, 2));
+        let hit = grid
+            .cast((3, 3), Direction8::NorthWest, |c| *c == '#')
+            .unwrap();
+        assert_eq!((hit.index, hit.distance, hit.before), ((0, 0), 3, (1, 1)));
+        assert_eq!(grid.cast((3, 2), Direction8::East, |c| *c == '#'), None);
+    }
+    #[test]
+    fn test_grid_line() {
+        assert_eq!(
+            sample()
+                .line((0, 0), (3, 2))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "#.#L"
+        );
+    }
+}
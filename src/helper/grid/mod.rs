@@ -1,24 +1,27 @@
+pub mod automaton;
+pub mod bits;
 pub mod dense;
 pub mod index;
+pub mod infinite;
+pub mod line;
+pub mod path;
+pub mod region;
 pub mod sparse;
+pub mod toroidal;
+pub mod view;
 use core::{
     fmt::Debug,
     ops::{Index, IndexMut},
 };
 
 use index::GridIndex;
+use line::{Hit, Line};
 
-use crate::helper::position::Direction8;
+use crate::helper::position::{Direction8, Position};
 
 pub trait Grid<T>
 where
-    Self: Index<usize>
-        + IndexMut<usize>
-        + Index<(usize, usize)>
-        + IndexMut<(usize, usize)>
-        + IntoIterator
-        + Clone
-        + Debug,
+    Self: Index<usize> + Index<(usize, usize)> + IntoIterator + Clone + Debug,
 {
     fn width(&self) -> usize;
     fn height(&self) -> usize;
@@ -32,9 +35,6 @@ where
         I: GridIndex<T>;
     fn all_indices(&self) -> impl Iterator<Item = impl GridIndex<T>>;
     fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
-    where
-        T: 'a;
-    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
     where
         T: 'a;
     /// returns the neigbors (if they exist) counterclockwise starting from the North.
@@ -88,6 +88,89 @@ where
             Direction8::NorthWest => get_north_west(self, index),
         }
     }
+    /// The cells in the direction from `index` until the border, without `index` itself.
+    fn ray<'a, I>(
+        &'a self,
+        index: I,
+        dir: Direction8,
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)>
+    where
+        T: 'a,
+        Self: Sized,
+        I: GridIndex<T>,
+    {
+        core::iter::successors(self.get_dir8(index, dir), move |(pos, _)| {
+            self.get_dir8(*pos, dir)
+        })
+    }
+    /// The first cell in the direction from `index` that matches, see [`Grid::ray`].
+    fn cast<I, F>(&self, index: I, dir: Direction8, predicate: F) -> Option<Hit<'_, T>>
+    where
+        Self: Sized,
+        I: GridIndex<T>,
+        F: Fn(&T) -> bool,
+    {
+        let mut before = index.to_coordinates(self);
+        for (distance, (pos, cell)) in self.ray(index, dir).enumerate() {
+            if predicate(cell) {
+                return Some(Hit {
+                    index: pos,
+                    cell,
+                    distance: distance + 1,
+                    before,
+                });
+            }
+            before = pos;
+        }
+        None
+    }
+    /// The cells on the line between both cells, see [`Line`].
+    fn line<'a, I>(&'a self, from: I, to: I) -> impl Iterator<Item = ((usize, usize), &'a T)>
+    where
+        T: 'a,
+        Self: Sized,
+        I: GridIndex<T>,
+    {
+        let position = |index: I| {
+            let (y, x) = index.to_coordinates(self);
+            Position {
+                x: x.cast_signed(),
+                y: y.cast_signed(),
+            }
+        };
+        Line::new(position(from), position(to)).filter_map(move |pos| {
+            let index = (pos.y.cast_unsigned(), pos.x.cast_unsigned());
+            self.get(index).map(|cell| (index, cell))
+        })
+    }
+}
+/// A grid whose cells can be borrowed mutably.
+#[expect(clippy::module_name_repetitions, reason = "makes more sense")]
+pub trait GridMut<T>
+where
+    Self: Grid<T> + IndexMut<usize> + IndexMut<(usize, usize)>,
+{
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a;
+}
+
+/// The position one step in the direction, with North being `y - 1` like in the grids.
+pub const fn step(pos: Position<isize>, dir: Direction8) -> Position<isize> {
+    let (dx, dy) = match dir {
+        Direction8::North => (0, -1),
+        Direction8::NorthEast => (1, -1),
+        Direction8::East => (1, 0),
+        Direction8::SouthEast => (1, 1),
+        Direction8::South => (0, 1),
+        Direction8::SouthWest => (-1, 1),
+        Direction8::West => (-1, 0),
+        Direction8::NorthWest => (-1, -1),
+    };
+    Position {
+        x: pos.x + dx,
+        y: pos.y + dy,
+    }
 }
 
 fn get_north<T>(grid: &impl Grid<T>, index: impl GridIndex<T>) -> Option<((usize, usize), &T)> {
@@ -169,8 +252,6 @@ fn get_north_west<T>(
     index: impl GridIndex<T>,
 ) -> Option<((usize, usize), &T)> {
     let (y, x) = index.to_coordinates(grid);
-    let (new_y, new_x) = x
-        .checked_sub(1)
-        .and_then(|new_x| y.checked_sub(1).map(|new_y| (new_y, new_x)))?;
+    let (new_y, new_x) = y.checked_sub(1).zip(x.checked_sub(1))?;
     grid.get((new_y, new_x)).map(|r| ((new_y, new_x), r))
 }
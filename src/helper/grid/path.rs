@@ -0,0 +1,481 @@
+use core::{cmp::Ordering, fmt::Debug, ops::Add};
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::{Grid, dense::DenseGrid, index::GridIndex as _};
+use crate::helper::position::{Direction4, Direction8};
+
+/// Extra state of a search on top of the cell, like the facing direction.
+///
+/// Every value needs its own index below `COUNT`, so that the distances of all
+/// `(cell, state)` pairs fit into one vector.
+pub trait SearchState: Copy {
+    const COUNT: usize;
+    fn index(self) -> usize;
+}
+impl SearchState for () {
+    const COUNT: usize = 1;
+    fn index(self) -> usize {
+        0
+    }
+}
+impl SearchState for bool {
+    const COUNT: usize = 2;
+    fn index(self) -> usize {
+        usize::from(self)
+    }
+}
+impl SearchState for Direction4 {
+    const COUNT: usize = 4;
+    fn index(self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::East => 1,
+            Self::South => 2,
+            Self::West => 3,
+        }
+    }
+}
+impl SearchState for Direction8 {
+    const COUNT: usize = 8;
+    fn index(self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::NorthEast => 1,
+            Self::East => 2,
+            Self::SouthEast => 3,
+            Self::South => 4,
+            Self::SouthWest => 5,
+            Self::West => 6,
+            Self::NorthWest => 7,
+        }
+    }
+}
+impl<A, B> SearchState for (A, B)
+where
+    A: SearchState,
+    B: SearchState,
+{
+    const COUNT: usize = A::COUNT * B::COUNT;
+    fn index(self) -> usize {
+        self.0.index() * B::COUNT + self.1.index()
+    }
+}
+
+/// A cell as `(y, x)` together with the state of the search there.
+pub type Node<S> = ((usize, usize), S);
+
+/// The result of a search: the distance of every reached `(cell, state)` and the step it was
+/// reached from. Cells are `(y, x)` coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distances<S, C> {
+    width: usize,
+    height: usize,
+    dist: Vec<Option<C>>,
+    prev: Vec<Option<Node<S>>>,
+}
+impl<S, C> Distances<S, C>
+where
+    S: SearchState,
+    C: Copy + Ord,
+{
+    fn new<G, T>(grid: &G) -> Self
+    where
+        G: Grid<T>,
+    {
+        let nodes = grid.width() * grid.height() * S::COUNT;
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            dist: vec![None; nodes],
+            prev: vec![None; nodes],
+        }
+    }
+    const fn contains(&self, (y, x): (usize, usize)) -> bool {
+        y < self.height && x < self.width
+    }
+    const fn cell(&self, (y, x): (usize, usize)) -> usize {
+        (y * self.width + x) * S::COUNT
+    }
+    fn node(&self, index: (usize, usize), state: S) -> usize {
+        self.cell(index) + state.index()
+    }
+    /// The distance of the cell in the state, `None` if it wasn't reached.
+    pub fn get(&self, index: (usize, usize), state: S) -> Option<C> {
+        if !self.contains(index) {
+            return None;
+        }
+        self.dist[self.node(index, state)]
+    }
+    /// The smallest distance of the cell in any state.
+    pub fn min(&self, index: (usize, usize)) -> Option<C> {
+        if !self.contains(index) {
+            return None;
+        }
+        let first = self.cell(index);
+        self.dist[first..first + S::COUNT]
+            .iter()
+            .flatten()
+            .min()
+            .copied()
+    }
+    /// The steps from a start to the cell in the state, both included.
+    /// Empty if it wasn't reached.
+    pub fn path(&self, index: (usize, usize), state: S) -> Vec<Node<S>> {
+        if self.get(index, state).is_none() {
+            return vec![];
+        }
+        let mut path = vec![(index, state)];
+        let mut current = (index, state);
+        while let Some(prev) = self.prev[self.node(current.0, current.1)] {
+            path.push(prev);
+            current = prev;
+        }
+        path.reverse();
+        path
+    }
+    /// The smallest distance of every cell.
+    pub fn to_grid(&self) -> DenseGrid<Option<C>> {
+        DenseGrid::from_iter(
+            self.dist
+                .chunks(S::COUNT)
+                .map(|states| states.iter().flatten().min().copied()),
+            self.width,
+        )
+    }
+}
+
+/// Breadth-first search over the 4 neighbours, `passable(cell, neighbour)` decides if a step is
+/// possible.
+pub fn bfs<G, T, I, F>(grid: &G, starts: I, passable: F) -> Distances<(), usize>
+where
+    G: Grid<T>,
+    I: IntoIterator<Item = (usize, usize)>,
+    F: Fn(&T, &T) -> bool,
+{
+    let cost = |cell: &T, neighbour: &T| passable(cell, neighbour).then_some(());
+    let cost = &cost;
+    bfs_with_state(grid, starts.into_iter().map(|s| (s, ())), |index, ()| {
+        moves(grid, index, cost)
+    })
+}
+/// Breadth-first search where `successors(cell, state)` returns the next steps.
+/// Starts and steps outside of the grid are skipped.
+pub fn bfs_with_state<G, T, S, I, F, N>(
+    grid: &G,
+    starts: I,
+    mut successors: F,
+) -> Distances<S, usize>
+where
+    G: Grid<T>,
+    S: SearchState,
+    I: IntoIterator<Item = Node<S>>,
+    F: FnMut((usize, usize), S) -> N,
+    N: IntoIterator<Item = Node<S>>,
+{
+    let mut distances = Distances::new(grid);
+    let mut queue = VecDeque::new();
+    for (index, state) in starts {
+        if !distances.contains(index) {
+            continue;
+        }
+        let node = distances.node(index, state);
+        if distances.dist[node].is_none() {
+            distances.dist[node] = Some(0);
+            queue.push_back((index, state, 0));
+        }
+    }
+    while let Some((index, state, dist)) = queue.pop_front() {
+        for (next, next_state) in successors(index, state) {
+            if !distances.contains(next) {
+                continue;
+            }
+            let node = distances.node(next, next_state);
+            if distances.dist[node].is_none() {
+                distances.dist[node] = Some(dist + 1);
+                distances.prev[node] = Some((index, state));
+                queue.push_back((next, next_state, dist + 1));
+            }
+        }
+    }
+    distances
+}
+/// Dijkstra over the 4 neighbours, `cost(cell, neighbour)` is the cost of a step or `None` if
+/// it isn't possible.
+pub fn dijkstra<G, T, I, C, F>(grid: &G, starts: I, cost: F) -> Distances<(), C>
+where
+    G: Grid<T>,
+    I: IntoIterator<Item = (usize, usize)>,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: Fn(&T, &T) -> Option<C>,
+{
+    let cost = &cost;
+    dijkstra_with_state(grid, starts.into_iter().map(|s| (s, ())), |index, ()| {
+        moves(grid, index, cost).map(|(next, c)| (next, (), c))
+    })
+}
+/// Dijkstra where `successors(cell, state)` returns the next steps with their costs.
+/// Starts and steps outside of the grid are skipped.
+pub fn dijkstra_with_state<G, T, S, I, C, F, N>(
+    grid: &G,
+    starts: I,
+    successors: F,
+) -> Distances<S, C>
+where
+    G: Grid<T>,
+    S: SearchState,
+    I: IntoIterator<Item = Node<S>>,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut((usize, usize), S) -> N,
+    N: IntoIterator<Item = ((usize, usize), S, C)>,
+{
+    search(grid, starts, successors, |_, _| C::default(), |_, _| false).0
+}
+/// A* over the 4 neighbours from `start` to `goal`, see [`dijkstra`]. The `heuristic` must never
+/// overestimate the remaining cost. Returns the cost and the cells of the path.
+pub fn astar<G, T, C, F, H>(
+    grid: &G,
+    start: (usize, usize),
+    goal: (usize, usize),
+    cost: F,
+    heuristic: H,
+) -> Option<(C, Vec<(usize, usize)>)>
+where
+    G: Grid<T>,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: Fn(&T, &T) -> Option<C>,
+    H: Fn((usize, usize)) -> C,
+{
+    let cost = &cost;
+    let (dist, path) = astar_with_state(
+        grid,
+        [(start, ())],
+        |index, ()| moves(grid, index, cost).map(|(next, c)| (next, (), c)),
+        |index, ()| heuristic(index),
+        |index, ()| index == goal,
+    )?;
+    Some((dist, path.into_iter().map(|(index, ())| index).collect()))
+}
+/// A* until `goal(cell, state)` holds, see [`dijkstra_with_state`] and [`astar`].
+pub fn astar_with_state<G, T, S, I, C, F, N, H, E>(
+    grid: &G,
+    starts: I,
+    successors: F,
+    heuristic: H,
+    goal: E,
+) -> Option<(C, Vec<Node<S>>)>
+where
+    G: Grid<T>,
+    S: SearchState,
+    I: IntoIterator<Item = Node<S>>,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut((usize, usize), S) -> N,
+    N: IntoIterator<Item = ((usize, usize), S, C)>,
+    H: Fn((usize, usize), S) -> C,
+    E: Fn((usize, usize), S) -> bool,
+{
+    let (distances, Some((index, state))) = search(grid, starts, successors, heuristic, goal)
+    else {
+        return None;
+    };
+    Some((distances.get(index, state)?, distances.path(index, state)))
+}
+/// The reachable neighbours of the cell with the cost of the step.
+fn moves<'a, G, T, C, F>(
+    grid: &'a G,
+    index: (usize, usize),
+    cost: &'a F,
+) -> impl Iterator<Item = ((usize, usize), C)> + use<'a, G, T, C, F>
+where
+    G: Grid<T>,
+    T: 'a,
+    F: Fn(&T, &T) -> Option<C>,
+{
+    grid.get(index).into_iter().flat_map(move |cell| {
+        grid.get_neigbors4(index)
+            .filter_map(move |(next, neighbour)| {
+                Some((next.to_coordinates(grid), cost(cell, neighbour)?))
+            })
+    })
+}
+/// Dijkstra, or A* with a heuristic, until the first `(cell, state)` for which `goal` holds.
+fn search<G, T, S, I, C, F, N, H, E>(
+    grid: &G,
+    starts: I,
+    mut successors: F,
+    heuristic: H,
+    goal: E,
+) -> (Distances<S, C>, Option<Node<S>>)
+where
+    G: Grid<T>,
+    S: SearchState,
+    I: IntoIterator<Item = Node<S>>,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut((usize, usize), S) -> N,
+    N: IntoIterator<Item = ((usize, usize), S, C)>,
+    H: Fn((usize, usize), S) -> C,
+    E: Fn((usize, usize), S) -> bool,
+{
+    let mut distances = Distances::new(grid);
+    let mut heap = BinaryHeap::new();
+    for (index, state) in starts {
+        if !distances.contains(index) {
+            continue;
+        }
+        let node = distances.node(index, state);
+        distances.dist[node] = Some(C::default());
+        heap.push(Entry {
+            priority: heuristic(index, state),
+            cost: C::default(),
+            index,
+            state,
+        });
+    }
+    while let Some(Entry {
+        cost, index, state, ..
+    }) = heap.pop()
+    {
+        if distances.dist[distances.node(index, state)].is_some_and(|dist| dist < cost) {
+            continue;
+        }
+        if goal(index, state) {
+            return (distances, Some((index, state)));
+        }
+        for (next, next_state, step) in successors(index, state) {
+            if !distances.contains(next) {
+                continue;
+            }
+            let next_cost = cost + step;
+            let node = distances.node(next, next_state);
+            if distances.dist[node].is_none_or(|dist| next_cost < dist) {
+                distances.dist[node] = Some(next_cost);
+                distances.prev[node] = Some((index, state));
+                heap.push(Entry {
+                    priority: next_cost + heuristic(next, next_state),
+                    cost: next_cost,
+                    index: next,
+                    state: next_state,
+                });
+            }
+        }
+    }
+    (distances, None)
+}
+/// An entry of the priority queue, the smallest priority is popped first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    index: (usize, usize),
+    state: S,
+}
+impl<S, C> PartialEq for Entry<S, C>
+where
+    C: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+impl<S, C> Eq for Entry<S, C> where C: Ord {}
+impl<S, C> Ord for Entry<S, C>
+where
+    C: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+impl<S, C> PartialOrd for Entry<S, C>
+where
+    C: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze() -> DenseGrid<char> {
+        DenseGrid::from_string("S.#\n..#\n#..")
+    }
+    fn costs() -> DenseGrid<u32> {
+        DenseGrid::from_iter([1, 9, 1, 1, 1, 1, 9, 9, 1].into_iter(), 3)
+    }
+    /// Turning costs 10, so going straight along the edge is cheaper.
+    /// Steps over the right and bottom edge are left to the search.
+    fn turns(
+        index: (usize, usize),
+        dir: Direction4,
+    ) -> impl Iterator<Item = ((usize, usize), Direction4, u32)> {
+        [dir, dir.turn_left(), dir.turn_right()]
+            .into_iter()
+            .filter_map(move |d| {
+                let next = match d {
+                    Direction4::North => (index.0.checked_sub(1)?, index.1),
+                    Direction4::East => (index.0, index.1 + 1),
+                    Direction4::South => (index.0 + 1, index.1),
+                    Direction4::West => (index.0, index.1.checked_sub(1)?),
+                };
+                Some((next, d, if d == dir { 1 } else { 11 }))
+            })
+    }
+    #[test]
+    fn test_bfs() {
+        let distances = bfs(&maze(), [(0, 0)], |_, n| *n != '#');
+        assert_eq!(distances.get((2, 2), ()), Some(4));
+        assert_eq!(distances.get((0, 2), ()), None);
+        assert_eq!(distances.get((5, 0), ()), None);
+        assert_eq!(distances.path((2, 1), ()).len(), 4);
+        assert_eq!(
+            distances.to_grid().iter().filter(|d| d.is_some()).count(),
+            6
+        );
+    }
+    #[test]
+    fn test_dijkstra() {
+        let distances = dijkstra(&costs(), [(0, 0)], |_, n| Some(*n));
+        assert_eq!(distances.min((0, 2)), Some(4));
+        assert_eq!(distances.min((2, 2)), Some(4));
+        assert_eq!(distances.min((0, 3)), None);
+    }
+    #[test]
+    fn test_astar() {
+        let (dist, path) = astar(
+            &costs(),
+            (0, 0),
+            (2, 2),
+            |_, n| Some(*n),
+            |(y, x)| (2 - y + 2 - x).try_into().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(dist, 4);
+        assert_eq!(path, [(0, 0), (1, 0), (1, 1), (1, 2), (2, 2)]);
+    }
+    #[test]
+    fn test_dijkstra_with_state() {
+        let grid = DenseGrid::<char>::from_string("...\n...\n...");
+        let distances = dijkstra_with_state(&grid, [((0, 0), Direction4::East)], turns);
+        assert_eq!(distances.min((2, 2)), Some(14));
+        assert_eq!(distances.get((2, 2), Direction4::South), Some(14));
+        assert_eq!(distances.get((2, 2), Direction4::East), Some(24));
+    }
+    #[test]
+    fn test_successors_outside() {
+        let grid = DenseGrid::<char>::from_string("..\n..");
+        let distances = bfs_with_state(&grid, [((0, 0), ()), ((0, 9), ())], |(y, x), ()| {
+            [((y, x + 1), ()), ((y + 1, x), ())]
+        });
+        assert_eq!(distances.get((1, 1), ()), Some(2));
+        assert_eq!(distances.to_grid().iter().flatten().count(), 4);
+        let goal = astar_with_state(
+            &grid,
+            [((0, 0), Direction4::East)],
+            turns,
+            |_, _| 0,
+            |index, _| index == (1, 1),
+        );
+        assert_eq!(goal.map(|(dist, _)| dist), Some(12));
+    }
+}
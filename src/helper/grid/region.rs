@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+
+use super::{Grid, index::GridIndex};
+
+/// Which cells count as neighbours of a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// North, East, South and West.
+    Four,
+    /// Also the diagonals.
+    Eight,
+}
+/// A connected set of cells, as `(y, x)` coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub cells: HashSet<(usize, usize)>,
+}
+/// Every region of a grid, see [`label_regions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    pub regions: Vec<Region>,
+    /// The index into `regions` for every flat index of the grid, `None` for unset cells.
+    pub labels: Vec<Option<usize>>,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn contains(&self, index: (usize, usize)) -> bool {
+        self.cells.contains(&index)
+    }
+    /// The number of cell edges between the region and everything else, including the
+    /// border of the grid.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&(y, x)| {
+                [
+                    (y.wrapping_sub(1), x),
+                    (y, x + 1),
+                    (y + 1, x),
+                    (y, x.wrapping_sub(1)),
+                ]
+                .into_iter()
+                .filter(|n| !self.contains(*n))
+                .count()
+            })
+            .sum()
+    }
+    /// The number of corners of the outline, which is also the number of straight sides.
+    /// Holes count as well.
+    pub fn sides(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&(y, x)| {
+                let (north, south) = (y.wrapping_sub(1), y + 1);
+                let (west, east) = (x.wrapping_sub(1), x + 1);
+                [(north, east), (north, west), (south, east), (south, west)]
+                    .into_iter()
+                    .filter(|&(dy, dx)| {
+                        let vertical = self.contains((dy, x));
+                        let horizontal = self.contains((y, dx));
+                        // an outer corner or an inner corner of a concave bend
+                        (!vertical && !horizontal)
+                            || (vertical && horizontal && !self.contains((dy, dx)))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+    /// The smallest and the largest corner as `(y, x)`, both inclusive.
+    pub fn bounding_box(&self) -> ((usize, usize), (usize, usize)) {
+        self.cells.iter().fold(
+            ((usize::MAX, usize::MAX), (0, 0)),
+            |((min_y, min_x), (max_y, max_x)), &(y, x)| {
+                ((min_y.min(y), min_x.min(x)), (max_y.max(y), max_x.max(x)))
+            },
+        )
+    }
+}
+impl Regions {
+    /// The region the cell belongs to.
+    pub fn region_of<G, T, I>(&self, grid: &G, index: I) -> Option<&Region>
+    where
+        G: Grid<T>,
+        I: GridIndex<T>,
+    {
+        let label = (*self.labels.get(index.to_flat_index(grid))?)?;
+        self.regions.get(label)
+    }
+}
+
+/// The region around `start`. A neighbour belongs to it if `connected(cell, neighbour)` holds.
+pub fn flood_fill<G, T, I, F>(
+    grid: &G,
+    start: I,
+    connectivity: Connectivity,
+    connected: F,
+) -> Region
+where
+    G: Grid<T>,
+    I: GridIndex<T>,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut cells = HashSet::new();
+    let mut stack = vec![start.to_coordinates(grid)];
+    while let Some(index) = stack.pop() {
+        let Some(cell) = grid.get(index) else {
+            continue;
+        };
+        if !cells.insert(index) {
+            continue;
+        }
+        let mut visit = |i: (usize, usize), neighbour: &T| {
+            if !cells.contains(&i) && connected(cell, neighbour) {
+                stack.push(i);
+            }
+        };
+        match connectivity {
+            Connectivity::Four => {
+                for (i, n) in grid.get_neigbors4(index) {
+                    visit(i.to_coordinates(grid), n);
+                }
+            }
+            Connectivity::Eight => {
+                for (i, n) in grid.get_neigbors8(index) {
+                    visit(i.to_coordinates(grid), n);
+                }
+            }
+        }
+    }
+    Region { cells }
+}
+
+/// Splits the set cells of the grid into regions in one pass, see [`flood_fill`].
+/// The regions are ordered by their first cell in the order of [`Grid::all_indices`].
+pub fn label_regions<G, T, F>(grid: &G, connectivity: Connectivity, connected: F) -> Regions
+where
+    G: Grid<T>,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut labels = vec![None; grid.width() * grid.height()];
+    let mut regions = vec![];
+    for index in grid.all_indices() {
+        if labels[index.to_flat_index(grid)].is_some() {
+            continue;
+        }
+        let region = flood_fill(grid, index, connectivity, &connected);
+        for cell in &region.cells {
+            labels[cell.to_flat_index(grid)] = Some(regions.len());
+        }
+        regions.push(region);
+    }
+    Regions { regions, labels }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::grid::dense::DenseGrid;
+
+    /// The garden of the first example of 2024 day 12.
+    fn garden() -> DenseGrid<char> {
+        DenseGrid::from_string("AAAA\nBBCD\nBBCC\nEEEC")
+    }
+    fn diagonal() -> DenseGrid<char> {
+        DenseGrid::from_string("#..\n.#.\n..#")
+    }
+    #[test]
+    fn test_label_regions() {
+        let grid = garden();
+        let regions = label_regions(&grid, Connectivity::Four, |a, b| a == b);
+        assert_eq!(regions.regions.len(), 5);
+        let dots = label_regions(&diagonal(), Connectivity::Four, |a, b| a == b);
+        assert_eq!(dots.regions.len(), 5);
+    }
+    #[test]
+    fn test_perimeter_and_sides() {
+        let regions = label_regions(&garden(), Connectivity::Four, |a, b| a == b);
+        let price = |cost: fn(&Region) -> usize| {
+            regions
+                .regions
+                .iter()
+                .map(|r| r.area() * cost(r))
+                .sum::<usize>()
+        };
+        assert_eq!(price(Region::perimeter), 140);
+        assert_eq!(price(Region::sides), 80);
+    }
+    #[test]
+    fn test_region_of() {
+        let grid = garden();
+        let regions = label_regions(&grid, Connectivity::Four, |a, b| a == b);
+        let c = regions.region_of(&grid, (1, 2)).unwrap();
+        assert_eq!(c.area(), 4);
+        assert_eq!(c.bounding_box(), ((1, 2), (3, 3)));
+    }
+    #[test]
+    fn test_flood_fill_connectivity() {
+        let grid = diagonal();
+        let four = flood_fill(&grid, (0, 0), Connectivity::Four, |a, b| a == b);
+        assert_eq!(four.area(), 1);
+        let eight = flood_fill(&grid, (0, 0), Connectivity::Eight, |a, b| a == b);
+        assert_eq!(eight.area(), 3);
+    }
+}
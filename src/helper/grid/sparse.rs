@@ -1,5 +1,6 @@
 use core::{
     fmt::Debug,
+    hash::{Hash, Hasher},
     ops::{Index, IndexMut},
 };
 use std::collections::{
@@ -7,7 +8,7 @@ use std::collections::{
     hash_map::{IntoValues, Iter},
 };
 
-use super::{Grid, index::GridIndex};
+use super::{Grid, GridMut, index::GridIndex};
 #[derive(Clone, PartialEq, Eq)]
 #[expect(clippy::module_name_repetitions, reason = "makes more sense")]
 pub struct SparseGrid<T> {
@@ -64,7 +65,11 @@ where
     fn all_indices(&self) -> impl Iterator<Item = impl GridIndex<T>> {
         self.data.keys().copied()
     }
-
+}
+impl<T> GridMut<T> for SparseGrid<T>
+where
+    T: Clone + Debug,
+{
     fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
     where
         T: 'a,
@@ -99,6 +104,24 @@ where
     pub fn iter_all(&self) -> Iter<'_, usize, T> {
         self.data.iter()
     }
+    /// Unsets every cell.
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+}
+/// Hashes the cells in the order of their indices, so that equal grids have equal hashes.
+impl<T> Hash for SparseGrid<T>
+where
+    T: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        let mut cells = self.data.iter().collect::<Vec<_>>();
+        cells.sort_unstable_by_key(|(i, _)| **i);
+        (self.width, self.height, cells).hash(state);
+    }
 }
 
 impl<T> Index<usize> for SparseGrid<T> {
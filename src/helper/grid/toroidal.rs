@@ -0,0 +1,169 @@
+use core::{marker::PhantomData, ops::Index};
+
+use super::{Grid, step};
+use crate::helper::position::{Direction8, Position};
+
+/// A view of a grid that repeats it infinitely in every direction, so that every signed
+/// position is valid.
+///
+/// The copy a position lies in is its [`Toroidal::tile`], the original grid is tile `(0, 0)`.
+#[derive(Debug)]
+pub struct Toroidal<'a, G, T> {
+    grid: &'a G,
+    cell: PhantomData<T>,
+}
+// derived, these would require `G: Clone` and `T: Clone` although only a reference is copied
+impl<G, T> Clone for Toroidal<'_, G, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<G, T> Copy for Toroidal<'_, G, T> {}
+impl<'a, G, T> Toroidal<'a, G, T>
+where
+    G: Grid<T>,
+    T: 'a,
+{
+    /// `None` if the grid is empty, because then no position can be wrapped into it.
+    pub fn new(grid: &'a G) -> Option<Self> {
+        (grid.width() > 0 && grid.height() > 0).then_some(Self {
+            grid,
+            cell: PhantomData,
+        })
+    }
+    pub const fn inner(self) -> &'a G {
+        self.grid
+    }
+    /// The position in the original grid.
+    pub fn wrap(self, pos: Position<isize>) -> Position<usize> {
+        Position {
+            x: pos.x.rem_euclid(self.width()).cast_unsigned(),
+            y: pos.y.rem_euclid(self.height()).cast_unsigned(),
+        }
+    }
+    /// Which copy of the grid the position lies in.
+    pub fn tile(self, pos: Position<isize>) -> Position<isize> {
+        Position {
+            x: pos.x.div_euclid(self.width()),
+            y: pos.y.div_euclid(self.height()),
+        }
+    }
+    pub fn get(self, pos: Position<isize>) -> &'a T {
+        self.grid
+            .get(self.wrap(pos))
+            .expect("a wrapped position is always in the grid")
+    }
+    pub fn get_dir8(self, pos: Position<isize>, dir: Direction8) -> (Position<isize>, &'a T) {
+        let next = step(pos, dir);
+        (next, self.get(next))
+    }
+    /// returns the neigbors clockwise starting from the North, without wrapping their positions.
+    pub fn get_neigbors4(
+        self,
+        pos: Position<isize>,
+    ) -> impl Iterator<Item = (Position<isize>, &'a T)> {
+        [
+            Direction8::North,
+            Direction8::East,
+            Direction8::South,
+            Direction8::West,
+        ]
+        .map(|dir| self.get_dir8(pos, dir))
+        .into_iter()
+    }
+    /// returns the neigbors clockwise starting from the North, without wrapping their positions.
+    pub fn get_neigbors8(
+        self,
+        pos: Position<isize>,
+    ) -> impl Iterator<Item = (Position<isize>, &'a T)> {
+        Direction8::all_dirs()
+            .map(|dir| self.get_dir8(pos, dir))
+            .into_iter()
+    }
+    fn width(self) -> isize {
+        self.grid.width().cast_signed()
+    }
+    fn height(self) -> isize {
+        self.grid.height().cast_signed()
+    }
+}
+impl<G, T> Index<Position<isize>> for Toroidal<'_, G, T>
+where
+    G: Grid<T>,
+{
+    type Output = T;
+
+    fn index(&self, index: Position<isize>) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::grid::dense::DenseGrid;
+
+    fn sample() -> DenseGrid<char> {
+        DenseGrid::from_string("ab\ncd\nef")
+    }
+    #[test]
+    fn test_index() {
+        let grid = sample();
+        let torus = Toroidal::new(&grid).unwrap();
+        assert_eq!(torus[Position { x: 2, y: 3 }], 'a');
+        assert_eq!(torus[Position { x: -1, y: -1 }], 'f');
+    }
+    #[test]
+    fn test_wrap() {
+        let grid = sample();
+        let torus = Toroidal::new(&grid).unwrap();
+        assert_eq!(
+            torus.wrap(Position { x: -3, y: 7 }),
+            Position { x: 1, y: 1 }
+        );
+    }
+    #[test]
+    fn test_tile() {
+        let grid = sample();
+        let torus = Toroidal::new(&grid).unwrap();
+        assert_eq!(
+            torus.tile(Position { x: -3, y: 7 }),
+            Position { x: -2, y: 2 }
+        );
+        assert_eq!(torus.tile(Position { x: 1, y: 2 }), Position { x: 0, y: 0 });
+    }
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let torus = Toroidal::new(&grid).unwrap();
+        let neighbours = torus
+            .get_neigbors4(Position { x: 0, y: 0 })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            neighbours,
+            [
+                (Position { x: 0, y: -1 }, &'e'),
+                (Position { x: 1, y: 0 }, &'b'),
+                (Position { x: 0, y: 1 }, &'c'),
+                (Position { x: -1, y: 0 }, &'b'),
+            ]
+        );
+        assert_eq!(torus.get_neigbors8(Position { x: 5, y: 5 }).count(), 8);
+    }
+    #[test]
+    fn test_empty() {
+        let grid = DenseGrid::new(0, 3, '.');
+        assert!(Toroidal::new(&grid).is_none());
+    }
+    #[test]
+    fn test_copy() {
+        // `DenseGrid` isn't `Copy`, the view still is
+        let grid = sample();
+        let torus = Toroidal::new(&grid).unwrap();
+        let copy = torus;
+        assert_eq!(
+            torus[Position { x: 0, y: 0 }],
+            copy[Position { x: 0, y: 0 }]
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use core::{
+    fmt::Debug,
+    ops::{Index, IndexMut},
+};
+
+use super::{Grid as _, dense::DenseGrid};
+
+/// A borrowed rectangle of a [`DenseGrid`]. Its `(0, 0)` is the cell `offset` of the grid.
+pub struct View<'a, T> {
+    grid: &'a DenseGrid<T>,
+    offset: (usize, usize),
+    height: usize,
+    width: usize,
+}
+/// A mutably borrowed rectangle of a [`DenseGrid`]. Its `(0, 0)` is the cell `offset` of the grid.
+#[expect(clippy::module_name_repetitions, reason = "makes more sense")]
+pub struct ViewMut<'a, T> {
+    grid: &'a mut DenseGrid<T>,
+    offset: (usize, usize),
+    height: usize,
+    width: usize,
+}
+/// Panics if the rectangle doesn't fit into the grid.
+fn check_bounds<T>(grid: &DenseGrid<T>, offset: (usize, usize), height: usize, width: usize)
+where
+    T: Clone + Debug,
+{
+    assert!(
+        offset.0 + height <= grid.height() && offset.1 + width <= grid.width(),
+        "view must fit into the grid"
+    );
+}
+impl<'a, T> View<'a, T>
+where
+    T: Clone + Debug,
+{
+    pub fn new(
+        grid: &'a DenseGrid<T>,
+        offset: (usize, usize),
+        height: usize,
+        width: usize,
+    ) -> Self {
+        check_bounds(grid, offset, height, width);
+        Self {
+            grid,
+            offset,
+            height,
+            width,
+        }
+    }
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    /// The index of the top left cell in the grid.
+    pub const fn offset(&self) -> (usize, usize) {
+        self.offset
+    }
+    /// The cell at `(y, x)` relative to the view, `None` outside of it.
+    pub fn get(&self, (y, x): (usize, usize)) -> Option<&'a T> {
+        (y < self.height && x < self.width)
+            .then(|| self.grid.get((self.offset.0 + y, self.offset.1 + x)))
+            .flatten()
+    }
+    /// Panics if the row is outside of the view, like [`DenseGrid::get_row`].
+    pub fn get_row(&self, y: usize) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        assert!(y < self.height, "row must be in the view");
+        self.grid
+            .get_row(self.offset.0 + y)
+            .skip(self.offset.1)
+            .take(self.width)
+    }
+    /// All cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        let (grid, (y, x), width) = (self.grid, self.offset, self.width);
+        (y..y + self.height).flat_map(move |y| grid.get_row(y).skip(x).take(width))
+    }
+    pub fn to_grid(self) -> DenseGrid<T> {
+        DenseGrid::from_iter(self.iter().cloned(), self.width)
+    }
+}
+impl<'a, T> ViewMut<'a, T>
+where
+    T: Clone + Debug,
+{
+    pub fn new(
+        grid: &'a mut DenseGrid<T>,
+        offset: (usize, usize),
+        height: usize,
+        width: usize,
+    ) -> Self {
+        check_bounds(grid, offset, height, width);
+        Self {
+            grid,
+            offset,
+            height,
+            width,
+        }
+    }
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    /// The index of the top left cell in the grid.
+    pub const fn offset(&self) -> (usize, usize) {
+        self.offset
+    }
+    /// The cell at `(y, x)` relative to the view, `None` outside of it.
+    pub fn get(&self, (y, x): (usize, usize)) -> Option<&T> {
+        (y < self.height && x < self.width)
+            .then(|| self.grid.get((self.offset.0 + y, self.offset.1 + x)))
+            .flatten()
+    }
+    pub fn get_mut(&mut self, (y, x): (usize, usize)) -> Option<&mut T> {
+        (y < self.height && x < self.width)
+            .then(|| &mut self.grid[(self.offset.0 + y, self.offset.1 + x)])
+    }
+    /// Sets the cell at `(y, x)` relative to the view, `false` if it is outside of it.
+    pub fn set(&mut self, index: (usize, usize), val: T) -> bool {
+        self.get_mut(index).map(|cell| *cell = val).is_some()
+    }
+    pub fn fill(&mut self, val: &T) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                self[(y, x)].clone_from(val);
+            }
+        }
+    }
+    pub fn as_view(&self) -> View<'_, T> {
+        View::new(self.grid, self.offset, self.height, self.width)
+    }
+}
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for View<'_, T> {}
+impl<T> Index<(usize, usize)> for View<'_, T>
+where
+    T: Clone + Debug,
+{
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+impl<T> Index<(usize, usize)> for ViewMut<'_, T>
+where
+    T: Clone + Debug,
+{
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+impl<T> IndexMut<(usize, usize)> for ViewMut<'_, T>
+where
+    T: Clone + Debug,
+{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(index).expect("Index out of bounds")
+    }
+}
+impl<T> Debug for View<'_, T>
+where
+    T: Clone + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.to_grid().fmt(f)
+    }
+}
+impl<T> Debug for ViewMut<'_, T>
+where
+    T: Clone + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
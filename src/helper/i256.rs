@@ -195,11 +195,7 @@ impl i256 {
         for i in (0..=255).rev() {
             if self.get_bit(i) {
                 i.checked_add(rhs.index_highest_one())?.checked_add(1)?;
-                if let Some(x) = ret.checked_add(rhs.checked_shl(i)?) {
-                    ret = x;
-                } else {
-                    return None;
-                }
+                ret = ret.checked_add(rhs.checked_shl(i)?)?;
             }
         }
         if ret.is_negative() { None } else { Some(ret) }
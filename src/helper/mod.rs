@@ -7,10 +7,13 @@ pub mod intcode;
 pub mod md5;
 pub mod misc;
 pub mod modulo;
+pub mod ocr;
 pub mod parser;
 pub mod permutations;
 pub mod position;
 pub mod position3d;
+pub mod property;
 pub mod rand;
 pub mod range;
 pub mod rational;
+pub mod viz;
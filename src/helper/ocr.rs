@@ -0,0 +1,342 @@
+use core::fmt::Display;
+
+use super::{
+    grid::{Grid, infinite::InfiniteGrid},
+    position::Position,
+};
+
+/// The letters of the 6 pixel high font.
+const SMALL: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+/// The letters of the 10 pixel high font.
+const LARGE: [(char, [&str; 10]); 15] = [
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// A group of pixels that isn't a known letter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// The number of letters in front of it.
+    pub index: usize,
+    /// The pixels, see [`render`].
+    pub glyph: String,
+}
+impl Display for UnknownGlyph {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unknown letter at {}:\n{}", self.index, self.glyph)
+    }
+}
+
+/// Reads the letters of the 6 or 10 pixel high font from the lit pixels.
+/// Empty rows and columns around the text are ignored and the spacing between the letters may
+/// vary, they only need to be on one line.
+pub fn read_grid<G>(grid: &G) -> Result<String, UnknownGlyph>
+where
+    G: Grid<bool>,
+{
+    let lit = |y, x| grid.get((y, x)) == Some(&true);
+    let rows = (0..grid.height())
+        .filter(|&y| (0..grid.width()).any(|x| lit(y, x)))
+        .collect::<Vec<_>>();
+    let (Some(&top), Some(&bottom)) = (rows.first(), rows.last()) else {
+        return Ok(String::new());
+    };
+    let height = bottom - top + 1;
+    // every column as a bitmask with the top row as the lowest bit
+    let columns = (0..grid.width())
+        .map(|x| {
+            (0..height)
+                .filter(|&y| lit(top + y, x))
+                .fold(0_u16, |mask, y| mask | (1 << y))
+        })
+        .collect::<Vec<_>>();
+    let font = match height {
+        6 => glyphs(&SMALL),
+        10 => glyphs(&LARGE),
+        _ => {
+            return Err(UnknownGlyph {
+                index: 0,
+                glyph: render_columns(trim(&columns), height),
+            });
+        }
+    };
+    let mut text = String::new();
+    for segment in columns.split(|c| *c == 0).filter(|s| !s.is_empty()) {
+        let letters = decode(segment, &font).ok_or_else(|| UnknownGlyph {
+            index: text.chars().count(),
+            glyph: render_columns(segment, height),
+        })?;
+        text.push_str(&letters);
+    }
+    Ok(text)
+}
+/// Reads the letters from the lit positions, see [`read_grid`].
+pub fn read_positions<I>(positions: I) -> Result<String, UnknownGlyph>
+where
+    I: IntoIterator<Item = Position<isize>>,
+{
+    let grid = positions
+        .into_iter()
+        .map(|pos| (pos, true))
+        .collect::<InfiniteGrid<_>>();
+    read_grid(&grid.to_dense(false))
+}
+/// The pixels as rows of `#` and `.`, like [`crate::cli::runner::Answer::Grid`].
+pub fn render<G>(grid: &G) -> String
+where
+    G: Grid<bool>,
+{
+    (0..grid.height())
+        .map(|y| {
+            (0..grid.width())
+                .map(|x| {
+                    if grid.get((y, x)) == Some(&true) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+/// The letters of the font as column bitmasks without empty columns at the sides.
+fn glyphs<const N: usize>(font: &[(char, [&str; N])]) -> Vec<(char, Vec<u16>)> {
+    font.iter()
+        .map(|(c, rows)| {
+            let width = rows[0].len();
+            let columns = (0..width)
+                .map(|x| {
+                    (0..N)
+                        .filter(|&y| rows[y].as_bytes()[x] == b'#')
+                        .fold(0_u16, |mask, y| mask | (1 << y))
+                })
+                .collect::<Vec<_>>();
+            (*c, trim(&columns).to_vec())
+        })
+        .collect()
+}
+fn trim(columns: &[u16]) -> &[u16] {
+    let start = columns
+        .iter()
+        .position(|c| *c != 0)
+        .unwrap_or(columns.len());
+    let end = columns
+        .iter()
+        .rposition(|c| *c != 0)
+        .map_or(start, |i| i + 1);
+    &columns[start..end]
+}
+/// Splits columns without a gap into letters, as wide letters can touch the next one.
+fn decode(segment: &[u16], font: &[(char, Vec<u16>)]) -> Option<String> {
+    if segment.is_empty() {
+        return Some(String::new());
+    }
+    font.iter()
+        .filter(|(_, glyph)| segment.starts_with(glyph))
+        .find_map(|(c, glyph)| {
+            let rest = decode(&segment[glyph.len()..], font)?;
+            Some(format!("{c}{rest}"))
+        })
+}
+fn render_columns(columns: &[u16], height: usize) -> String {
+    (0..height)
+        .map(|y| {
+            columns
+                .iter()
+                .map(|c| if (c >> y) & 1 == 1 { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::grid::dense::DenseGrid;
+
+    fn grid(rows: &[&str]) -> DenseGrid<bool> {
+        DenseGrid::from_iter(
+            rows.iter().flat_map(|r| r.chars().map(|c| c == '#')),
+            rows[0].len(),
+        )
+    }
+
+    #[test]
+    fn test_read_small() {
+        let letters = [6, 3, 5, 16, 11].map(|i| SMALL[i].1);
+        // the Y touches the O
+        let mut small = (0..6)
+            .map(|r| {
+                format!(
+                    "..{}.{}.{}..{}{}",
+                    letters[0][r], letters[1][r], letters[2][r], letters[3][r], letters[4][r]
+                )
+            })
+            .collect::<Vec<_>>();
+        small.insert(0, ".".repeat(small[0].len()));
+        let small = small.iter().map(String::as_str).collect::<Vec<_>>();
+        assert_eq!(read_grid(&grid(&small)).unwrap(), "HEGYO");
+    }
+    #[test]
+    fn test_read_large() {
+        let large = (0..10)
+            .map(|r| format!("{}..{}", LARGE[1].1[r], LARGE[13].1[r]))
+            .collect::<Vec<_>>();
+        let large = large.iter().map(String::as_str).collect::<Vec<_>>();
+        assert_eq!(read_grid(&grid(&large)).unwrap(), "BX");
+    }
+    #[test]
+    fn test_unknown_glyph() {
+        let unknown = [
+            "#..#.#.#", "#..#.#.#", "####.###", "#..#...#", "#..#...#", "#..#...#",
+        ];
+        let err = read_grid(&grid(&unknown)).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(err.glyph, "#.#\n#.#\n###\n..#\n..#\n..#");
+    }
+    #[test]
+    fn test_read_positions() {
+        let positions = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (0, 5),
+            (1, 5),
+            (2, 5),
+            (3, 5),
+        ];
+        let positions = positions.map(|(x, y)| Position { x: x - 7, y: y + 3 });
+        assert_eq!(read_positions(positions).unwrap(), "L");
+    }
+    #[test]
+    fn test_render() {
+        assert_eq!(render(&grid(&["#.", ".#"])), "#.\n.#");
+    }
+}
@@ -0,0 +1,413 @@
+//! A small property testing harness.
+//!
+//! Random inputs are generated from a seeded [`Rng`], so every failure can be reproduced.
+//! A failing input is shrunk to a minimal one before the test panics.
+//! The seed can be changed with the `PROPTEST_SEED` environment variable.
+//! ```
+//! use all_aoc::helper::property::Config;
+//!
+//! Config::default().agree(
+//!     |v: &Vec<u8>| v.iter().map(|&x| u32::from(x)).sum::<u32>(),
+//!     |v: &Vec<u8>| v.iter().fold(0, |acc, &x| acc + u32::from(x)),
+//! );
+//! ```
+use core::fmt::Debug;
+
+use crate::helper::{
+    grid::{Grid as _, dense::DenseGrid},
+    rand::Rng,
+};
+
+/// Produces smaller versions of a value, the simplest candidates first.
+pub trait Shrink: Sized {
+    fn shrink(&self) -> Vec<Self>;
+}
+/// A type that can be generated randomly.
+/// `size` grows during a run and bounds lengths and magnitudes.
+pub trait Arbitrary: Shrink {
+    fn arbitrary(rng: &mut Rng, size: usize) -> Self;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Shrink for $t {
+                fn shrink(&self) -> Vec<Self> {
+                    let mut ret = vec![0, self / 2, self.saturating_sub(1)];
+                    ret.dedup();
+                    ret.retain(|x| x < self);
+                    ret
+                }
+            }
+            impl Arbitrary for $t {
+                fn arbitrary(rng: &mut Rng, size: usize) -> Self {
+                    let bound = u64::try_from(size).unwrap().saturating_add(1);
+                    let max = u64::try_from(<$t>::MAX).unwrap_or(u64::MAX);
+                    <$t>::try_from(rng.below(bound).min(max)).unwrap()
+                }
+            }
+        )*
+    };
+}
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Shrink for $t {
+                fn shrink(&self) -> Vec<Self> {
+                    let mut ret = vec![0, self.saturating_abs(), self / 2, self - self.signum()];
+                    ret.dedup();
+                    ret.retain(|x| x.unsigned_abs() < self.unsigned_abs() || (*x > 0 && -x == *self));
+                    ret
+                }
+            }
+            impl Arbitrary for $t {
+                fn arbitrary(rng: &mut Rng, size: usize) -> Self {
+                    let max = u64::try_from(<$t>::MAX).unwrap();
+                    let abs = <$t>::try_from(u64::arbitrary(rng, size).min(max)).unwrap();
+                    if rng.bool() { -abs } else { abs }
+                }
+            }
+        )*
+    };
+}
+impl_unsigned!(u8, u16, u32, u64, usize);
+impl_signed!(i8, i16, i32, i64, isize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self { vec![false] } else { vec![] }
+    }
+}
+impl Arbitrary for bool {
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        rng.bool()
+    }
+}
+impl Shrink for char {
+    fn shrink(&self) -> Vec<Self> {
+        if *self == 'a' { vec![] } else { vec!['a'] }
+    }
+}
+impl Arbitrary for char {
+    /// A lowercase ascii letter, like most identifiers in the puzzles.
+    fn arbitrary(rng: &mut Rng, _: usize) -> Self {
+        Self::from(b'a' + u8::try_from(rng.below(26)).unwrap())
+    }
+}
+impl<T> Shrink for Vec<T>
+where
+    T: Shrink + Clone,
+{
+    fn shrink(&self) -> Vec<Self> {
+        let mut ret = vec![];
+        if self.is_empty() {
+            return ret;
+        }
+        ret.push(vec![]);
+        if self.len() > 2 {
+            let (a, b) = self.split_at(self.len() / 2);
+            ret.push(a.to_vec());
+            ret.push(b.to_vec());
+        }
+        for i in 0..self.len() {
+            let mut v = self.clone();
+            v.remove(i);
+            ret.push(v);
+        }
+        for (i, x) in self.iter().enumerate() {
+            for s in x.shrink() {
+                let mut v = self.clone();
+                v[i] = s;
+                ret.push(v);
+            }
+        }
+        ret
+    }
+}
+impl<T> Arbitrary for Vec<T>
+where
+    T: Arbitrary + Clone,
+{
+    fn arbitrary(rng: &mut Rng, size: usize) -> Self {
+        let len = rng.range(0..size + 1);
+        core::iter::repeat_with(|| T::arbitrary(rng, size))
+            .take(len)
+            .collect()
+    }
+}
+impl Shrink for String {
+    /// Only removes characters, so the remaining ones keep their meaning.
+    fn shrink(&self) -> Vec<Self> {
+        let chars = self.chars().collect::<Vec<_>>();
+        if chars.is_empty() {
+            return vec![];
+        }
+        let mut ret = vec![Self::new()];
+        for i in 0..chars.len() {
+            let mut c = chars.clone();
+            c.remove(i);
+            ret.push(c.into_iter().collect());
+        }
+        ret
+    }
+}
+impl Arbitrary for String {
+    fn arbitrary(rng: &mut Rng, size: usize) -> Self {
+        let len = rng.range(0..size + 1);
+        core::iter::repeat_with(|| char::arbitrary(rng, size))
+            .take(len)
+            .collect()
+    }
+}
+impl<A, B> Shrink for (A, B)
+where
+    A: Shrink + Clone,
+    B: Shrink + Clone,
+{
+    fn shrink(&self) -> Vec<Self> {
+        self.0
+            .shrink()
+            .into_iter()
+            .map(|a| (a, self.1.clone()))
+            .chain(self.1.shrink().into_iter().map(|b| (self.0.clone(), b)))
+            .collect()
+    }
+}
+impl<A, B> Arbitrary for (A, B)
+where
+    A: Arbitrary + Clone,
+    B: Arbitrary + Clone,
+{
+    fn arbitrary(rng: &mut Rng, size: usize) -> Self {
+        (A::arbitrary(rng, size), B::arbitrary(rng, size))
+    }
+}
+impl<T> Shrink for DenseGrid<T>
+where
+    T: Shrink + Clone + Debug,
+{
+    /// Removes single rows and columns first, then shrinks single cells.
+    fn shrink(&self) -> Vec<Self> {
+        let mut ret = vec![];
+        if self.height() > 1 {
+            for skip in 0..self.height() {
+                ret.push(Self::from_iter_iter(
+                    (0..self.height())
+                        .filter(|y| *y != skip)
+                        .map(|y| self.get_row(y).cloned()),
+                ));
+            }
+        }
+        if self.width() > 1 {
+            for skip in 0..self.width() {
+                let mut grid = self.clone();
+                grid.remove_col(skip);
+                ret.push(grid);
+            }
+        }
+        for i in 0..self.len() {
+            for s in self[i].shrink() {
+                let mut grid = self.clone();
+                grid[i] = s;
+                ret.push(grid);
+            }
+        }
+        ret
+    }
+}
+impl<T> Arbitrary for DenseGrid<T>
+where
+    T: Arbitrary + Clone + Debug,
+{
+    fn arbitrary(rng: &mut Rng, size: usize) -> Self {
+        let width = rng.range(1..size + 2);
+        let height = rng.range(1..size + 2);
+        Self::from_iter(
+            core::iter::repeat_with(|| T::arbitrary(rng, size)).take(width * height),
+            width,
+        )
+    }
+}
+
+/// How a property is checked.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub seed: u64,
+    /// How many random inputs are tried.
+    pub cases: usize,
+    /// The size passed to the generator grows linearly up to this value.
+    pub max_size: usize,
+    /// How many shrink candidates are tried at most.
+    pub max_shrinks: usize,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: std::env::var("PROPTEST_SEED")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0x00AD_FC0D_E2E5),
+            cases: 1000,
+            max_size: 16,
+            max_shrinks: 10_000,
+        }
+    }
+}
+impl Config {
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+    #[must_use]
+    pub const fn with_cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+    #[must_use]
+    pub const fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+    /// Checks the property for random values of `T`.
+    pub fn check<T, P>(&self, prop: P)
+    where
+        T: Arbitrary + Clone + Debug,
+        P: Fn(&T) -> bool,
+    {
+        self.check_with(T::arbitrary, prop);
+    }
+    /// Checks the property for values from a custom generator.
+    pub fn check_with<T, G, P>(&self, generator: G, prop: P)
+    where
+        T: Shrink + Clone + Debug,
+        G: Fn(&mut Rng, usize) -> T,
+        P: Fn(&T) -> bool,
+    {
+        if let Some((case, original, minimal)) = self.find_counterexample(generator, &prop) {
+            panic!(
+                "property failed at case {case} with seed {}\noriginal input: {original:?}\nminimal input: {minimal:?}",
+                self.seed
+            );
+        }
+    }
+    /// Checks that both functions return the same value for random values of `T`.
+    /// Typically `fast` is the real solution and `slow` a brute force.
+    pub fn agree<T, R, F, S>(&self, fast: F, slow: S)
+    where
+        T: Arbitrary + Clone + Debug,
+        R: PartialEq + Debug,
+        F: Fn(&T) -> R,
+        S: Fn(&T) -> R,
+    {
+        self.agree_with(T::arbitrary, fast, slow);
+    }
+    /// Like [`Config::agree`] with a custom generator.
+    pub fn agree_with<T, R, G, F, S>(&self, generator: G, fast: F, slow: S)
+    where
+        T: Shrink + Clone + Debug,
+        R: PartialEq + Debug,
+        G: Fn(&mut Rng, usize) -> T,
+        F: Fn(&T) -> R,
+        S: Fn(&T) -> R,
+    {
+        if let Some((case, original, minimal)) =
+            self.find_counterexample(generator, &|x| fast(x) == slow(x))
+        {
+            panic!(
+                "solutions disagree at case {case} with seed {}\noriginal input: {original:?}\nminimal input: {minimal:?}\nfast: {:?}\nslow: {:?}",
+                self.seed,
+                fast(&minimal),
+                slow(&minimal),
+            );
+        }
+    }
+    /// Returns the failing case, the failing input and its shrunk version.
+    fn find_counterexample<T>(
+        &self,
+        generator: impl Fn(&mut Rng, usize) -> T,
+        prop: &impl Fn(&T) -> bool,
+    ) -> Option<(usize, T, T)>
+    where
+        T: Shrink + Clone + Debug,
+    {
+        let mut rng = Rng::new(self.seed);
+        for case in 0..self.cases {
+            let size = 1 + case * self.max_size / self.cases.max(1);
+            let value = generator(&mut rng, size);
+            if !prop(&value) {
+                let minimal = self.shrink(value.clone(), prop);
+                return Some((case, value, minimal));
+            }
+        }
+        None
+    }
+    /// Greedily replaces the value with the first smaller candidate that still fails.
+    fn shrink<T>(&self, value: T, prop: &impl Fn(&T) -> bool) -> T
+    where
+        T: Shrink,
+    {
+        let mut tries = 0;
+        let mut current = value;
+        'outer: loop {
+            for candidate in current.shrink() {
+                if tries >= self.max_shrinks {
+                    break 'outer;
+                }
+                tries += 1;
+                if !prop(&candidate) {
+                    current = candidate;
+                    continue 'outer;
+                }
+            }
+            break;
+        }
+        current
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink_to_minimal() {
+        let config = Config::default();
+        let (_, _, minimal) = config
+            .find_counterexample(Vec::<u32>::arbitrary, &|v: &Vec<u32>| {
+                v.iter().all(|x| *x < 5)
+            })
+            .unwrap();
+        assert_eq!(minimal, vec![5]);
+
+        let (_, _, minimal) = config
+            .find_counterexample(DenseGrid::<bool>::arbitrary, &|g: &DenseGrid<bool>| {
+                g.iter().filter(|b| **b).count() < 2
+            })
+            .unwrap();
+        assert_eq!(minimal.len(), 2);
+    }
+
+    #[test]
+    fn test_same_seed_same_values() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert_eq!(
+            Vec::<i64>::arbitrary(&mut a, 20),
+            Vec::<i64>::arbitrary(&mut b, 20)
+        );
+    }
+
+    #[test]
+    fn test_agree() {
+        Config::default().agree(
+            |v: &Vec<u8>| v.iter().map(|&x| u32::from(x)).sum::<u32>(),
+            |v: &Vec<u8>| v.iter().fold(0, |acc, &x| acc + u32::from(x)),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "minimal input: \"z\"")]
+    fn test_check_panics_with_minimal_input() {
+        Config::default().check(|s: &String| !s.contains('z'));
+    }
+}
@@ -23,3 +23,58 @@ impl<T> Shuffle for [T] {
         shuffle(self);
     }
 }
+/// A small seeded random number generator (`SplitMix64`).
+/// The same seed always produces the same sequence.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+impl Rng {
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    /// A number in `0..n`. `n` must not be zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "range must not be empty");
+        // rejection sampling avoids the modulo bias
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: core::ops::Range<usize>) -> usize {
+        let n = u64::try_from(range.end - range.start).unwrap();
+        range.start + usize::try_from(self.below(n)).unwrap()
+    }
+    pub const fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+    /// `true` with a probability of `numerator / denominator`.
+    pub fn ratio(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            None
+        } else {
+            slice.get(self.range(0..slice.len()))
+        }
+    }
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.range(0..i + 1);
+            slice.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,360 @@
+//! Writes frames as PPM or PNG stills and animated GIFs, without any dependencies.
+//!
+//! Every cell becomes a square of `scale` pixels, highlighted cells are drawn inverted.
+use core::time::Duration;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fs,
+    path::Path,
+};
+
+use super::{Color, Frame};
+
+/// GIF allows at most 12 bit codes, the last one is reserved.
+const MAX_CODE: u16 = 4095;
+/// Pixels per cell used by `solve --viz-out`.
+pub const DEFAULT_SCALE: usize = 4;
+/// The largest prime below `2^16`.
+const ADLER_MOD: u32 = 0xfff1;
+
+/// Writes the frames to `path`, the format is chosen by the extension.
+/// `.gif` gets all frames, `.png` and `.ppm` only the last one.
+pub fn save<P>(path: P, frames: &[Frame], scale: usize, delay: Duration) -> Result<(), String>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let last = frames.last().ok_or("No frames were recorded")?;
+    let bytes = match path.extension().and_then(|e| e.to_str()) {
+        Some("gif") => gif(frames, scale, delay)?,
+        Some("png") => png(last, scale),
+        Some("ppm") => ppm(last, scale),
+        _ => return Err(format!("Unsupported image format: {}", path.display())),
+    };
+    fs::write(path, bytes).map_err(|e| format!("{} can't be written: {e}", path.display()))
+}
+/// The time every frame is shown at the given speed.
+pub fn frame_delay(fps: f64) -> Result<Duration, String> {
+    if !fps.is_finite() || fps <= 0.0 {
+        return Err(format!("Fps has to be a positive number: {fps}"));
+    }
+    Duration::try_from_secs_f64(1.0 / fps).map_err(|e| format!("Invalid fps {fps}: {e}"))
+}
+/// A binary (`P6`) portable pixmap.
+pub fn ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height, pixels) = pixels(frame, scale);
+    let mut bytes = format!("P6\n{width} {height}\n255\n").into_bytes();
+    bytes.extend(pixels.iter().flat_map(|c| [c.r, c.g, c.b]));
+    bytes
+}
+/// An RGB PNG. The image data is stored uncompressed.
+#[expect(clippy::big_endian_bytes, reason = "PNG is big endian")]
+pub fn png(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height, pixels) = pixels(frame, scale);
+    let mut raw = Vec::with_capacity(pixels.len() * 3 + height);
+    for row in pixels.chunks(width.max(1)) {
+        // filter type "none"
+        raw.push(0);
+        raw.extend(row.iter().flat_map(|c| [c.r, c.g, c.b]));
+    }
+    let mut header = Vec::with_capacity(13);
+    header.extend(u32::try_from(width).unwrap().to_be_bytes());
+    header.extend(u32::try_from(height).unwrap().to_be_bytes());
+    // 8 bit depth, truecolour, deflate, default filters, no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut bytes, *b"IHDR", &header);
+    png_chunk(&mut bytes, *b"IDAT", &zlib_stored(&raw));
+    png_chunk(&mut bytes, *b"IEND", &[]);
+    bytes
+}
+/// An endlessly looping GIF. Fails if the frames use more than 256 colours.
+///
+/// The palette is collected from the unscaled frames first, then every frame is scaled and
+/// encoded on its own, so that only one scaled frame is in memory at a time.
+pub fn gif(frames: &[Frame], scale: usize, delay: Duration) -> Result<Vec<u8>, String> {
+    let mut palette: Vec<Color> = vec![];
+    let mut lookup: HashMap<Color, u8> = HashMap::new();
+    for frame in frames {
+        for c in pixels(frame, 1).2 {
+            if let Entry::Vacant(entry) = lookup.entry(c) {
+                entry.insert(
+                    u8::try_from(palette.len())
+                        .map_err(|_| "GIFs support at most 256 colours".to_owned())?,
+                );
+                palette.push(c);
+            }
+        }
+    }
+    // the colour table has 2^bits entries
+    let mut bits = 1;
+    while 1 << bits < palette.len() {
+        bits += 1;
+    }
+    palette.resize(1 << bits, Color::BLACK);
+    let scale = scale.max(1);
+    let width = frames.iter().map(Frame::width).max().unwrap_or_default() * scale;
+    let height = frames.iter().map(Frame::height).max().unwrap_or_default() * scale;
+    let delay = u16::try_from(delay.as_millis() / 10)
+        .unwrap_or(u16::MAX)
+        .max(2);
+
+    let mut bytes = b"GIF89a".to_vec();
+    bytes.extend(gif_u16(width)?);
+    bytes.extend(gif_u16(height)?);
+    bytes.extend([0x80 | ((bits - 1) << 4) | (bits - 1), 0, 0]);
+    bytes.extend(palette.iter().flat_map(|c| [c.r, c.g, c.b]));
+    // loop forever
+    bytes.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+    let min_code_size = bits.max(2);
+    for frame in frames {
+        let (w, h, pixels) = pixels(frame, scale);
+        bytes.extend([0x21, 0xf9, 4, 0]);
+        bytes.extend(delay.to_le_bytes());
+        bytes.extend([0, 0, 0x2c, 0, 0, 0, 0]);
+        bytes.extend(gif_u16(w)?);
+        bytes.extend(gif_u16(h)?);
+        bytes.extend([0, min_code_size]);
+        let indices = pixels.iter().map(|c| lookup[c]).collect::<Vec<_>>();
+        for block in lzw(&indices, min_code_size).chunks(255) {
+            bytes.push(u8::try_from(block.len()).unwrap());
+            bytes.extend(block);
+        }
+        bytes.push(0);
+    }
+    bytes.push(0x3b);
+    Ok(bytes)
+}
+/// The frame as a row major list of pixels, with its width and height.
+fn pixels(frame: &Frame, scale: usize) -> (usize, usize, Vec<Color>) {
+    let scale = scale.max(1);
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let (x, y) = (x / scale, y / scale);
+            let Color { r, g, b } = frame.cell(x, y).color;
+            pixels.push(if frame.is_highlighted(x, y) {
+                Color::new(255 - r, 255 - g, 255 - b)
+            } else {
+                Color::new(r, g, b)
+            });
+        }
+    }
+    (width, height, pixels)
+}
+#[expect(clippy::big_endian_bytes, reason = "PNG is big endian")]
+fn png_chunk(bytes: &mut Vec<u8>, kind: [u8; 4], data: &[u8]) {
+    bytes.extend(u32::try_from(data.len()).unwrap().to_be_bytes());
+    let start = bytes.len();
+    bytes.extend(kind);
+    bytes.extend(data);
+    let crc = crc32(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+/// A zlib stream that only uses uncompressed deflate blocks.
+#[expect(clippy::big_endian_bytes, reason = "the checksum is big endian")]
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x78, 0x01];
+    let mut blocks = data.chunks(usize::from(u16::MAX)).peekable();
+    if blocks.peek().is_none() {
+        bytes.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        bytes.push(u8::from(blocks.peek().is_none()));
+        let len = u16::try_from(block.len()).unwrap();
+        bytes.extend(len.to_le_bytes());
+        bytes.extend((!len).to_le_bytes());
+        bytes.extend(block);
+    }
+    bytes.extend(adler32(data).to_be_bytes());
+    bytes
+}
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(u32::MAX, |crc, b| {
+        (0..8).fold(crc ^ u32::from(*b), |c, _| {
+            if c & 1 == 1 {
+                (c >> 1) ^ 0xedb8_8320
+            } else {
+                c >> 1
+            }
+        })
+    })
+}
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1, 0), |(a, b), x| {
+        let a = (a + u32::from(*x)) % ADLER_MOD;
+        (a, (b + a) % ADLER_MOD)
+    });
+    (b << 16) | a
+}
+fn gif_u16(n: usize) -> Result<[u8; 2], String> {
+    u16::try_from(n)
+        .map(u16::to_le_bytes)
+        .map_err(|_| format!("GIFs can be at most {} pixels wide", u16::MAX))
+}
+/// Collects codes of varying width, least significant bit first.
+#[derive(Debug, Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    len: u8,
+}
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u8) {
+        self.buffer |= u32::from(code) << self.len;
+        self.len += width;
+        while self.len >= 8 {
+            self.bytes.push(self.buffer.to_le_bytes()[0]);
+            self.buffer >>= 8;
+            self.len -= 8;
+        }
+    }
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.buffer.to_le_bytes()[0]);
+        }
+        self.bytes
+    }
+}
+/// GIF flavoured LZW compression of colour indices.
+fn lzw(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1 << min_code_size;
+    let end = clear + 1;
+    let mut out = BitWriter::default();
+    let mut width = min_code_size + 1;
+    let mut last_code = end;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    out.write(clear, width);
+    let Some((first, rest)) = indices.split_first() else {
+        out.write(end, width);
+        return out.finish();
+    };
+    let mut prefix = u16::from(*first);
+    for &k in rest {
+        if let Some(&code) = table.get(&(prefix, k)) {
+            prefix = code;
+            continue;
+        }
+        out.write(prefix, width);
+        last_code += 1;
+        if last_code == 1 << width {
+            width += 1;
+        }
+        if last_code == MAX_CODE {
+            out.write(clear, width);
+            width = min_code_size + 1;
+            last_code = end;
+            table.clear();
+        } else {
+            table.insert((prefix, k), last_code);
+        }
+        prefix = u16::from(k);
+    }
+    out.write(prefix, width);
+    if last_code + 1 == 1 << width {
+        width += 1;
+    }
+    out.write(end, width);
+    out.finish()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::{grid::dense::DenseGrid, rand::Rng, viz::Cell};
+
+    /// Decodes the output of [`lzw`], to check that it round trips.
+    fn unlzw(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1_usize << min_code_size;
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut width = min_code_size + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = vec![];
+        let (mut buffer, mut len, mut bytes) = (0_u32, 0, bytes.iter());
+        loop {
+            while len < width {
+                buffer |= u32::from(*bytes.next().unwrap()) << len;
+                len += 8;
+            }
+            let code = usize::try_from(buffer & ((1 << width) - 1)).unwrap();
+            buffer >>= width;
+            len -= width;
+            if code == clear {
+                table = (0..=255).map(|i| vec![i]).take(clear).collect();
+                // the clear and end codes
+                table.extend([vec![], vec![]]);
+                width = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+            let entry = match (table.get(code), &previous) {
+                (Some(e), _) => e.clone(),
+                (None, Some(p)) => [p.as_slice(), &p[..1]].concat(),
+                (None, None) => panic!("invalid code"),
+            };
+            if let Some(p) = previous {
+                table.push([p.as_slice(), &entry[..1]].concat());
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            out.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        let mut rng = Rng::new(3);
+        for (len, colours) in [(0, 4), (1, 4), (100, 2), (20_000, 4), (20_000, 200)] {
+            let indices = core::iter::repeat_with(|| u8::try_from(rng.below(colours)).unwrap())
+                .take(len)
+                .collect::<Vec<_>>();
+            let bits = if colours > 4 { 8 } else { 2 };
+            assert_eq!(unlzw(&lzw(&indices, bits), bits), indices);
+        }
+    }
+
+    #[test]
+    fn test_frame_delay() {
+        assert_eq!(frame_delay(4.0), Ok(Duration::from_millis(250)));
+        frame_delay(0.0).unwrap_err();
+        frame_delay(-1.0).unwrap_err();
+        frame_delay(f64::NAN).unwrap_err();
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_formats() {
+        let grid = DenseGrid::from_iter([true, false, false, true].into_iter(), 2);
+        let frame = Frame::from_grid(&grid, |b| {
+            Cell::new('#', if *b { Color::WHITE } else { Color::BLACK })
+        });
+        let ppm = ppm(&frame, 2);
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        assert_eq!(&ppm[11..17], &[255, 255, 255, 255, 255, 255]);
+
+        let png = png(&frame, 1);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+
+        let gif = gif(
+            &[frame.clone(), frame.highlight([0])],
+            1,
+            Duration::from_millis(100),
+        )
+        .unwrap();
+        assert!(gif.starts_with(b"GIF89a\x02\x00\x02\x00"));
+        assert_eq!(gif.last(), Some(&0x3b));
+    }
+}
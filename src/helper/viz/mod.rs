@@ -0,0 +1,254 @@
+//! Visualisation of grid simulations.
+//!
+//! A solution records frames with [`record`]. Recording is a no-op unless the
+//! binary runs with `--viz` (`cargo solve 14 --viz`), so the closure that builds
+//! the frame costs nothing in normal runs. With `--viz-out file.gif` the frames
+//! are written to an image instead, see [`export`].
+//! ```
+//! use all_aoc::helper::{grid::dense::DenseGrid, viz::{self, Cell, Color, Frame}};
+//!
+//! let grid = DenseGrid::new(3, 2, false);
+//! viz::record(|| {
+//!     Frame::from_grid(&grid, |b| {
+//!         if *b { Cell::new('#', Color::GREEN) } else { Cell::new('.', Color::GRAY) }
+//!     })
+//! });
+//! ```
+pub mod export;
+pub mod terminal;
+
+use core::{fmt::Debug, ops::Range};
+use std::{
+    collections::HashSet,
+    sync::{Mutex, OnceLock},
+};
+
+use crate::helper::grid::{Grid as _, dense::DenseGrid, sparse::SparseGrid};
+
+/// Frames beyond this limit are dropped, so that long simulations don't run out of memory.
+const MAX_FRAMES: usize = 10_000;
+
+static FRAMES: Mutex<Vec<Frame>> = Mutex::new(Vec::new());
+static ENABLED: OnceLock<bool> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+impl Color {
+    pub const BLACK: Self = Self::new(0, 0, 0);
+    pub const WHITE: Self = Self::new(255, 255, 255);
+    pub const GRAY: Self = Self::new(110, 110, 110);
+    pub const RED: Self = Self::new(220, 50, 47);
+    pub const GREEN: Self = Self::new(60, 200, 80);
+    pub const BLUE: Self = Self::new(38, 139, 210);
+    pub const YELLOW: Self = Self::new(240, 200, 40);
+    pub const BROWN: Self = Self::new(150, 100, 50);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+    /// A colour between `self` (`t = 0`) and `other` (`t = 1`).
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| {
+            let v = f64::from(b).mul_add(t, f64::from(a) * (1.0 - t));
+            #[expect(clippy::cast_possible_truncation, reason = "clamped to 0..=255")]
+            #[expect(clippy::cast_sign_loss, reason = "clamped to 0..=255")]
+            let v = v.round().clamp(0.0, 255.0) as u8;
+            v
+        };
+        Self::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+}
+/// How one grid cell is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+impl Cell {
+    pub const fn new(glyph: char, color: Color) -> Self {
+        Self { glyph, color }
+    }
+}
+/// A snapshot of a grid, already mapped to glyphs and colours.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    highlights: HashSet<usize>,
+    caption: Option<String>,
+}
+impl Frame {
+    pub fn from_grid<T, F>(grid: &DenseGrid<T>, map: F) -> Self
+    where
+        T: Clone + Debug,
+        F: Fn(&T) -> Cell,
+    {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            cells: grid.iter().map(map).collect(),
+            highlights: HashSet::new(),
+            caption: None,
+        }
+    }
+    /// Cells that are not set in the grid are drawn as `background`.
+    pub fn from_sparse<T, F>(grid: &SparseGrid<T>, background: Cell, map: F) -> Self
+    where
+        T: Clone + Debug,
+        F: Fn(&T) -> Cell,
+    {
+        let mut cells = vec![background; grid.width() * grid.height()];
+        for (i, t) in grid.iter_all() {
+            cells[*i] = map(t);
+        }
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            cells,
+            highlights: HashSet::new(),
+            caption: None,
+        }
+    }
+    /// Marks cells (given as flat indices) to be drawn emphasised.
+    #[must_use]
+    pub fn highlight<I>(mut self, indices: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.highlights.extend(indices);
+        self
+    }
+    /// A line of text shown below the frame.
+    #[must_use]
+    pub fn with_caption<S>(mut self, caption: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+    /// The part of the frame inside the ranges, which are clamped to the frame.
+    #[must_use]
+    pub fn crop(&self, x: Range<usize>, y: Range<usize>) -> Self {
+        let x = x.start.min(self.width)..x.end.min(self.width);
+        let y = y.start.min(self.height)..y.end.min(self.height);
+        let width = x.len();
+        let mut cells = Vec::with_capacity(width * y.len());
+        let mut highlights = HashSet::new();
+        for (new_y, old_y) in y.clone().enumerate() {
+            let row = old_y * self.width;
+            cells.extend(&self.cells[row + x.start..row + x.end]);
+            highlights.extend(
+                x.clone()
+                    .enumerate()
+                    .filter(|(_, old_x)| self.highlights.contains(&(row + old_x)))
+                    .map(|(new_x, _)| new_y * width + new_x),
+            );
+        }
+        Self {
+            width,
+            height: y.len(),
+            cells,
+            highlights,
+            caption: self.caption.clone(),
+        }
+    }
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    pub fn caption(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
+    /// The cell at `x`, `y`. Panics if it is out of bounds.
+    pub fn cell(&self, x: usize, y: usize) -> Cell {
+        self.cells[y * self.width + x]
+    }
+    pub fn is_highlighted(&self, x: usize, y: usize) -> bool {
+        self.highlights.contains(&(y * self.width + x))
+    }
+}
+/// Whether the binary runs with `--viz`.
+pub fn enabled() -> bool {
+    *ENABLED.get_or_init(|| std::env::args().any(|x| x == "--viz"))
+}
+/// Records a frame if visualisation is enabled.
+pub fn record<F>(frame: F)
+where
+    F: FnOnce() -> Frame,
+{
+    if !enabled() {
+        return;
+    }
+    let mut frames = FRAMES.lock().unwrap();
+    if frames.len() < MAX_FRAMES {
+        frames.push(frame());
+    }
+}
+/// Removes and returns all recorded frames.
+pub fn take_frames() -> Vec<Frame> {
+    core::mem::take(&mut *FRAMES.lock().unwrap())
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_from_grids() {
+        let grid = DenseGrid::from_iter([1, 2, 3, 4, 5, 6].into_iter(), 3);
+        let frame = Frame::from_grid(&grid, |x| {
+            Cell::new(char::from_digit(*x, 10).unwrap(), Color::WHITE)
+        })
+        .highlight([4]);
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(frame.cell(2, 1).glyph, '6');
+        assert!(frame.is_highlighted(1, 1));
+        assert!(!frame.is_highlighted(1, 0));
+
+        let mut sparse = SparseGrid::new(2, 2);
+        sparse.set((1, 0), 'x');
+        let frame = Frame::from_sparse(&sparse, Cell::new(' ', Color::BLACK), |c| {
+            Cell::new(*c, Color::RED)
+        });
+        assert_eq!(frame.cell(0, 1), Cell::new('x', Color::RED));
+        assert_eq!(frame.cell(1, 1).glyph, ' ');
+    }
+
+    #[test]
+    fn test_crop() {
+        let grid = DenseGrid::from_iter(0..12, 4);
+        let frame = Frame::from_grid(&grid, |x| {
+            Cell::new(char::from_digit(*x, 16).unwrap(), Color::WHITE)
+        })
+        .highlight([6]);
+        let cropped = frame.crop(1..3, 1..10);
+        assert_eq!((cropped.width(), cropped.height()), (2, 2));
+        assert_eq!(cropped.cell(0, 0).glyph, '5');
+        assert_eq!(cropped.cell(1, 1).glyph, 'a');
+        assert!(cropped.is_highlighted(1, 0));
+        assert!(!cropped.is_highlighted(0, 0));
+    }
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.0), Color::BLACK);
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 1.0), Color::WHITE);
+        assert_eq!(
+            Color::BLACK.lerp(Color::WHITE, 0.5),
+            Color::new(128, 128, 128)
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Plays recorded frames as an ANSI animation.
+//!
+//! Keys: `space` pause, `n`/`p` step forward/back, `+`/`-` speed, `q` quit.
+//! If stdin is not a terminal, the frames are played once without controls.
+use core::{fmt::Write as _, time::Duration};
+use std::{
+    io::{self, IsTerminal as _, Read as _, Write as _},
+    process::{Command, Stdio},
+    sync::{
+        Mutex, OnceLock,
+        mpsc::{self, Receiver},
+    },
+    thread,
+};
+
+use super::{Color, Frame};
+
+pub const MIN_FPS: f64 = 0.25;
+pub const MAX_FPS: f64 = 1000.0;
+
+static KEYS: OnceLock<Mutex<Receiver<u8>>> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Player {
+    pub fps: f64,
+}
+impl Default for Player {
+    fn default() -> Self {
+        Self { fps: 10.0 }
+    }
+}
+impl Player {
+    pub fn play(self, frames: &[Frame], title: &str) {
+        let Some(last) = frames.len().checked_sub(1) else {
+            return;
+        };
+        let interactive = io::stdin().is_terminal() && io::stdout().is_terminal();
+        let _raw = interactive.then(RawMode::enable);
+        let keys = interactive.then(|| KEYS.get_or_init(spawn_key_reader));
+        let mut fps = self.fps;
+        let mut paused = false;
+        let mut i = 0;
+        print!("\u{1b}[?25l\u{1b}[2J");
+        loop {
+            let status = format!(
+                "{title}  frame {}/{}  {fps} fps{}",
+                i + 1,
+                frames.len(),
+                if paused { "  [paused]" } else { "" }
+            );
+            print!("\u{1b}[H{}{status}\u{1b}[K", render(&frames[i]));
+            if interactive {
+                print!("\n[space] pause  [n/p] step  [+/-] speed  [q] quit\u{1b}[K");
+            }
+            let _ = io::stdout().flush();
+
+            let delay = Duration::from_secs_f64(1.0 / fps);
+            let key = match keys {
+                Some(rx) if paused => rx.lock().unwrap().recv().ok(),
+                Some(rx) => rx.lock().unwrap().recv_timeout(delay).ok(),
+                None => {
+                    thread::sleep(delay);
+                    None
+                }
+            };
+            match key {
+                Some(b'q') => break,
+                Some(b' ') => paused = !paused,
+                Some(b'n') => {
+                    paused = true;
+                    i = (i + 1).min(last);
+                }
+                Some(b'p') => {
+                    paused = true;
+                    i = i.saturating_sub(1);
+                }
+                Some(b'+') => fps = (fps * 2.0).min(MAX_FPS),
+                Some(b'-') => fps = (fps / 2.0).max(MIN_FPS),
+                Some(_) => {}
+                None if paused => {}
+                None if i < last => i += 1,
+                None if keys.is_some() => paused = true,
+                None => break,
+            }
+        }
+        println!("\u{1b}[?25h");
+    }
+}
+/// Draws the frame with true colour escape codes, highlighted cells inverted.
+pub fn render(frame: &Frame) -> String {
+    let mut s = String::new();
+    for y in 0..frame.height() {
+        let mut color: Option<Color> = None;
+        let mut highlighted = false;
+        for x in 0..frame.width() {
+            let cell = frame.cell(x, y);
+            if color != Some(cell.color) {
+                let Color { r, g, b } = cell.color;
+                let _ = write!(s, "\u{1b}[38;2;{r};{g};{b}m");
+                color = Some(cell.color);
+            }
+            if frame.is_highlighted(x, y) != highlighted {
+                highlighted = !highlighted;
+                s.push_str(if highlighted {
+                    "\u{1b}[7m"
+                } else {
+                    "\u{1b}[27m"
+                });
+            }
+            s.push(cell.glyph);
+        }
+        s.push_str("\u{1b}[0m\u{1b}[K\n");
+    }
+    if let Some(caption) = frame.caption() {
+        s.push_str(caption);
+        s.push_str("\u{1b}[K\n");
+    }
+    s
+}
+/// Reads single key presses from stdin. Lives for the rest of the program.
+fn spawn_key_reader() -> Mutex<Receiver<u8>> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buf = [0];
+        while io::stdin().read_exact(&mut buf).is_ok() {
+            if tx.send(buf[0]).is_err() {
+                break;
+            }
+        }
+    });
+    Mutex::new(rx)
+}
+/// Switches the terminal to unbuffered input without echo while it lives.
+struct RawMode {
+    saved: Option<String>,
+}
+impl RawMode {
+    fn enable() -> Self {
+        let saved = Command::new("stty")
+            .arg("-g")
+            .stdin(Stdio::inherit())
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_owned());
+        let _ = Command::new("stty")
+            .args(["-icanon", "-echo", "min", "1"])
+            .stdin(Stdio::inherit())
+            .status();
+        Self { saved }
+    }
+}
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            let _ = Command::new("stty")
+                .arg(saved)
+                .stdin(Stdio::inherit())
+                .status();
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::{grid::dense::DenseGrid, viz::Cell};
+
+    #[test]
+    fn test_render() {
+        let grid = DenseGrid::from_iter([true, false, false, true].into_iter(), 2);
+        let frame = Frame::from_grid(&grid, |b| {
+            if *b {
+                Cell::new('#', Color::RED)
+            } else {
+                Cell::new('.', Color::RED)
+            }
+        })
+        .highlight([1])
+        .with_caption("step 1");
+        assert_eq!(
+            render(&frame),
+            "\u{1b}[38;2;220;50;47m#\u{1b}[7m.\u{1b}[0m\u{1b}[K\n\u{1b}[38;2;220;50;47m.#\u{1b}[0m\u{1b}[K\nstep 1\u{1b}[K\n"
+        );
+    }
+}
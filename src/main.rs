@@ -1,12 +1,22 @@
-use std::env;
+use std::{env, process::ExitCode};
 
 use all_aoc::cli::{
     commands::{
+        bench,
         download::download,
+        generate::{generate, scaling},
+        leaderboard::{Source, leaderboard},
         prepare::prepare,
-        solve::{single_day, year},
+        read::{DEFAULT_WIDTH, read},
+        report::report,
+        search::{DEFAULT_LIMIT, search},
+        solve::{single_day, visualize, year},
+        stats::stats,
     },
     day::Day,
+    error::AOCError,
+    index::tagged,
+    timings::Cost,
 };
 #[derive(Debug)]
 enum Command {
@@ -16,82 +26,187 @@ enum Command {
     Prepare {
         days: Days,
     },
+    Read {
+        day: Day,
+        part: Option<u8>,
+        width: usize,
+    },
+    Report {
+        by_tag: bool,
+    },
+    Stats {
+        days: Days,
+    },
+    BenchDiff {
+        rev_a: String,
+        rev_b: String,
+        days: Days,
+    },
+    Leaderboard {
+        days: Days,
+        source: Source,
+    },
+    Search {
+        query: String,
+        regex: bool,
+        limit: usize,
+    },
+    Generate {
+        day: Day,
+        size: usize,
+        seed: u64,
+        release: bool,
+        bench: bool,
+    },
     Solve {
         days: Days,
+        tag: Option<String>,
         submit: Option<u8>,
         release: bool,
         time: bool,
+        max_cost: Option<Cost>,
+        no_cache: bool,
+        viz: bool,
+        fps: Option<f64>,
+        viz_out: Option<String>,
     },
 }
 #[derive(Debug)]
 enum Days {
     Day(Day),
     Year(u16),
+    /// Every day with a binary.
+    All,
 }
 impl Days {
     fn to_vec(&self) -> Vec<Day> {
         match self {
             Self::Day(day) => vec![*day],
             Self::Year(year) => (1..=25).map(|day| Day { day, year: *year }).collect(),
+            Self::All => Day::all(),
+        }
+    }
+    /// The selected days, `None` if every day is selected.
+    fn selection(&self) -> Option<Vec<Day>> {
+        match self {
+            Self::Day(_) | Self::Year(_) => Some(self.to_vec()),
+            Self::All => None,
         }
     }
 }
 impl Command {
-    fn execute(&self) -> Result<(), String> {
+    fn execute(&self) -> Result<(), AOCError> {
         match self {
             Self::Download { days } => {
                 for day in days.to_vec() {
-                    if let Err(e) = download(day) {
-                        eprintln!("Error while downloading {}.{}: {e}", day.day, day.year);
-                    }
+                    download(day)?;
                 }
                 Ok(())
             }
-            Self::Prepare { days } => {
-                for day in days.to_vec() {
-                    if let Err(e) = prepare(day) {
-                        eprintln!("Error while preparing {}.{}: {e}", day.day, day.year);
-                        return Err(e.to_string());
-                    }
+            Self::Prepare { days } => days.to_vec().into_iter().try_for_each(prepare),
+            Self::Read { day, part, width } => read(*day, *part, *width),
+            Self::Report { by_tag } => report(*by_tag),
+            Self::Stats { days } => stats(days.to_vec()),
+            Self::BenchDiff { rev_a, rev_b, days } => {
+                bench::diff(rev_a, rev_b, days.selection().as_deref())
+            }
+            Self::Leaderboard { days, source } => match days {
+                Days::Day(day) => leaderboard(day.year, Some(day.day), source),
+                Days::Year(year) => leaderboard(*year, None, source),
+                Days::All => Err(AOCError::Unsupported(
+                    "leaderboard needs a Day or Year".to_owned(),
+                )),
+            },
+            Self::Search {
+                query,
+                regex,
+                limit,
+            } => search(query, *regex, *limit),
+            Self::Generate {
+                day,
+                size,
+                seed,
+                release,
+                bench,
+            } => {
+                if !day.exists() {
+                    return Err(AOCError::MissingDay(*day));
+                }
+                if *bench {
+                    scaling(*day, *size, *seed, *release)
+                } else {
+                    generate(*day, *size, *seed, *release).map(|_| ())
                 }
-                Ok(())
             }
             Self::Solve {
                 days,
+                tag: Some(tag),
                 submit,
                 release,
                 time,
+                max_cost,
+                no_cache,
+                viz,
+                ..
             } => {
-                match days {
-                    Days::Day(day) => {
-                        if day.exists() {
-                            single_day(*day, *release, *submit, *time);
-                        } else {
-                            eprintln!("Binary for Day {day} not found");
-                        }
-                    }
-                    Days::Year(_) if submit.is_some() => {
-                        return Err("Sumbit Flag with multiple Days is not supported".to_owned());
-                    }
-                    days @ Days::Year(_) => {
-                        year(days.to_vec(), *release, *time);
-                    }
+                if submit.is_some() || *viz {
+                    return Err(AOCError::Unsupported(
+                        "Submit and Viz Flags are not supported with --tag".to_owned(),
+                    ));
                 }
-
-                Ok(())
+                let days = tagged(&days.to_vec(), tag)?;
+                if days.is_empty() {
+                    return Err(AOCError::InvalidArgument(format!(
+                        "No Day is tagged \"{tag}\""
+                    )));
+                }
+                year(days, *release, *time, *max_cost, !no_cache)
             }
+            Self::Solve {
+                days,
+                tag: None,
+                submit,
+                release,
+                time,
+                max_cost,
+                no_cache,
+                viz,
+                fps,
+                viz_out,
+            } => match days {
+                Days::Day(day) if !day.exists() => Err(AOCError::MissingDay(*day)),
+                Days::Day(_) if max_cost.is_some() && (submit.is_some() || *viz) => {
+                    Err(AOCError::Unsupported(
+                        "Max Cost Flag is not supported with Submit or Viz".to_owned(),
+                    ))
+                }
+                Days::Day(day) if *viz => visualize(*day, *release, *fps, viz_out.as_deref()),
+                Days::Day(day) => single_day(*day, *release, *submit, *time, *max_cost, !no_cache),
+                Days::Year(_) | Days::All if submit.is_some() => Err(AOCError::Unsupported(
+                    "Sumbit Flag with multiple Days is not supported".to_owned(),
+                )),
+                Days::Year(_) | Days::All if *viz => Err(AOCError::Unsupported(
+                    "Viz Flag with multiple Days is not supported".to_owned(),
+                )),
+                days @ (Days::Year(_) | Days::All) => {
+                    year(days.to_vec(), *release, *time, *max_cost, !no_cache)
+                }
+            },
         }
     }
 }
-fn main() {
+/// Fails with the exit code of the error, see [`AOCError::exit_code`].
+fn main() -> ExitCode {
     let args: Vec<String> = env::args().collect();
-    let command = parse(&args);
-    match command {
-        Ok(c) => match c.execute() {
-            Ok(()) => {}
-            Err(e) => eprintln!("{e}"),
-        },
-        Err(e) => eprintln!("{e}"),
+    match parse(&args)
+        .map_err(AOCError::InvalidArgument)
+        .and_then(|c| c.execute())
+    {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::from(e.exit_code())
+        }
     }
 }
 
@@ -110,48 +225,262 @@ fn parse(args: &[String]) -> Result<Command, String> {
                 days: parse_day(day)?,
             })
         }
-        "solve" => {
-            let mut iter = args.iter().skip(2);
-            let day = iter.next().ok_or_else(|| "Missing Day".to_owned())?;
-            let mut release = false;
-            let mut time = false;
+        "read" => parse_read(&args[2..]),
+        "search" => parse_search(&args[2..]),
+        "leaderboard" => parse_leaderboard(&args[2..]),
+        "report" => match args.get(2).map(String::as_str) {
+            None => Ok(Command::Report { by_tag: false }),
+            Some("--by-tag") => Ok(Command::Report { by_tag: true }),
+            Some(x) => Err(format!("This argument is not supported: {x}")),
+        },
+        "stats" => Ok(Command::Stats {
+            days: args.get(2).map_or(Ok(Days::All), |d| parse_day(d))?,
+        }),
+        "bench" => parse_bench(&args[2..]),
+        "generate" => parse_generate(&args[2..]),
+        "solve" => parse_solve(&args[2..]),
+        c => Err(format!("Unknown Subcommand {c}")),
+    }
+}
+fn parse_bench(args: &[String]) -> Result<Command, String> {
+    let [mode, rev_a, rev_b, rest @ ..] = args else {
+        return Err("Format: bench diff <rev-a> <rev-b> [day|year]".to_owned());
+    };
+    if mode != "diff" {
+        return Err(format!("Unknown bench mode {mode}"));
+    }
+    let days = match rest {
+        [] => Days::All,
+        [day] => parse_day(day)?,
+        [_, x, ..] => return Err(format!("This argument is not supported: {x}")),
+    };
+    Ok(Command::BenchDiff {
+        rev_a: rev_a.clone(),
+        rev_b: rev_b.clone(),
+        days,
+    })
+}
+fn parse_read(args: &[String]) -> Result<Command, String> {
+    let mut iter = args.iter();
+    let day = iter.next().ok_or_else(|| "Missing Day".to_owned())?;
+    let Days::Day(day) = parse_day(day)? else {
+        return Err("read only supports a single Day".to_owned());
+    };
+    let mut part = None;
+    let mut width = env::var("COLUMNS")
+        .ok()
+        .and_then(|c| c.parse().ok())
+        .unwrap_or(DEFAULT_WIDTH);
+    while let Some(a) = iter.next() {
+        match a.as_str() {
+            "--part" => {
+                part = Some(
+                    iter.next()
+                        .ok_or("if --part flag is set, there has to be a next argument")?
+                        .parse()
+                        .map_err(|e| format!("Has to be a number: {e}"))?,
+                );
+            }
+            "--width" => {
+                width = iter
+                    .next()
+                    .ok_or("if --width flag is set, there has to be a next argument")?
+                    .parse()
+                    .map_err(|e| format!("Has to be a number: {e}"))?;
+            }
+            x => return Err(format!("This argument is not supported: {x}")),
+        }
+    }
+    if part.is_some_and(|p| !(1..=2).contains(&p)) {
+        return Err("Part has to be 1 or 2".to_owned());
+    }
+    Ok(Command::Read { day, part, width })
+}
+fn parse_search(args: &[String]) -> Result<Command, String> {
+    let mut iter = args.iter();
+    let mut words = Vec::new();
+    let mut regex = false;
+    let mut limit = DEFAULT_LIMIT;
+    while let Some(a) = iter.next() {
+        match a.as_str() {
+            "--regex" => regex = true,
+            "--limit" => {
+                limit = iter
+                    .next()
+                    .ok_or("if --limit flag is set, there has to be a next argument")?
+                    .parse()
+                    .map_err(|e| format!("Has to be a number: {e}"))?;
+            }
+            x if x.starts_with("--") => return Err(format!("This argument is not supported: {x}")),
+            word => words.push(word),
+        }
+    }
+    if words.is_empty() {
+        return Err("Missing Query".to_owned());
+    }
+    Ok(Command::Search {
+        query: words.join(" "),
+        regex,
+        limit,
+    })
+}
+fn parse_leaderboard(args: &[String]) -> Result<Command, String> {
+    let mut iter = args.iter();
+    let days = parse_day(iter.next().ok_or_else(|| "Missing Day".to_owned())?)?;
+    let mut source = env::var("AOC_LEADERBOARD")
+        .ok()
+        .map(|id| Source::fetch(&id))
+        .transpose()?;
+    while let Some(a) = iter.next() {
+        match a.as_str() {
+            "--id" => {
+                source = Some(Source::fetch(
+                    iter.next()
+                        .ok_or("if --id flag is set, there has to be a next argument")?,
+                )?);
+            }
+            "--file" => {
+                source = Some(Source::File(
+                    iter.next()
+                        .ok_or("if --file flag is set, there has to be a next argument")?
+                        .into(),
+                ));
+            }
+            x => return Err(format!("This argument is not supported: {x}")),
+        }
+    }
+    let source = source
+        .ok_or_else(|| "Missing Leaderboard, set --id, --file or AOC_LEADERBOARD".to_owned())?;
+    Ok(Command::Leaderboard { days, source })
+}
+fn parse_generate(args: &[String]) -> Result<Command, String> {
+    let mut iter = args.iter();
+    let day = iter.next().ok_or_else(|| "Missing Day".to_owned())?;
+    let Days::Day(day) = parse_day(day)? else {
+        return Err("generate only supports a single Day".to_owned());
+    };
+    let mut size = 0;
+    let mut seed = 0;
+    let mut release = false;
+    let mut bench = false;
+    while let Some(a) = iter.next() {
+        match a.as_str() {
+            "--release" => release = true,
+            "--bench" => bench = true,
+            "--size" => {
+                size = iter
+                    .next()
+                    .ok_or("if --size flag is set, there has to be a next argument")?
+                    .parse()
+                    .map_err(|e| format!("Has to be a number: {e}"))?;
+            }
+            "--seed" => {
+                seed = iter
+                    .next()
+                    .ok_or("if --seed flag is set, there has to be a next argument")?
+                    .parse()
+                    .map_err(|e| format!("Has to be a number: {e}"))?;
+            }
+            x => return Err(format!("This argument is not supported: {x}")),
+        }
+    }
+    Ok(Command::Generate {
+        day,
+        size,
+        seed,
+        release,
+        bench,
+    })
+}
+fn parse_solve(args: &[String]) -> Result<Command, String> {
+    let mut iter = args.iter().peekable();
+    let day = iter.next_if(|a| !a.starts_with("--"));
+    let mut release = false;
+    let mut time = false;
+    let mut tag = None;
 
-            let mut submit = None;
-            while let Some(a) = iter.next() {
-                match a.as_str() {
-                    "--release" => release = true,
-                    "--time" => time = true,
-                    "--machine-readable" => (),
-                    "--submit" => {
-                        submit = Some(
-                            iter.next()
-                                .ok_or("if --submit flag is set, there has to be a next argument")?
-                                .parse()
-                                .map_err(|e| format!("Has to be a number: {e}"))?,
-                        );
-                    }
-                    x => return Err(format!("This argument is not supported: {x}")),
+    let mut submit = None;
+    let mut max_cost = None;
+    let mut no_cache = false;
+    let mut viz = false;
+    let mut fps = None;
+    let mut viz_out = None;
+    while let Some(a) = iter.next() {
+        match a.as_str() {
+            "--release" => release = true,
+            "--time" => time = true,
+            "--no-cache" => no_cache = true,
+            "--machine-readable" => (),
+            "--submit" => {
+                submit = Some(
+                    iter.next()
+                        .ok_or("if --submit flag is set, there has to be a next argument")?
+                        .parse()
+                        .map_err(|e| format!("Has to be a number: {e}"))?,
+                );
+            }
+            "--tag" => {
+                tag = Some(
+                    iter.next()
+                        .ok_or("if --tag flag is set, there has to be a next argument")?
+                        .to_owned(),
+                );
+            }
+            "--viz" => viz = true,
+            "--fps" => {
+                let value = iter
+                    .next()
+                    .ok_or("if --fps flag is set, there has to be a next argument")?
+                    .parse::<f64>()
+                    .map_err(|e| format!("Has to be a number: {e}"))?;
+                if !value.is_finite() || value <= 0.0 {
+                    return Err(format!("Fps has to be a positive number: {value}"));
                 }
+                fps = Some(value);
             }
-
-            Ok(Command::Solve {
-                days: parse_day(day)?,
-                submit,
-                release,
-                time,
-            })
+            "--viz-out" => {
+                viz = true;
+                viz_out = Some(
+                    iter.next()
+                        .ok_or("if --viz-out flag is set, there has to be a next argument")?
+                        .to_owned(),
+                );
+            }
+            "--max-cost" => {
+                max_cost = Some(
+                    iter.next()
+                        .ok_or("if --max-cost flag is set, there has to be a next argument")?
+                        .parse()?,
+                );
+            }
+            x => return Err(format!("This argument is not supported: {x}")),
         }
-
-        c => Err(format!("Unknown Subcommand {c}")),
     }
+
+    let days = match day {
+        Some(day) => parse_day(day)?,
+        None if tag.is_some() => Days::All,
+        None => return Err("Missing Day".to_owned()),
+    };
+    Ok(Command::Solve {
+        days,
+        tag,
+        submit,
+        release,
+        time,
+        max_cost,
+        no_cache,
+        viz,
+        fps,
+        viz_out,
+    })
 }
 fn parse_day(arg: &str) -> Result<Days, String> {
     // possible Ways:
     // 17           needs year from env
     // 17.2023      specified year
     // 2023         whole year
-    if arg.contains('.') {
-        let (day, year) = arg.split_once('.').expect("checked above");
+    if let Some((day, year)) = arg.split_once('.') {
         let day = day
             .parse::<u8>()
             .map_err(|_| format!("Day is not a number between 1 and 25: {day}"))?;
@@ -170,9 +499,9 @@ fn parse_day(arg: &str) -> Result<Days, String> {
         Ok(Days::Day(Day { day, year }))
     } else {
         let n = arg.parse::<u16>().map_err(|e| e.to_string())?;
-        if (1..=25).contains(&n) {
+        if let Ok(day @ 1..=25) = u8::try_from(n) {
             Ok(Days::Day(Day {
-                day: n.try_into().unwrap(),
+                day,
                 year: match std::env::var("AOC_YEAR") {
                     Ok(x) => x.parse::<u16>().map_err(|e| e.to_string())?,
                     Err(_) => return Err(
@@ -0,0 +1,234 @@
+//! Runs every day that has an input and known answers and compares the results
+//! with the answers store in `data/answers`.
+//!
+//! ```text
+//! cargo test --test golden -- [filter ...] [--max-cost fast|medium|slow]
+//! ```
+//! A filter is matched against the binary name, so `2016` selects a whole year
+//! and `2016_11` a single day. The cost of a part is taken from its timing history,
+//! which every run updates. Slow parts only run with `--max-cost slow`.
+//! Parts without history run, unless their seed in `data/seed_timings` marks them as slow.
+//!
+//! The results cache of `cargo solve` is never used, so every selected part runs.
+use core::{
+    str::FromStr as _,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{
+    env,
+    path::PathBuf,
+    process::{Command, ExitCode},
+    sync::Mutex,
+    thread,
+};
+
+use all_aoc::cli::{
+    answers::Answers,
+    day::{Day, parse_machine_readable},
+    runner::{Answer, PartDayResult},
+    timings::{Cost, Profile, Timings},
+};
+
+enum Outcome {
+    Ok,
+    Failed(String),
+    Ignored(String),
+}
+
+struct Options {
+    filters: Vec<String>,
+    max_cost: Cost,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let days = Day::all()
+        .into_iter()
+        .filter(|d| {
+            options.filters.is_empty() || options.filters.iter().any(|f| d.bin_name().contains(f))
+        })
+        .collect::<Vec<_>>();
+    println!("\nrunning {} days", days.len());
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![]);
+    let threads = thread::available_parallelism().map_or(1, usize::from);
+    thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let outcomes = check_day(day, options.max_cost);
+                    for (part, outcome) in &outcomes {
+                        print_outcome(day, *part, outcome);
+                    }
+                    results.lock().unwrap().extend(outcomes);
+                }
+            });
+        }
+    });
+
+    let results = results.into_inner().unwrap();
+    let passed = results
+        .iter()
+        .filter(|(_, o)| matches!(o, Outcome::Ok))
+        .count();
+    let failed = results
+        .iter()
+        .filter(|(_, o)| matches!(o, Outcome::Failed(_)))
+        .count();
+    let ignored = results.len() - passed - failed;
+    let status = if failed == 0 { "ok" } else { "FAILED" };
+    println!("\ntest result: {status}. {passed} passed; {failed} failed; {ignored} ignored\n");
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        filters: vec![],
+        max_cost: Cost::Medium,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--max-cost" => {
+                options.max_cost = Cost::from_str(
+                    &args
+                        .next()
+                        .ok_or("if --max-cost flag is set, there has to be a next argument")?,
+                )?;
+            }
+            // options of the default test harness are ignored
+            "--test-threads" | "--skip" | "--format" | "--color" => {
+                args.next();
+            }
+            x if x.starts_with('-') => {}
+            x => options.filters.push(x.to_owned()),
+        }
+    }
+    Ok(options)
+}
+
+fn check_day(day: Day, max_cost: Cost) -> Vec<(u8, Outcome)> {
+    let (answers, mut timings) = match Answers::load(day).and_then(|a| Ok((a, Timings::load(day)?)))
+    {
+        Ok(x) => x,
+        Err(e) => return vec![(1, Outcome::Failed(e.to_string()))],
+    };
+    let selected = [1, 2]
+        .into_iter()
+        .filter_map(|part| answers.part(part).map(|e| (part, e)))
+        .collect::<Vec<_>>();
+    if !day.input_path().exists() {
+        return selected
+            .into_iter()
+            .map(|(part, _)| (part, Outcome::Ignored("no input".to_owned())))
+            .collect();
+    }
+    let (to_run, skipped): (Vec<_>, Vec<_>) = selected
+        .into_iter()
+        .partition(|(part, _)| timings.cost(*part).is_none_or(|c| c <= max_cost));
+    let mut outcomes = skipped
+        .into_iter()
+        .map(|(part, _)| {
+            let cost = timings
+                .cost(part)
+                .expect("only parts with a history are skipped");
+            (part, Outcome::Ignored(cost.to_string()))
+        })
+        .collect::<Vec<_>>();
+    let only_part = match to_run.as_slice() {
+        [] => return outcomes,
+        [(part, _)] => Some(*part),
+        _ => None,
+    };
+    let results = match run(day, only_part) {
+        Ok(r) => r,
+        Err(e) => {
+            outcomes.extend(
+                to_run
+                    .into_iter()
+                    .map(|(part, _)| (part, Outcome::Failed(e.clone()))),
+            );
+            return outcomes;
+        }
+    };
+    timings.record_results(&[&results.0, &results.1], Profile::current());
+    if let Err(e) = timings.save() {
+        eprintln!("{e}");
+    }
+    outcomes.extend(to_run.into_iter().map(|(part, expected)| {
+        let actual = if part == 1 {
+            &results.0.result
+        } else {
+            &results.1.result
+        };
+        if *actual == expected.answer {
+            (part, Outcome::Ok)
+        } else {
+            (
+                part,
+                Outcome::Failed(format!(
+                    "expected {}, got {}",
+                    escaped(expected.answer.as_ref()),
+                    escaped(actual.as_ref())
+                )),
+            )
+        }
+    }));
+    outcomes
+}
+
+fn escaped(answer: Option<&Answer>) -> String {
+    answer.map_or_else(|| "None".to_owned(), Answer::escaped)
+}
+
+/// Runs the binary of the day, which was built by cargo next to this test.
+fn run(
+    day: Day,
+    part: Option<u8>,
+) -> Result<(PartDayResult<Answer>, PartDayResult<Answer>), String> {
+    let mut command = Command::new(bin_dir().join(day.bin_name() + env::consts::EXE_SUFFIX));
+    command.arg("--machine-readable");
+    if let Some(part) = part {
+        command.arg("--part").arg(part.to_string());
+    }
+    let output = command
+        .output()
+        .map_err(|e| format!("binary can't be started: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "binary failed with {}:\n{}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    parse_machine_readable(day, &String::from_utf8_lossy(&output.stdout)).map_err(|e| e.to_string())
+}
+
+/// The test itself lives in `target/<profile>/deps`, the binaries one level up.
+fn bin_dir() -> PathBuf {
+    let mut path = env::current_exe().unwrap();
+    path.pop();
+    if path.ends_with("deps") {
+        path.pop();
+    }
+    path
+}
+
+fn print_outcome(day: Day, part: u8, outcome: &Outcome) {
+    let name = day.bin_name();
+    match outcome {
+        Outcome::Ok => println!("test {name} part {part} ... ok"),
+        Outcome::Failed(e) => println!("test {name} part {part} ... FAILED\n    {e}"),
+        Outcome::Ignored(reason) => println!("test {name} part {part} ... ignored, {reason}"),
+    }
+}